@@ -1,4 +1,4 @@
-use crate::InstructionReader;
+use crate::{CompilerWarning, InstructionReader};
 use koto_memory::Ptr;
 use koto_parser::{ConstantPool, Span};
 use std::{
@@ -12,6 +12,8 @@ pub struct DebugInfo {
     source_map: Vec<(u32, Span)>,
     /// The source of the program that the debug info was derived from
     pub source: String,
+    /// Warnings that were emitted by the compiler
+    pub warnings: Vec<CompilerWarning>,
 }
 
 impl DebugInfo {
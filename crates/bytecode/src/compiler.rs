@@ -1,13 +1,14 @@
 use crate::{
     frame::{Arg, AssignedOrReserved, Frame, FrameError},
+    type_checker::TypeChecker,
     DebugInfo, FunctionFlags, Op, StringFormatFlags,
 };
 use circular_buffer::CircularBuffer;
 use derive_name::VariantName;
 use koto_parser::{
-    Ast, AstBinaryOp, AstFor, AstIf, AstIndex, AstNode, AstTry, AstUnaryOp, AstVec, ChainNode,
-    ConstantIndex, Function, ImportItem, MatchArm, MetaKeyId, Node, Span, StringContents,
-    StringFormatOptions, StringNode, SwitchArm,
+    Ast, AstBinaryOp, AstFor, AstIf, AstIndex, AstNode, AstString, AstTry, AstUnaryOp, AstVec,
+    ChainNode, ConstantIndex, Function, ImportItem, MatchArm, MetaKeyId, Node, Span,
+    StringContents, StringFormatOptions, StringNode, SwitchArm,
 };
 use smallvec::{smallvec, SmallVec};
 use thiserror::Error;
@@ -20,6 +21,8 @@ enum ErrorKind {
     UnexpectedNode { expected: String, unexpected: Node },
     #[error("attempting to assign to a temporary value")]
     AssigningToATemporaryValue,
+    #[error("attempting to assign to a const value")]
+    AssigningToAConst,
     #[error("invalid {kind} op ({op:?})")]
     InvalidBinaryOp { kind: String, op: AstBinaryOp },
     #[error("`{0}` used outside of loop")]
@@ -48,6 +51,8 @@ enum ErrorKind {
     MissingChainParentRegister,
     #[error("missing result register")]
     MissingResultRegister,
+    #[error("assigning to a value after a `?` null check isn't supported")]
+    NullCheckInAssignment,
     #[error("missing String nodes")]
     MissingStringNodes,
     #[error("a type check is needed when the catch block isn't the last in the try expression")]
@@ -79,6 +84,10 @@ enum ErrorKind {
     UnassignedBreakValue,
     #[error("unexpected Ellipsis")]
     UnexpectedEllipsis,
+    #[error("unexpected map pattern")]
+    UnexpectedMapPattern,
+    #[error("unexpected record pattern")]
+    UnexpectedRecordPattern,
     #[error("unexpected Wildcard")]
     UnexpectedWildcard,
     #[error("expected {expected} patterns in match arm, found {unexpected}")]
@@ -100,6 +109,42 @@ pub struct CompilerError {
     pub span: Span,
 }
 
+/// The different warning types that can be emitted by the compiler
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum WarningKind {
+    #[error("the match expression doesn't cover all possible values, missing: {0}")]
+    NonExhaustiveMatch(String),
+    #[error("expected '{expected}', found '{found}'")]
+    UnexpectedType { expected: String, found: String },
+    #[error("expected '{expected}' for argument {arg}, found '{found}'")]
+    UnexpectedArgType {
+        arg: usize,
+        expected: String,
+        found: String,
+    },
+    #[error("expected '{expected}' as the function's output, found '{found}'")]
+    UnexpectedOutputType { expected: String, found: String },
+}
+
+/// A warning emitted during compilation
+///
+/// Warnings don't prevent compilation from succeeding, they're available in a compiled chunk's
+/// [DebugInfo].
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[error("{warning}")]
+pub struct CompilerWarning {
+    /// The warning's message
+    warning: WarningKind,
+    /// The span in the source where the warning was emitted
+    pub span: Span,
+}
+
+impl CompilerWarning {
+    pub(crate) fn new(warning: WarningKind, span: Span) -> Self {
+        Self { warning, span }
+    }
+}
+
 #[derive(Copy, Clone)]
 struct CompileNodeContext<'a> {
     ast: &'a Ast,
@@ -202,6 +247,7 @@ impl CompileNodeOutput {
 }
 
 /// The settings used by the [Compiler]
+#[derive(Clone, Copy, Debug)]
 pub struct CompilerSettings {
     /// Causes all top level identifiers to be exported
     ///
@@ -214,6 +260,20 @@ pub struct CompilerSettings {
     ///
     /// Enabled by default.
     pub enable_type_checks: bool,
+    /// Runs a static type checking pass before compilation
+    ///
+    /// Type hint mismatches that can be detected before running the script are reported as
+    /// warnings, see [CompilerWarning].
+    ///
+    /// Disabled by default.
+    pub enable_static_type_checks: bool,
+    /// Causes values assigned in `const` declarations to be frozen
+    ///
+    /// Frozen lists and maps can't be modified, and any containers that they contain are also
+    /// frozen.
+    ///
+    /// Enabled by default.
+    pub freeze_const_values: bool,
 }
 
 impl Default for CompilerSettings {
@@ -221,6 +281,8 @@ impl Default for CompilerSettings {
         Self {
             export_top_level_ids: false,
             enable_type_checks: true,
+            enable_static_type_checks: false,
+            freeze_const_values: true,
         }
     }
 }
@@ -245,6 +307,10 @@ impl Compiler {
             ..Default::default()
         };
 
+        if compiler.settings.enable_static_type_checks {
+            compiler.debug_info.warnings = TypeChecker::check(ast);
+        }
+
         if let Some(entry_point) = ast.entry_point() {
             compiler.compile_node(
                 entry_point,
@@ -451,6 +517,10 @@ impl Compiler {
                 targets,
                 expression,
             } => self.compile_multi_assign(targets, *expression, false, ctx)?,
+            Node::Const { target, expression } => self.compile_const(*target, *expression, ctx)?,
+            Node::Record { name, fields } => self.compile_record(*name, fields, ctx)?,
+            Node::Enum { name, variants } => self.compile_enum(*name, variants, ctx)?,
+            Node::Interface { name, entries } => self.compile_interface(*name, entries, ctx)?,
             Node::UnaryOp { op, value } => self.compile_unary_op(*op, *value, ctx)?,
             Node::BinaryOp { op, lhs, rhs } => self.compile_binary_op(*op, *lhs, *rhs, ctx)?,
             Node::If(ast_if) => self.compile_if(ast_if, ctx)?,
@@ -458,6 +528,8 @@ impl Compiler {
             Node::Switch(arms) => self.compile_switch(arms, ctx)?,
             Node::Ellipsis(_) => return self.error(ErrorKind::UnexpectedEllipsis),
             Node::Wildcard(..) => return self.error(ErrorKind::UnexpectedWildcard),
+            Node::MapPattern(_) => return self.error(ErrorKind::UnexpectedMapPattern),
+            Node::RecordPattern { .. } => return self.error(ErrorKind::UnexpectedRecordPattern),
             Node::For(ast_for) => self.compile_for(ast_for, ctx)?,
             Node::While { condition, body } => {
                 self.compile_loop(Some((*condition, false)), *body, ctx)?
@@ -546,7 +618,7 @@ impl Compiler {
                 // see compile_assign().
                 unreachable!();
             }
-            Node::Type(..) => {
+            Node::Type { .. } | Node::TypeUnion { .. } => {
                 // Type hints are only compiled in the context of typed identifiers.
                 unreachable!();
             }
@@ -605,9 +677,14 @@ impl Compiler {
 
                     self.pop_span();
                 }
+                Node::MapPattern(entries) => {
+                    self.push_span(arg_node, ctx.ast);
+                    self.compile_unpack_map_pattern(arg_register, entries, false, ctx)?;
+                    self.pop_span();
+                }
                 unexpected => {
                     return self.error(ErrorKind::UnexpectedNode {
-                        expected: "ID or Tuple as function arg".into(),
+                        expected: "ID, Tuple, or map pattern as function arg".into(),
                         unexpected: unexpected.clone(),
                     })
                 }
@@ -762,6 +839,11 @@ impl Compiler {
         // Args should then appear as:
         // [Local(a), Placeholder, Placeholder, Local(e), Unpacked(b), Unpacked(c), Unpacked(d)]
         //
+        // Map patterns are treated in the same way as nested containers, e.g. given:
+        // f = |a, {b, c: (d, e)}|
+        // Args should then appear as:
+        // [Local(a), Placeholder, Unpacked(b), Unpacked(d), Unpacked(e)]
+        //
         // Note that the value stack at runtime will have the function's captures loaded in after
         // the top-level locals and placeholders, and before any unpacked args (e.g. in the example
         // above, captures will be placed after Local(e) and before Unpacked(b)).
@@ -777,6 +859,10 @@ impl Compiler {
                     result.push(Arg::Placeholder);
                     nested_args.extend(self.collect_nested_args(nested, ast)?);
                 }
+                Node::MapPattern(_) => {
+                    result.push(Arg::Placeholder);
+                    nested_args.extend(self.collect_nested_args(&[*arg], ast)?);
+                }
                 unexpected => {
                     return self.error(ErrorKind::UnexpectedNode {
                         expected: "ID in function args".into(),
@@ -800,6 +886,12 @@ impl Compiler {
                 Node::Tuple(nested_args) => {
                     result.extend(self.collect_nested_args(nested_args, ast)?);
                 }
+                Node::MapPattern(entries) => {
+                    for (key, value) in entries.iter() {
+                        // Entries without a value pattern assign to the key's id
+                        result.extend(self.collect_nested_args(&[value.unwrap_or(*key)], ast)?);
+                    }
+                }
                 Node::Ellipsis(Some(id)) => result.push(Arg::Unpacked(*id)),
                 Node::Ellipsis(None) => {}
                 unexpected => {
@@ -855,6 +947,12 @@ impl Compiler {
                     self.compile_unpack_nested_args(tuple_register, nested_args, ctx)?;
                     self.pop_register()?; // tuple_register
                 }
+                Node::MapPattern(entries) => {
+                    let map_register = self.push_register()?;
+                    self.push_op(TempIndex, &[map_register, container_register, arg_index]);
+                    self.compile_unpack_map_pattern(map_register, entries, false, ctx)?;
+                    self.pop_register()?; // map_register
+                }
                 Node::Ellipsis(maybe_id) if is_first_arg => {
                     if let Some(id) = maybe_id {
                         // e.g. [first..., x, y]
@@ -885,6 +983,182 @@ impl Compiler {
         Ok(())
     }
 
+    // Unpacks the entries of a map pattern in function args or let expressions
+    //
+    // Errors will be thrown at runtime if the value isn't a map, or if an entry is missing.
+    //
+    // See also: compile_match_map_pattern
+    fn compile_unpack_map_pattern(
+        &mut self,
+        map_register: u8,
+        entries: &[(AstIndex, Option<AstIndex>)],
+        export_assignment: bool,
+        ctx: CompileNodeContext,
+    ) -> Result<()> {
+        use Op::*;
+
+        let export_assignment = export_assignment || self.force_export_assignment();
+
+        self.push_op(AssertMap, &[map_register]);
+
+        for (key, value) in entries.iter() {
+            let key_node = ctx.node_with_span(*key);
+            self.push_span(key_node, ctx.ast);
+
+            match (&key_node.node, value) {
+                (Node::Ellipsis(maybe_id), None) => {
+                    if let Some(id) = maybe_id {
+                        // e.g. `{foo, rest...}`
+                        let id_register = self.assign_local_register(*id)?;
+                        self.compile_map_rest(id_register, map_register, entries, ctx)?;
+                        if export_assignment {
+                            self.compile_value_export(*id, id_register)?;
+                        }
+                    }
+                }
+                (Node::Id(id, ..), None) => {
+                    // e.g. `{foo}`
+                    let id_register = self.assign_local_register(*id)?;
+                    self.push_op(AccessMapEntry, &[id_register, map_register]);
+                    self.push_var_u32((*id).into());
+                    if export_assignment {
+                        self.compile_value_export(*id, id_register)?;
+                    }
+                }
+                (key_node, Some(value)) => {
+                    // e.g. `{foo: x}`
+                    let key = self.map_pattern_key(key_node)?;
+
+                    match ctx.node(*value) {
+                        Node::Id(id, maybe_type) => {
+                            let id_register = self.assign_local_register(*id)?;
+                            self.push_op(AccessMapEntry, &[id_register, map_register]);
+                            self.push_var_u32(key.into());
+                            if let Some(type_hint) = maybe_type {
+                                self.compile_assert_type(
+                                    id_register,
+                                    *type_hint,
+                                    Some(*value),
+                                    ctx,
+                                )?;
+                            }
+                            if export_assignment {
+                                self.compile_value_export(*id, id_register)?;
+                            }
+                        }
+                        Node::Wildcard(_, maybe_type) => {
+                            // The entry is still expected to be present in the map
+                            let temp_register = self.push_register()?;
+                            self.push_op(AccessMapEntry, &[temp_register, map_register]);
+                            self.push_var_u32(key.into());
+                            if let Some(type_hint) = maybe_type {
+                                self.compile_assert_type(
+                                    temp_register,
+                                    *type_hint,
+                                    Some(*value),
+                                    ctx,
+                                )?;
+                            }
+                            self.pop_register()?; // temp_register
+                        }
+                        Node::Tuple(nested_args) => {
+                            let tuple_register = self.push_register()?;
+                            self.push_op(AccessMapEntry, &[tuple_register, map_register]);
+                            self.push_var_u32(key.into());
+                            let (size_op, size_to_check) = args_size_op(nested_args, ctx.ast);
+                            self.push_op(size_op, &[tuple_register, size_to_check as u8]);
+                            self.compile_unpack_nested_args(tuple_register, nested_args, ctx)?;
+                            self.pop_register()?; // tuple_register
+                        }
+                        Node::MapPattern(nested_entries) => {
+                            let nested_register = self.push_register()?;
+                            self.push_op(AccessMapEntry, &[nested_register, map_register]);
+                            self.push_var_u32(key.into());
+                            self.compile_unpack_map_pattern(
+                                nested_register,
+                                nested_entries,
+                                export_assignment,
+                                ctx,
+                            )?;
+                            self.pop_register()?; // nested_register
+                        }
+                        unexpected => {
+                            return self.error(ErrorKind::UnexpectedNode {
+                                expected: "ID, wildcard, or nested pattern as map pattern value"
+                                    .into(),
+                                unexpected: unexpected.clone(),
+                            })
+                        }
+                    }
+                }
+                (unexpected, None) => {
+                    return self.error(ErrorKind::UnexpectedNode {
+                        expected: "ID or Ellipsis as map pattern key".into(),
+                        unexpected: unexpected.clone(),
+                    })
+                }
+            }
+
+            self.pop_span();
+        }
+
+        Ok(())
+    }
+
+    // Gets the constant index of a key in a map pattern
+    fn map_pattern_key(&self, key_node: &Node) -> Result<ConstantIndex> {
+        match key_node {
+            Node::Id(id, ..) => Ok(*id),
+            Node::Str(AstString {
+                contents: StringContents::Literal(constant) | StringContents::Raw { constant, .. },
+                ..
+            }) => Ok(*constant),
+            unexpected => self.error(ErrorKind::UnexpectedNode {
+                expected: "ID or String literal as map pattern key".into(),
+                unexpected: unexpected.clone(),
+            }),
+        }
+    }
+
+    // Places the entries of a map that aren't included in a map pattern in a new map
+    //
+    // e.g. `{foo, bar: x, rest...}`
+    //                     ^~~~ rest will be a map containing all entries other than foo and bar
+    fn compile_map_rest(
+        &mut self,
+        result_register: u8,
+        map_register: u8,
+        entries: &[(AstIndex, Option<AstIndex>)],
+        ctx: CompileNodeContext,
+    ) -> Result<()> {
+        let stack_count = self.stack_count();
+        let keys_start = self.frame().next_temporary_register();
+        let mut key_count = 0;
+
+        for (key, _) in entries.iter() {
+            match ctx.node(*key) {
+                Node::Ellipsis(_) => {}
+                key_node => {
+                    let key = self.map_pattern_key(key_node)?;
+                    let key_register = self.push_register()?;
+                    self.compile_load_string_constant(key_register, key);
+                    key_count += 1;
+                }
+            }
+        }
+
+        if key_count > u8::MAX as usize {
+            return self.error(ErrorKind::TooManyContainerEntries(key_count));
+        }
+
+        self.push_op(
+            Op::MapRest,
+            &[result_register, map_register, keys_start, key_count as u8],
+        );
+
+        self.truncate_register_stack(stack_count)
+    }
+
     fn compile_block(
         &mut self,
         expressions: &[AstIndex],
@@ -926,7 +1200,26 @@ impl Compiler {
     ) -> Result<Option<u8>> {
         let result = match ctx.node(target) {
             Node::Id(constant_index, ..) => Some(self.reserve_local_register(*constant_index)?),
-            Node::Meta { .. } | Node::Chain(_) | Node::Wildcard(..) => None,
+            Node::Meta { .. } | Node::Chain(_) | Node::Wildcard(..) | Node::MapPattern(_) => None,
+            unexpected => {
+                return self.error(ErrorKind::UnexpectedNode {
+                    expected: "ID".into(),
+                    unexpected: unexpected.clone(),
+                })
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn compile_const(
+        &mut self,
+        target: AstIndex,
+        expression: AstIndex,
+        ctx: CompileNodeContext,
+    ) -> Result<CompileNodeOutput> {
+        let id = match ctx.node(target) {
+            Node::Id(id, _) => *id,
             unexpected => {
                 return self.error(ErrorKind::UnexpectedNode {
                     expected: "ID".into(),
@@ -935,9 +1228,205 @@ impl Compiler {
             }
         };
 
+        let result = self.compile_assign(target, expression, false, ctx)?;
+
+        if self.settings.freeze_const_values {
+            if let Some(register) = self.frame().get_local_assigned_register(id) {
+                self.push_op(Op::Freeze, &[register]);
+            }
+        }
+
+        // Further assignments to the id in this frame will now be rejected
+        self.frame_mut().add_const_id(id);
+
+        Ok(result)
+    }
+
+    fn compile_record(
+        &mut self,
+        name: ConstantIndex,
+        fields: &[AstIndex],
+        ctx: CompileNodeContext,
+    ) -> Result<CompileNodeOutput> {
+        let result = self.assign_result_register(ctx)?;
+
+        if let Some(result_register) = result.register {
+            let stack_count = self.stack_count();
+            let (fields_start, field_count) = self.compile_record_fields(fields, ctx)?;
+            self.push_op(
+                Op::MakeRecord,
+                &[result_register, fields_start, field_count],
+            );
+            self.push_var_u32(name.into());
+            self.truncate_register_stack(stack_count)?;
+        }
+
+        Ok(result)
+    }
+
+    fn compile_enum(
+        &mut self,
+        name: ConstantIndex,
+        variants: &[AstIndex],
+        ctx: CompileNodeContext,
+    ) -> Result<CompileNodeOutput> {
+        let result = self.assign_result_register(ctx)?;
+
+        let Some(result_register) = result.register else {
+            return Ok(result);
+        };
+
+        let Ok(size_hint) = u32::try_from(variants.len()) else {
+            return self.error(ErrorKind::TooManyContainerEntries(variants.len()));
+        };
+        self.push_op(Op::MakeMap, &[result_register]);
+        self.push_var_u32(size_hint);
+
+        for variant in variants.iter() {
+            let variant_node = ctx.node_with_span(*variant);
+            let Node::Record {
+                name: variant_name,
+                fields,
+            } = &variant_node.node
+            else {
+                return self.error(ErrorKind::UnexpectedNode {
+                    expected: "Record as enum variant".into(),
+                    unexpected: variant_node.node.clone(),
+                });
+            };
+
+            self.push_span(variant_node, ctx.ast);
+            let stack_count = self.stack_count();
+
+            let variant_register = self.push_register()?;
+            let (fields_start, field_count) = self.compile_record_fields(fields, ctx)?;
+            self.push_op(
+                Op::MakeEnumVariant,
+                &[variant_register, fields_start, field_count],
+            );
+            self.push_var_u32(name.into());
+            self.push_var_u32((*variant_name).into());
+
+            let key_register = self.push_register()?;
+            self.compile_load_string_constant(key_register, *variant_name);
+            self.push_op(
+                Op::MapInsert,
+                &[result_register, key_register, variant_register],
+            );
+
+            self.truncate_register_stack(stack_count)?;
+            self.pop_span();
+        }
+
         Ok(result)
     }
 
+    // Compiles an interface declaration
+    //
+    // The interface's entries are inserted with null values into a temporary map,
+    // which is then used by MakeInterface to define the interface.
+    fn compile_interface(
+        &mut self,
+        name: ConstantIndex,
+        entries: &[AstIndex],
+        ctx: CompileNodeContext,
+    ) -> Result<CompileNodeOutput> {
+        let result = self.assign_result_register(ctx)?;
+
+        let Some(result_register) = result.register else {
+            return Ok(result);
+        };
+
+        let Ok(size_hint) = u32::try_from(entries.len()) else {
+            return self.error(ErrorKind::TooManyContainerEntries(entries.len()));
+        };
+
+        let stack_count = self.stack_count();
+
+        let keys_register = self.push_register()?;
+        self.push_op(Op::MakeMap, &[keys_register]);
+        self.push_var_u32(size_hint);
+
+        let value_register = self.push_register()?;
+        self.push_op(Op::SetNull, &[value_register]);
+
+        for entry in entries.iter() {
+            let key_node = ctx.node(*entry);
+            self.compile_map_insert(value_register, key_node, Some(keys_register), false, ctx)?;
+        }
+
+        self.push_op(Op::MakeInterface, &[result_register, keys_register]);
+        self.push_var_u32(name.into());
+
+        self.truncate_register_stack(stack_count)?;
+
+        Ok(result)
+    }
+
+    // Loads the names, type hints, and type hint bindings of a record's fields into groups of
+    // three consecutive registers
+    //
+    // The registers are left on the stack, and should be removed by the caller.
+    fn compile_record_fields(
+        &mut self,
+        fields: &[AstIndex],
+        ctx: CompileNodeContext,
+    ) -> Result<(u8, u8)> {
+        let fields_start = self.frame().next_temporary_register();
+
+        let Ok(field_count) = u8::try_from(fields.len()) else {
+            return self.error(ErrorKind::TooManyContainerEntries(fields.len()));
+        };
+
+        for field in fields.iter() {
+            let (id, type_hint) = match ctx.node(*field) {
+                Node::Id(id, type_hint) => (*id, *type_hint),
+                unexpected => {
+                    return self.error(ErrorKind::UnexpectedNode {
+                        expected: "ID as record field".into(),
+                        unexpected: unexpected.clone(),
+                    })
+                }
+            };
+
+            let name_register = self.push_register()?;
+            self.compile_load_string_constant(name_register, id);
+
+            let hint_register = self.push_register()?;
+            let bindings_register = self.push_register()?;
+            match type_hint.map(|type_hint| (type_hint, ctx.node(type_hint))) {
+                Some((type_hint, Node::Type { hint, .. } | Node::TypeUnion { hint, .. }))
+                    if self.settings.enable_type_checks =>
+                {
+                    self.compile_load_string_constant(hint_register, *hint);
+
+                    let stack_count = self.stack_count();
+                    let (bindings, binding_count) =
+                        self.compile_type_hint_bindings(type_hint, ctx)?;
+                    if binding_count > 0 {
+                        self.push_op(
+                            Op::MakeTempTuple,
+                            &[bindings_register, bindings, binding_count],
+                        );
+                        self.push_op(
+                            Op::TempTupleToTuple,
+                            &[bindings_register, bindings_register],
+                        );
+                        self.truncate_register_stack(stack_count)?;
+                    } else {
+                        self.push_op(Op::SetNull, &[bindings_register]);
+                    }
+                }
+                _ => {
+                    self.push_op(Op::SetNull, &[hint_register]);
+                    self.push_op(Op::SetNull, &[bindings_register]);
+                }
+            }
+        }
+
+        Ok((fields_start, field_count))
+    }
+
     fn compile_assign(
         &mut self,
         target: AstIndex,
@@ -994,9 +1483,12 @@ impl Compiler {
                     self.compile_assert_type(value_register, *type_hint, Some(target), ctx)?;
                 }
             }
+            Node::MapPattern(entries) => {
+                self.compile_unpack_map_pattern(value_register, entries, export_assignment, ctx)?;
+            }
             unexpected => {
                 return self.error(ErrorKind::UnexpectedNode {
-                    expected: "ID or Chain".into(),
+                    expected: "ID, Chain, or map pattern".into(),
                     unexpected: unexpected.clone(),
                 })
             }
@@ -1140,9 +1632,31 @@ impl Compiler {
                         self.push_op(IterNextQuiet, &[iter_register, 0, 0]);
                     }
                 }
+                Node::MapPattern(entries) => {
+                    let value_register = self.push_register()?;
+
+                    if rhs_is_temp_tuple {
+                        self.push_op(TempIndex, &[value_register, iter_register, i as u8]);
+                    } else {
+                        self.push_op(IterUnpack, &[value_register, iter_register]);
+                    }
+
+                    self.compile_unpack_map_pattern(
+                        value_register,
+                        entries,
+                        export_assignment,
+                        ctx,
+                    )?;
+
+                    if result.register.is_some() {
+                        self.push_op(SequencePush, &[value_register]);
+                    }
+
+                    self.pop_register()?; // value_register
+                }
                 unexpected => {
                     return self.error(ErrorKind::UnexpectedNode {
-                        expected: "ID or Chain".into(),
+                        expected: "ID, Chain, or map pattern".into(),
                         unexpected: unexpected.clone(),
                     })
                 }
@@ -1203,19 +1717,26 @@ impl Compiler {
     ) -> Result<()> {
         let type_node = ctx.node_with_span(type_hint);
         match &type_node.node {
-            Node::Type(type_index) => {
+            Node::Type { hint, .. } | Node::TypeUnion { hint, .. } => {
                 if self.settings.enable_type_checks {
+                    let stack_count = self.stack_count();
+                    let (bindings, binding_count) =
+                        self.compile_type_hint_bindings(type_hint, ctx)?;
+
                     if let Some(span_node_index) = span {
                         let span_node = ctx.node_with_span(span_node_index);
                         self.push_span(span_node, ctx.ast);
                     }
 
                     self.push_op(Op::AssertType, &[value_register]);
-                    self.push_var_u32((*type_index).into());
+                    self.push_var_u32((*hint).into());
+                    self.push_bytes(&[bindings, binding_count]);
 
                     if span.is_some() {
                         self.pop_span();
                     }
+
+                    self.truncate_register_stack(stack_count)?;
                 }
                 Ok(())
             }
@@ -1243,12 +1764,18 @@ impl Compiler {
     ) -> Result<usize> {
         let type_node = ctx.node_with_span(type_hint);
         match &type_node.node {
-            Node::Type(type_index) => {
+            Node::Type { hint, .. } | Node::TypeUnion { hint, .. } => {
+                let stack_count = self.stack_count();
+                let (bindings, binding_count) = self.compile_type_hint_bindings(type_hint, ctx)?;
+
                 self.push_span(type_node, ctx.ast);
                 self.push_op(Op::CheckType, &[value_register]);
-                self.push_var_u32((*type_index).into());
+                self.push_var_u32((*hint).into());
+                self.push_bytes(&[bindings, binding_count]);
                 let jump_placeholder = self.push_offset_placeholder();
                 self.pop_span();
+
+                self.truncate_register_stack(stack_count)?;
                 Ok(jump_placeholder)
             }
             unexpected => self.error(ErrorKind::UnexpectedNode {
@@ -1258,6 +1785,47 @@ impl Compiler {
         }
     }
 
+    // Loads the values that are bound to the names used in a type hint into consecutive registers
+    //
+    // Type hints can refer to values like interfaces, so the values that are assigned to the
+    // hint's names are made available to the runtime's type check. The names are loaded in the
+    // order in which they first appear in the hint, with null used for names that aren't assigned
+    // to local values, which are then looked up in the module's exports and the prelude.
+    //
+    // If none of the names refer to local values then no registers are used, and a count of zero
+    // is returned. Otherwise the registers are left on the stack, and should be removed by the
+    // caller.
+    fn compile_type_hint_bindings(
+        &mut self,
+        type_hint: AstIndex,
+        ctx: CompileNodeContext,
+    ) -> Result<(u8, u8)> {
+        let mut names = Vec::new();
+        type_hint_names(type_hint, ctx.ast, &mut names);
+
+        if !names
+            .iter()
+            .any(|name| self.frame().get_local_assigned_register(*name).is_some())
+        {
+            return Ok((0, 0));
+        }
+
+        let Ok(binding_count) = u8::try_from(names.len()) else {
+            return self.error(ErrorKind::TooManyContainerEntries(names.len()));
+        };
+
+        let bindings = self.frame().next_temporary_register();
+        for name in names {
+            let binding_register = self.push_register()?;
+            match self.frame().get_local_assigned_register(name) {
+                Some(local_register) => self.push_op(Op::Copy, &[binding_register, local_register]),
+                None => self.push_op(Op::SetNull, &[binding_register]),
+            }
+        }
+
+        Ok((bindings, binding_count))
+    }
+
     fn compile_value_export(&mut self, id: ConstantIndex, value_register: u8) -> Result<()> {
         let id_register = self.push_register()?;
         self.compile_load_string_constant(id_register, id);
@@ -1743,7 +2311,8 @@ impl Compiler {
         use AstBinaryOp::*;
 
         match op {
-            Add | Subtract | Multiply | Divide | Remainder => {
+            Add | Subtract | Multiply | Divide | IntegerDivide | Remainder | Power | BitwiseAnd
+            | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight => {
                 self.compile_arithmetic_op(op, lhs, rhs, ctx)
             }
             AddAssign | SubtractAssign | MultiplyAssign | DivideAssign | RemainderAssign => {
@@ -1752,7 +2321,7 @@ impl Compiler {
             Less | LessOrEqual | Greater | GreaterOrEqual | Equal | NotEqual => {
                 self.compile_comparison_op(op, lhs, rhs, ctx)
             }
-            And | Or => self.compile_logic_op(op, lhs, rhs, ctx),
+            And | Or | NullCoalesce => self.compile_logic_op(op, lhs, rhs, ctx),
             Pipe => self.compile_piped_call(lhs, rhs, ctx),
         }
     }
@@ -1771,7 +2340,14 @@ impl Compiler {
             Subtract => Op::Subtract,
             Multiply => Op::Multiply,
             Divide => Op::Divide,
+            IntegerDivide => Op::IntegerDivide,
             Remainder => Op::Remainder,
+            Power => Op::Power,
+            BitwiseAnd => Op::BitwiseAnd,
+            BitwiseOr => Op::BitwiseOr,
+            BitwiseXor => Op::BitwiseXor,
+            ShiftLeft => Op::ShiftLeft,
+            ShiftRight => Op::ShiftRight,
             _ => {
                 return self.error(ErrorKind::InvalidBinaryOp {
                     kind: "arithmetic".into(),
@@ -1836,6 +2412,12 @@ impl Compiler {
         let result = if let Node::Chain(chain_node) = lhs_node {
             self.compile_chain(chain_node, None, Some(rhs_register), Some(op), ctx)?
         } else {
+            if let Node::Id(id, ..) = lhs_node {
+                if self.frame().is_const_id(*id) {
+                    return self.error(ErrorKind::AssigningToAConst);
+                }
+            }
+
             let lhs = self.compile_node(lhs, ctx.with_any_register())?;
             let lhs_register = lhs.unwrap(self)?;
 
@@ -1983,6 +2565,7 @@ impl Compiler {
         let jump_op = match op {
             AstBinaryOp::And => Op::JumpIfFalse,
             AstBinaryOp::Or => Op::JumpIfTrue,
+            AstBinaryOp::NullCoalesce => Op::JumpIfNotNull,
             _ => unreachable!(),
         };
 
@@ -2086,6 +2669,10 @@ impl Compiler {
                                                 format_flags.as_byte(),
                                             ],
                                         );
+                                        let extended_flags = format_flags.as_extended_byte();
+                                        if extended_flags != 0 {
+                                            self.push_bytes(&[extended_flags]);
+                                        }
                                         if let Some(min_width) = format.min_width {
                                             self.push_var_u32(min_width);
                                         }
@@ -2095,6 +2682,9 @@ impl Compiler {
                                         if let Some(fill_constant) = format.fill_character {
                                             self.push_var_u32(fill_constant.into());
                                         }
+                                        if let Some(format_constant) = format.format_string {
+                                            self.push_var_u32(format_constant.into());
+                                        }
 
                                         if expression_result.is_temporary {
                                             self.pop_register()?;
@@ -2437,6 +3027,9 @@ impl Compiler {
 
         let mut chain_node = root_node.clone();
 
+        // Jump placeholders for `?` null checks, which skip to the end of the chain
+        let mut null_check_jumps = Vec::new();
+
         // Work through the chain, up until the last node, which will be handled separately
         while next_node_index.is_some() {
             match &chain_node {
@@ -2535,6 +3128,24 @@ impl Compiler {
                         ctx.with_fixed_register(node_register),
                     )?;
                 }
+                ChainNode::NullCheck => {
+                    // Short-circuit the rest of the chain if the previous node is null
+                    // e.g. x?.foo
+                    //    - x = Root
+                    //    - ? = NullCheck
+                    //    - foo = Id
+
+                    if rhs.is_some() {
+                        return self.error(ErrorKind::NullCheckInAssignment);
+                    }
+
+                    let Some(checked_register) = chain_nodes.previous() else {
+                        return self.error(ErrorKind::OutOfPositionChildNodeInChain);
+                    };
+
+                    self.push_op(JumpIfNull, &[checked_register]);
+                    null_check_jumps.push(self.push_offset_placeholder());
+                }
             }
 
             // Is the chain complete?
@@ -2643,6 +3254,7 @@ impl Compiler {
                     chain_nodes.push(result_register, false);
                 }
             }
+            ChainNode::NullCheck => return self.error(ErrorKind::MissingNextChainNode),
             _ => {}
         }
 
@@ -2714,6 +3326,25 @@ impl Compiler {
             )?;
         }
 
+        // If a null check short-circuited the chain, then the result is null
+        if !null_check_jumps.is_empty() {
+            let skip_null_result = if result.register.is_some() {
+                self.push_op(Jump, &[]);
+                Some(self.push_offset_placeholder())
+            } else {
+                None
+            };
+
+            for placeholder in null_check_jumps {
+                self.update_offset_placeholder(placeholder)?;
+            }
+
+            if let Some(skip_null_result) = skip_null_result {
+                self.push_op(SetNull, &[result.register.unwrap()]);
+                self.update_offset_placeholder(skip_null_result)?;
+            }
+        }
+
         // Clean up the span and register stacks
         self.span_stack.truncate(span_stack_count);
         self.truncate_register_stack(stack_count)?;
@@ -3143,6 +3774,10 @@ impl Compiler {
             _ => 1,
         };
 
+        if match_len == 1 {
+            self.check_match_exhaustiveness(arms, ctx);
+        }
+
         // Compile the match arms, collecting their jump offset placeholders
         let arm_jump_placeholders = arms
             .iter()
@@ -3167,6 +3802,47 @@ impl Compiler {
         Ok(result)
     }
 
+    // Emits a warning if a match on a known finite set of values doesn't cover all of the values
+    //
+    // Currently the only known finite set is Bool, i.e. when all of the arms' patterns are
+    // `true` or `false` and there's no `else` arm.
+    fn check_match_exhaustiveness(&mut self, arms: &[MatchArm], ctx: CompileNodeContext) {
+        let mut true_covered = false;
+        let mut false_covered = false;
+        let mut has_patterns = false;
+
+        for arm in arms {
+            if arm.is_else() {
+                return;
+            }
+
+            for pattern in arm.patterns.iter() {
+                let covered = match ctx.node(*pattern) {
+                    Node::BoolTrue => &mut true_covered,
+                    Node::BoolFalse => &mut false_covered,
+                    // The set of possible values isn't known
+                    _ => return,
+                };
+                // Patterns in arms with conditions don't cover their values
+                if arm.condition.is_none() {
+                    *covered = true;
+                }
+                has_patterns = true;
+            }
+        }
+
+        let missing = match (true_covered, false_covered) {
+            (true, true) => return,
+            (true, false) => "`false`",
+            (false, true) => "`true`",
+            (false, false) => "`true`, `false`",
+        };
+
+        if has_patterns {
+            self.warning(WarningKind::NonExhaustiveMatch(missing.into()));
+        }
+    }
+
     fn compile_match_arm(
         &mut self,
         result: CompileNodeOutput,
@@ -3319,22 +3995,34 @@ impl Compiler {
                 | Node::Int(_)
                 | Node::Float(_)
                 | Node::Str(_)
-                | Node::Chain(_) => {
+                | Node::Chain(_)
+                | Node::Range { .. }
+                | Node::RangeFrom { .. }
+                | Node::RangeTo { .. } => {
                     let pattern_register = self.push_register()?;
                     self.compile_node(*pattern, ctx.with_fixed_register(pattern_register))?;
                     let comparison = self.push_register()?;
 
+                    // Range patterns match values contained in the range,
+                    // other patterns need to be equal to the value.
+                    let comparison_op = match &pattern_node.node {
+                        Node::Range { .. } | Node::RangeFrom { .. } | Node::RangeTo { .. } => {
+                            RangeContains
+                        }
+                        _ => Equal,
+                    };
+
                     if match_is_container {
                         let element = self.push_register()?;
                         self.push_op(
                             TempIndex,
                             &[element, params.match_register, pattern_index as u8],
                         );
-                        self.push_op(Equal, &[comparison, pattern_register, element]);
+                        self.push_op(comparison_op, &[comparison, pattern_register, element]);
                         self.pop_register()?; // element
                     } else {
                         self.push_op(
-                            Equal,
+                            comparison_op,
                             &[comparison, pattern_register, params.match_register],
                         );
                     }
@@ -3387,7 +4075,7 @@ impl Compiler {
                     }
 
                     // The variable has received its value, is a jump needed?
-                    if is_last_pattern && !params.is_last_alternative {
+                    if params.has_last_pattern && is_last_pattern && !params.is_last_alternative {
                         // e.g. x, 0, y or x, 1, y if x == y then
                         //            ^ ~~~~~~ We're here, jump to the if condition
                         self.push_op(Jump, &[]);
@@ -3419,7 +4107,7 @@ impl Compiler {
                     }
 
                     // The wildcard has been validated, is a jump needed?
-                    if is_last_pattern && !params.is_last_alternative {
+                    if params.has_last_pattern && is_last_pattern && !params.is_last_alternative {
                         // e.g. x, 0, _ or x, 1, y if foo x then
                         //            ^~~~~~~ We're here, jump to the if condition
                         self.push_op(Jump, &[]);
@@ -3439,6 +4127,36 @@ impl Compiler {
                         ctx,
                     )?;
                 }
+                Node::MapPattern(entries) => {
+                    self.compile_match_map_pattern(
+                        MatchArmParameters {
+                            match_register: params.match_register,
+                            is_last_alternative: params.is_last_alternative,
+                            has_last_pattern: params.has_last_pattern && is_last_pattern,
+                            jumps: params.jumps,
+                        },
+                        match_is_container.then_some(pattern_index),
+                        entries,
+                        ctx,
+                    )?;
+                }
+                Node::RecordPattern {
+                    constructor,
+                    fields,
+                } => {
+                    self.compile_match_record_pattern(
+                        MatchArmParameters {
+                            match_register: params.match_register,
+                            is_last_alternative: params.is_last_alternative,
+                            has_last_pattern: params.has_last_pattern && is_last_pattern,
+                            jumps: params.jumps,
+                        },
+                        match_is_container.then_some(pattern_index),
+                        *constructor,
+                        fields,
+                        ctx,
+                    )?;
+                }
                 Node::Ellipsis(maybe_id) => {
                     if is_last_pattern {
                         if let Some(id) = maybe_id {
@@ -3452,7 +4170,7 @@ impl Compiler {
                             );
                         }
 
-                        if !params.is_last_alternative {
+                        if params.has_last_pattern && !params.is_last_alternative {
                             // Ellipses match unconditionally in last position,
                             // multi-expression pattern, skip over the remaining alternatives
                             // e.g. (x, 0, rest...) or (x, 1, y) if rest.size() > 0 then
@@ -3573,6 +4291,269 @@ impl Compiler {
         Ok(())
     }
 
+    // Compiles a record pattern in a match arm
+    //
+    // The match fails if the value wasn't made by the record's constructor, otherwise the values
+    // of the record's fields are matched against the nested patterns.
+    fn compile_match_record_pattern(
+        &mut self,
+        params: MatchArmParameters,
+        pattern_index: Option<i8>,
+        constructor: AstIndex,
+        fields: &[AstIndex],
+        ctx: CompileNodeContext,
+    ) -> Result<()> {
+        use Op::*;
+
+        let stack_count = self.stack_count();
+
+        let value_register = if let Some(pattern_index) = pattern_index {
+            // Place the nested record into a register
+            let value_register = self.push_register()?;
+            self.push_op(
+                TempIndex,
+                &[value_register, params.match_register, pattern_index as u8],
+            );
+            value_register
+        } else {
+            params.match_register
+        };
+
+        let constructor_register = self.push_register()?;
+        self.compile_node(constructor, ctx.with_fixed_register(constructor_register))?;
+
+        // Patterns that are also valid call expressions, e.g. `f(1)`, are evaluated as calls
+        // if the constructor turns out to be something other than a record constructor.
+        let record_placeholder = if fields_are_expressions(fields, ctx.ast) {
+            self.push_op(CheckRecordConstructor, &[constructor_register]);
+            let fallback_placeholder = self.push_offset_placeholder();
+            self.push_op(Jump, &[]);
+            let record_placeholder = self.push_offset_placeholder();
+
+            self.update_offset_placeholder(fallback_placeholder)?;
+            self.compile_match_call_pattern(
+                MatchArmParameters {
+                    match_register: value_register,
+                    is_last_alternative: params.is_last_alternative,
+                    has_last_pattern: params.has_last_pattern,
+                    jumps: params.jumps,
+                },
+                constructor_register,
+                fields,
+                ctx,
+            )?;
+            self.push_op(Jump, &[]);
+            let end_placeholder = self.push_offset_placeholder();
+
+            self.update_offset_placeholder(record_placeholder)?;
+            Some(end_placeholder)
+        } else {
+            None
+        };
+
+        let fields_register = self.push_register()?;
+        self.push_op(
+            CheckRecord,
+            &[fields_register, value_register, constructor_register],
+        );
+        params
+            .jumps
+            .push_failed_check(self.push_offset_placeholder(), params.is_last_alternative);
+
+        // Jumps after a successful match are handled below,
+        // so the field patterns are compiled without a last pattern.
+        self.compile_nested_match_arm_patterns(
+            MatchArmParameters {
+                match_register: fields_register,
+                is_last_alternative: params.is_last_alternative,
+                has_last_pattern: false,
+                jumps: params.jumps,
+            },
+            None,
+            fields,
+            ctx,
+        )?;
+
+        // The record has been matched, is a jump needed?
+        if params.has_last_pattern && !params.is_last_alternative {
+            // e.g. Shape.Circle(0) or Shape.Square(0) then
+            //      ^~~~~~~~~~~~~~~ We're here, skip over the remaining alternatives
+            self.push_op(Jump, &[]);
+            params.jumps.match_end.push(self.push_offset_placeholder());
+        }
+
+        if let Some(end_placeholder) = record_placeholder {
+            self.update_offset_placeholder(end_placeholder)?;
+        }
+
+        self.truncate_register_stack(stack_count)
+    }
+
+    // Compiles a record pattern as a call expression, with the result compared to the value
+    //
+    // See compile_match_record_pattern.
+    fn compile_match_call_pattern(
+        &mut self,
+        params: MatchArmParameters,
+        function_register: u8,
+        args: &[AstIndex],
+        ctx: CompileNodeContext,
+    ) -> Result<()> {
+        use Op::*;
+
+        let stack_count = self.stack_count();
+
+        let result_register = self.push_register()?;
+        self.compile_call(
+            function_register,
+            args,
+            None,
+            None,
+            ctx.with_fixed_register(result_register),
+        )?;
+        self.push_op(
+            Equal,
+            &[result_register, result_register, params.match_register],
+        );
+
+        if params.is_last_alternative {
+            // No match on the last alternative, jump to the end of the arm
+            self.push_op(JumpIfFalse, &[result_register]);
+            params.jumps.arm_end.push(self.push_offset_placeholder());
+        } else if params.has_last_pattern {
+            // A match with remaining alternatives, jump to the end of the alternatives
+            self.push_op(JumpIfTrue, &[result_register]);
+            params.jumps.match_end.push(self.push_offset_placeholder());
+        } else {
+            // No match with remaining alternatives, jump to the next alternative
+            self.push_op(JumpIfFalse, &[result_register]);
+            params
+                .jumps
+                .alternative_end
+                .push(self.push_offset_placeholder());
+        }
+
+        self.truncate_register_stack(stack_count)
+    }
+
+    // Compiles a map pattern in a match arm
+    //
+    // The match fails if the value isn't a map, or if any of the pattern's entries are missing.
+    //
+    // See also: compile_unpack_map_pattern
+    fn compile_match_map_pattern(
+        &mut self,
+        params: MatchArmParameters,
+        pattern_index: Option<i8>,
+        entries: &[(AstIndex, Option<AstIndex>)],
+        ctx: CompileNodeContext,
+    ) -> Result<()> {
+        use Op::*;
+
+        let stack_count = self.stack_count();
+
+        let map_register = if let Some(pattern_index) = pattern_index {
+            // Place the nested map into a register
+            let map_register = self.push_register()?;
+            self.push_op(
+                TempIndex,
+                &[map_register, params.match_register, pattern_index as u8],
+            );
+            map_register
+        } else {
+            params.match_register
+        };
+
+        self.push_op(CheckMap, &[map_register]);
+        params
+            .jumps
+            .push_failed_check(self.push_offset_placeholder(), params.is_last_alternative);
+
+        for (key, value) in entries.iter() {
+            let key_node = ctx.node_with_span(*key);
+            self.push_span(key_node, ctx.ast);
+
+            match (&key_node.node, value) {
+                (Node::Ellipsis(maybe_id), None) => {
+                    if let Some(id) = maybe_id {
+                        // e.g. `{foo, rest...}`
+                        let id_register = self.assign_local_register(*id)?;
+                        self.compile_map_rest(id_register, map_register, entries, ctx)?;
+                    }
+                }
+                (Node::Id(id, ..), None) => {
+                    // e.g. `{foo}`
+                    let id_register = self.assign_local_register(*id)?;
+                    self.push_op(CheckMapEntry, &[id_register, map_register]);
+                    self.push_var_u32((*id).into());
+                    params.jumps.push_failed_check(
+                        self.push_offset_placeholder(),
+                        params.is_last_alternative,
+                    );
+                }
+                (key_node, Some(value)) => {
+                    // e.g. `{foo: 42}`, or `{foo: (x, y)}`
+                    let key = self.map_pattern_key(key_node)?;
+                    let value_register = self.push_register()?;
+                    self.push_op(CheckMapEntry, &[value_register, map_register]);
+                    self.push_var_u32(key.into());
+                    params.jumps.push_failed_check(
+                        self.push_offset_placeholder(),
+                        params.is_last_alternative,
+                    );
+
+                    // Jumps after a successful match are handled below once all entries have
+                    // been checked, so the value pattern is compiled without a last pattern.
+                    let value_params = MatchArmParameters {
+                        match_register: value_register,
+                        is_last_alternative: params.is_last_alternative,
+                        has_last_pattern: false,
+                        jumps: params.jumps,
+                    };
+
+                    match ctx.node(*value) {
+                        Node::Tuple(patterns) => {
+                            self.compile_nested_match_arm_patterns(
+                                value_params,
+                                None,
+                                patterns,
+                                ctx,
+                            )?;
+                        }
+                        Node::MapPattern(nested_entries) => {
+                            self.compile_match_map_pattern(
+                                value_params,
+                                None,
+                                nested_entries,
+                                ctx,
+                            )?;
+                        }
+                        _ => {
+                            self.compile_match_arm_patterns(value_params, false, &[*value], ctx)?;
+                        }
+                    }
+
+                    self.pop_register()?; // value_register
+                }
+                (unexpected, None) => {
+                    return self.error(ErrorKind::InvalidMatchPattern(unexpected.clone()));
+                }
+            }
+
+            self.pop_span();
+        }
+
+        // The map has been matched, is a jump needed?
+        if params.has_last_pattern && !params.is_last_alternative {
+            // e.g. {foo: 0} or {bar: 1} then
+            //      ^~~~~~~~ We're here, skip over the remaining alternatives
+            self.push_op(Jump, &[]);
+            params.jumps.match_end.push(self.push_offset_placeholder());
+        }
+
+        self.truncate_register_stack(stack_count)
+    }
+
     fn compile_for(
         &mut self,
         ast_for: &AstFor,
@@ -3932,12 +4913,20 @@ impl Compiler {
     }
 
     fn assign_local_register(&mut self, local: ConstantIndex) -> Result<u8> {
+        if self.frame().is_const_id(local) {
+            return self.error(ErrorKind::AssigningToAConst);
+        }
+
         self.frame_mut()
             .assign_local_register(local)
             .map_err(|e| self.make_error(e))
     }
 
     fn reserve_local_register(&mut self, local: ConstantIndex) -> Result<u8> {
+        if self.frame().is_const_id(local) {
+            return self.error(ErrorKind::AssigningToAConst);
+        }
+
         self.frame_mut()
             .reserve_local_register(local)
             .map_err(|e| self.make_error(e))
@@ -3966,6 +4955,13 @@ impl Compiler {
         }
     }
 
+    fn warning(&mut self, warning: WarningKind) {
+        let span = self.span();
+        self.debug_info
+            .warnings
+            .push(CompilerWarning { warning, span });
+    }
+
     fn push_span(&mut self, node: &AstNode, ast: &Ast) {
         self.span_stack.push(*ast.span(node.span));
     }
@@ -3990,6 +4986,44 @@ fn args_size_op(args: &[AstIndex], ast: &Ast) -> (Op, usize) {
     }
 }
 
+// Collects the names used in a type hint, in the order in which they first appear
+fn type_hint_names(type_hint: AstIndex, ast: &Ast, names: &mut Vec<ConstantIndex>) {
+    match &ast.node(type_hint).node {
+        Node::Type { name, nested, .. } => {
+            if !names.contains(name) {
+                names.push(*name);
+            }
+            for nested_type in nested.iter() {
+                type_hint_names(*nested_type, ast, names);
+            }
+        }
+        Node::TypeUnion { types, .. } => {
+            for member in types.iter() {
+                type_hint_names(*member, ast, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+// Returns true if the fields of a record pattern can also be compiled as call arguments
+fn fields_are_expressions(fields: &[AstIndex], ast: &Ast) -> bool {
+    fields.iter().all(|field| {
+        matches!(
+            &ast.node(*field).node,
+            Node::Null
+                | Node::BoolTrue
+                | Node::BoolFalse
+                | Node::SmallInt(_)
+                | Node::Int(_)
+                | Node::Float(_)
+                | Node::Str(_)
+                | Node::Id(_, None)
+                | Node::Chain(_)
+        )
+    })
+}
+
 #[derive(Default)]
 struct MatchJumpPlaceholders {
     // Jumps to the end of the arm
@@ -4005,6 +5039,18 @@ struct MatchJumpPlaceholders {
     alternative_end: Vec<usize>,
 }
 
+impl MatchJumpPlaceholders {
+    // Failed checks jump to the next alternative,
+    // or to the end of the arm if there are no more alternatives
+    fn push_failed_check(&mut self, placeholder: usize, is_last_alternative: bool) {
+        if is_last_alternative {
+            self.arm_end.push(placeholder);
+        } else {
+            self.alternative_end.push(placeholder);
+        }
+    }
+}
+
 struct MatchArmParameters<'a> {
     match_register: u8,
     is_last_alternative: bool,
@@ -72,6 +72,8 @@ pub(crate) struct Frame {
     register_stack: Vec<u8>,
     local_registers: Vec<LocalRegister>,
     exported_ids: HashSet<ConstantIndex>,
+    // Ids that have been declared with `const`, and can't be reassigned
+    const_ids: HashSet<ConstantIndex>,
     temporary_base: u8,
     temporary_count: u8,
     // Used to decide if an additional return instruction is needed,
@@ -205,6 +207,14 @@ impl Frame {
         self.exported_ids.insert(id);
     }
 
+    pub fn add_const_id(&mut self, id: ConstantIndex) {
+        self.const_ids.insert(id);
+    }
+
+    pub fn is_const_id(&self, id: ConstantIndex) -> bool {
+        self.const_ids.contains(&id)
+    }
+
     pub fn defer_op_until_register_is_committed(
         &mut self,
         reserved_register: u8,
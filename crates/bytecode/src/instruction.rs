@@ -1,6 +1,8 @@
 use std::fmt;
 
-use koto_parser::{ConstantIndex, MetaKeyId, StringAlignment, StringFormatOptions};
+use koto_parser::{
+    ConstantIndex, MetaKeyId, StringAlignment, StringFormatOptions, StringFormatRepresentation,
+};
 
 /// Decoded instructions produced by an [InstructionReader](crate::InstructionReader) for execution
 /// in the runtime
@@ -149,11 +151,46 @@ pub enum Instruction {
         lhs: u8,
         rhs: u8,
     },
+    IntegerDivide {
+        register: u8,
+        lhs: u8,
+        rhs: u8,
+    },
     Remainder {
         register: u8,
         lhs: u8,
         rhs: u8,
     },
+    Power {
+        register: u8,
+        lhs: u8,
+        rhs: u8,
+    },
+    BitwiseAnd {
+        register: u8,
+        lhs: u8,
+        rhs: u8,
+    },
+    BitwiseOr {
+        register: u8,
+        lhs: u8,
+        rhs: u8,
+    },
+    BitwiseXor {
+        register: u8,
+        lhs: u8,
+        rhs: u8,
+    },
+    ShiftLeft {
+        register: u8,
+        lhs: u8,
+        rhs: u8,
+    },
+    ShiftRight {
+        register: u8,
+        lhs: u8,
+        rhs: u8,
+    },
     AddAssign {
         lhs: u8,
         rhs: u8,
@@ -218,6 +255,14 @@ pub enum Instruction {
         register: u8,
         offset: u16,
     },
+    JumpIfNull {
+        register: u8,
+        offset: u16,
+    },
+    JumpIfNotNull {
+        register: u8,
+        offset: u16,
+    },
     Call {
         result: u8,
         function: u8,
@@ -330,12 +375,76 @@ pub enum Instruction {
     AssertType {
         value: u8,
         type_string: ConstantIndex,
+        bindings: u8,
+        binding_count: u8,
     },
     CheckType {
         value: u8,
         type_string: ConstantIndex,
+        bindings: u8,
+        binding_count: u8,
         jump_offset: u16,
     },
+    AssertMap {
+        value: u8,
+    },
+    CheckMap {
+        value: u8,
+        jump_offset: u16,
+    },
+    AccessMapEntry {
+        register: u8,
+        map: u8,
+        key: ConstantIndex,
+    },
+    CheckMapEntry {
+        register: u8,
+        map: u8,
+        key: ConstantIndex,
+        jump_offset: u16,
+    },
+    MapRest {
+        register: u8,
+        map: u8,
+        keys_start: u8,
+        key_count: u8,
+    },
+    RangeContains {
+        register: u8,
+        range: u8,
+        value: u8,
+    },
+    Freeze {
+        value: u8,
+    },
+    MakeRecord {
+        register: u8,
+        fields_start: u8,
+        field_count: u8,
+        name: ConstantIndex,
+    },
+    MakeEnumVariant {
+        register: u8,
+        fields_start: u8,
+        field_count: u8,
+        enum_name: ConstantIndex,
+        variant_name: ConstantIndex,
+    },
+    CheckRecord {
+        register: u8,
+        value: u8,
+        constructor: u8,
+        jump_offset: u16,
+    },
+    CheckRecordConstructor {
+        value: u8,
+        jump_offset: u16,
+    },
+    MakeInterface {
+        register: u8,
+        keys: u8,
+        name: ConstantIndex,
+    },
     StringStart {
         size_hint: u32,
     },
@@ -392,6 +501,9 @@ impl FunctionFlags {
 }
 
 /// Format flags used by the [StringPush][crate::Op::StringPush] op
+///
+/// The flags are packed into one byte, followed by a second byte containing the extended flags
+/// if the [EXTENDED](Self::EXTENDED) flag is set.
 pub struct StringFormatFlags {
     /// The alignment of the string
     pub alignment: StringAlignment,
@@ -401,6 +513,16 @@ pub struct StringFormatFlags {
     pub precision: bool,
     /// True if a fill character is specified
     pub fill_character: bool,
+    /// True if a custom format string is specified
+    pub format_string: bool,
+    /// The representation to use when formatting numbers
+    pub representation: StringFormatRepresentation,
+    /// True if a `+` sign should be shown for non-negative numbers
+    pub show_sign: bool,
+    /// True if the alternate form of the representation should be used
+    pub alternate: bool,
+    /// True if thousands separators should be used
+    pub thousands_separator: bool,
 }
 
 impl StringFormatFlags {
@@ -410,9 +532,22 @@ impl StringFormatFlags {
     pub const PRECISION: u8 = 1 << 3;
     /// Set to true when fill_character is defined
     pub const FILL_CHARACTER: u8 = 1 << 4;
+    /// Set to true when format_string is defined
+    pub const FORMAT_STRING: u8 = 1 << 5;
+    /// Set to true when the flags are followed by a byte containing extended flags
+    pub const EXTENDED: u8 = 1 << 6;
+
+    /// Extended flag, set to true when show_sign is enabled
+    pub const SHOW_SIGN: u8 = 1 << 3; // The first three bits are taken up by the representation
+    /// Extended flag, set to true when alternate is enabled
+    pub const ALTERNATE: u8 = 1 << 4;
+    /// Extended flag, set to true when thousands_separator is enabled
+    pub const THOUSANDS_SEPARATOR: u8 = 1 << 5;
 
     /// Decodes a byte into format flags
-    pub fn from_byte(byte: u8) -> Self {
+    ///
+    /// The extended flags should be provided if the [EXTENDED](Self::EXTENDED) flag is set.
+    pub fn from_bytes(byte: u8, extended: u8) -> Self {
         use StringAlignment::*;
         let alignment_bits = byte & 0b11;
         let alignment = if alignment_bits == Default as u8 {
@@ -424,15 +559,34 @@ impl StringFormatFlags {
         } else {
             Right
         };
+        let representation = {
+            use StringFormatRepresentation::*;
+            match extended & 0b111 {
+                1 => Binary,
+                2 => Octal,
+                3 => HexLower,
+                4 => HexUpper,
+                5 => ExpLower,
+                6 => ExpUpper,
+                _ => Default,
+            }
+        };
         Self {
             alignment,
             min_width: byte & Self::MIN_WIDTH != 0,
             precision: byte & Self::PRECISION != 0,
             fill_character: byte & Self::FILL_CHARACTER != 0,
+            format_string: byte & Self::FORMAT_STRING != 0,
+            representation,
+            show_sign: extended & Self::SHOW_SIGN != 0,
+            alternate: extended & Self::ALTERNATE != 0,
+            thousands_separator: extended & Self::THOUSANDS_SEPARATOR != 0,
         }
     }
 
     /// Returns a byte containing the packed flags
+    ///
+    /// The [EXTENDED](Self::EXTENDED) flag is set if [Self::as_extended_byte] is non-zero.
     pub fn as_byte(&self) -> u8 {
         let mut result = self.alignment as u8;
 
@@ -445,9 +599,31 @@ impl StringFormatFlags {
         if self.fill_character {
             result |= Self::FILL_CHARACTER;
         }
+        if self.format_string {
+            result |= Self::FORMAT_STRING;
+        }
+        if self.as_extended_byte() != 0 {
+            result |= Self::EXTENDED;
+        }
 
         result
     }
+
+    /// Returns a byte containing the packed extended flags
+    pub fn as_extended_byte(&self) -> u8 {
+        let mut result = self.representation as u8;
+
+        if self.show_sign {
+            result |= Self::SHOW_SIGN;
+        }
+        if self.alternate {
+            result |= Self::ALTERNATE;
+        }
+        if self.thousands_separator {
+            result |= Self::THOUSANDS_SEPARATOR;
+        }
+        result
+    }
 }
 
 impl From<StringFormatOptions> for StringFormatFlags {
@@ -457,6 +633,11 @@ impl From<StringFormatOptions> for StringFormatFlags {
             min_width: options.min_width.is_some(),
             precision: options.precision.is_some(),
             fill_character: options.fill_character.is_some(),
+            format_string: options.format_string.is_some(),
+            representation: options.representation,
+            show_sign: options.show_sign,
+            alternate: options.alternate,
+            thousands_separator: options.thousands_separator,
         }
     }
 }
@@ -579,9 +760,33 @@ impl fmt::Debug for Instruction {
             Divide { register, lhs, rhs } => {
                 write!(f, "Divide\t\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
             }
+            IntegerDivide { register, lhs, rhs } => {
+                write!(f, "IntDivide\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
+            }
             Remainder { register, lhs, rhs } => {
                 write!(f, "Remainder\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
             }
+            Power { register, lhs, rhs } => {
+                write!(f, "Power\t\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
+            }
+            BitwiseAnd { register, lhs, rhs } => {
+                write!(f, "BitAnd\t\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
+            }
+            BitwiseOr { register, lhs, rhs } => {
+                write!(f, "BitOr\t\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
+            }
+            BitwiseXor { register, lhs, rhs } => {
+                write!(f, "BitXor\t\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
+            }
+            ShiftLeft { register, lhs, rhs } => {
+                write!(f, "ShiftLeft\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}")
+            }
+            ShiftRight { register, lhs, rhs } => {
+                write!(
+                    f,
+                    "ShiftRight\tresult: {register}\tlhs: {lhs}\t\trhs: {rhs}"
+                )
+            }
             AddAssign { lhs, rhs } => {
                 write!(f, "AddAssign\tlhs: {lhs}\t\trhs: {rhs}")
             }
@@ -625,6 +830,12 @@ impl fmt::Debug for Instruction {
             JumpIfFalse { register, offset } => {
                 write!(f, "JumpIfFalse\tresult: {register}\toffset: {offset}")
             }
+            JumpIfNull { register, offset } => {
+                write!(f, "JumpIfNull\tresult: {register}\toffset: {offset}")
+            }
+            JumpIfNotNull { register, offset } => {
+                write!(f, "JumpIfNotNull\tresult: {register}\toffset: {offset}")
+            }
             Call {
                 result,
                 function,
@@ -764,19 +975,113 @@ impl fmt::Debug for Instruction {
             CheckSizeMin { register, size } => {
                 write!(f, "CheckSizeMin\tvalue: {register}\tsize: {size}")
             }
-            AssertType { value, type_string } => {
-                write!(f, "AssertType\tvalue: {value}\ttype: {type_string}")
+            AssertType {
+                value,
+                type_string,
+                bindings,
+                binding_count,
+            } => {
+                write!(
+                    f,
+                    "AssertType\tvalue: {value}\ttype: {type_string}\
+                     \tbindings: {bindings}\tcount: {binding_count}"
+                )
             }
             CheckType {
                 value,
-                jump_offset,
                 type_string,
+                bindings,
+                binding_count,
+                jump_offset,
             } => {
                 write!(
                     f,
-                    "CheckType\tvalue: {value}\ttype: {type_string}\toffset: {jump_offset}"
+                    "CheckType\tvalue: {value}\ttype: {type_string}\
+                     \tbindings: {bindings}\tcount: {binding_count}\toffset: {jump_offset}"
+                )
+            }
+            AssertMap { value } => write!(f, "AssertMap\tvalue: {value}"),
+            CheckMap { value, jump_offset } => {
+                write!(f, "CheckMap\tvalue: {value}\toffset: {jump_offset}")
+            }
+            AccessMapEntry { register, map, key } => {
+                write!(
+                    f,
+                    "AccessMapEntry\tresult: {register}\tmap: {map}\tkey: {key}"
+                )
+            }
+            CheckMapEntry {
+                register,
+                map,
+                key,
+                jump_offset,
+            } => write!(
+                f,
+                "CheckMapEntry\tresult: {register}\tmap: {map}\tkey: {key}\toffset: {jump_offset}"
+            ),
+            MapRest {
+                register,
+                map,
+                keys_start,
+                key_count,
+            } => write!(
+                f,
+                "MapRest\t\tresult: {register}\tmap: {map}\tkeys: {keys_start}\tcount: {key_count}"
+            ),
+            RangeContains {
+                register,
+                range,
+                value,
+            } => write!(
+                f,
+                "RangeContains\tresult: {register}\trange: {range}\tvalue: {value}"
+            ),
+            Freeze { value } => write!(f, "Freeze\t\tvalue: {value}"),
+            MakeRecord {
+                register,
+                fields_start,
+                field_count,
+                name,
+            } => write!(
+                f,
+                "MakeRecord\tresult: {register}\tfields: {fields_start}\tcount: {field_count}\
+                 \tname: {name}"
+            ),
+            MakeEnumVariant {
+                register,
+                fields_start,
+                field_count,
+                enum_name,
+                variant_name,
+            } => write!(
+                f,
+                "MakeEnumVariant\tresult: {register}\tfields: {fields_start}\tcount: {field_count}\
+                 \tenum: {enum_name}\tvariant: {variant_name}"
+            ),
+            CheckRecord {
+                register,
+                value,
+                constructor,
+                jump_offset,
+            } => write!(
+                f,
+                "CheckRecord\tresult: {register}\tvalue: {value}\tconstructor: {constructor}\
+                 \toffset: {jump_offset}"
+            ),
+            CheckRecordConstructor { value, jump_offset } => {
+                write!(
+                    f,
+                    "CheckRecordConstructor\tvalue: {value}\toffset: {jump_offset}"
                 )
             }
+            MakeInterface {
+                register,
+                keys,
+                name,
+            } => write!(
+                f,
+                "MakeInterface\tresult: {register}\tkeys: {keys}\tname: {name}"
+            ),
             StringStart { size_hint } => {
                 write!(f, "StringStart\tsize hint: {size_hint}")
             }
@@ -796,6 +1101,9 @@ impl fmt::Debug for Instruction {
                     if let Some(fill_character) = opts.fill_character {
                         write!(f, "\tfill_character: {fill_character}")?;
                     }
+                    if let Some(format_string) = opts.format_string {
+                        write!(f, "\tformat_string: {format_string}")?;
+                    }
                 }
                 Ok(())
             }
@@ -251,11 +251,46 @@ impl Iterator for InstructionReader {
                 lhs: get_u8!(),
                 rhs: get_u8!(),
             }),
+            Op::IntegerDivide => Some(IntegerDivide {
+                register: get_u8!(),
+                lhs: get_u8!(),
+                rhs: get_u8!(),
+            }),
             Op::Remainder => Some(Remainder {
                 register: get_u8!(),
                 lhs: get_u8!(),
                 rhs: get_u8!(),
             }),
+            Op::Power => Some(Power {
+                register: get_u8!(),
+                lhs: get_u8!(),
+                rhs: get_u8!(),
+            }),
+            Op::BitwiseAnd => Some(BitwiseAnd {
+                register: get_u8!(),
+                lhs: get_u8!(),
+                rhs: get_u8!(),
+            }),
+            Op::BitwiseOr => Some(BitwiseOr {
+                register: get_u8!(),
+                lhs: get_u8!(),
+                rhs: get_u8!(),
+            }),
+            Op::BitwiseXor => Some(BitwiseXor {
+                register: get_u8!(),
+                lhs: get_u8!(),
+                rhs: get_u8!(),
+            }),
+            Op::ShiftLeft => Some(ShiftLeft {
+                register: get_u8!(),
+                lhs: get_u8!(),
+                rhs: get_u8!(),
+            }),
+            Op::ShiftRight => Some(ShiftRight {
+                register: get_u8!(),
+                lhs: get_u8!(),
+                rhs: get_u8!(),
+            }),
             Op::AddAssign => Some(AddAssign {
                 lhs: get_u8!(),
                 rhs: get_u8!(),
@@ -316,6 +351,14 @@ impl Iterator for InstructionReader {
                 register: get_u8!(),
                 offset: get_u16!(),
             }),
+            Op::JumpIfNull => Some(JumpIfNull {
+                register: get_u8!(),
+                offset: get_u16!(),
+            }),
+            Op::JumpIfNotNull => Some(JumpIfNotNull {
+                register: get_u8!(),
+                offset: get_u16!(),
+            }),
             Op::Call => Some(Call {
                 result: get_u8!(),
                 function: get_u8!(),
@@ -491,12 +534,72 @@ impl Iterator for InstructionReader {
             Op::AssertType => Some(AssertType {
                 value: get_u8!(),
                 type_string: get_var_u32!().into(),
+                bindings: get_u8!(),
+                binding_count: get_u8!(),
             }),
             Op::CheckType => Some(CheckType {
                 value: get_u8!(),
                 type_string: get_var_u32!().into(),
+                bindings: get_u8!(),
+                binding_count: get_u8!(),
+                jump_offset: get_u16!(),
+            }),
+            Op::AssertMap => Some(AssertMap { value: get_u8!() }),
+            Op::CheckMap => Some(CheckMap {
+                value: get_u8!(),
+                jump_offset: get_u16!(),
+            }),
+            Op::AccessMapEntry => Some(AccessMapEntry {
+                register: get_u8!(),
+                map: get_u8!(),
+                key: get_var_u32!().into(),
+            }),
+            Op::CheckMapEntry => Some(CheckMapEntry {
+                register: get_u8!(),
+                map: get_u8!(),
+                key: get_var_u32!().into(),
+                jump_offset: get_u16!(),
+            }),
+            Op::MapRest => Some(MapRest {
+                register: get_u8!(),
+                map: get_u8!(),
+                keys_start: get_u8!(),
+                key_count: get_u8!(),
+            }),
+            Op::RangeContains => Some(RangeContains {
+                register: get_u8!(),
+                range: get_u8!(),
+                value: get_u8!(),
+            }),
+            Op::Freeze => Some(Freeze { value: get_u8!() }),
+            Op::MakeRecord => Some(MakeRecord {
+                register: get_u8!(),
+                fields_start: get_u8!(),
+                field_count: get_u8!(),
+                name: get_var_u32!().into(),
+            }),
+            Op::MakeEnumVariant => Some(MakeEnumVariant {
+                register: get_u8!(),
+                fields_start: get_u8!(),
+                field_count: get_u8!(),
+                enum_name: get_var_u32!().into(),
+                variant_name: get_var_u32!().into(),
+            }),
+            Op::CheckRecord => Some(CheckRecord {
+                register: get_u8!(),
+                value: get_u8!(),
+                constructor: get_u8!(),
+                jump_offset: get_u16!(),
+            }),
+            Op::CheckRecordConstructor => Some(CheckRecordConstructor {
+                value: get_u8!(),
                 jump_offset: get_u16!(),
             }),
+            Op::MakeInterface => Some(MakeInterface {
+                register: get_u8!(),
+                keys: get_u8!(),
+                name: get_var_u32!().into(),
+            }),
             Op::StringStart => Some(StringStart {
                 size_hint: get_var_u32!(),
             }),
@@ -505,10 +608,19 @@ impl Iterator for InstructionReader {
                 let flags = get_u8!();
 
                 let format_options = if flags != 0 {
-                    let flags = StringFormatFlags::from_byte(flags);
+                    let extended_flags = if flags & StringFormatFlags::EXTENDED != 0 {
+                        get_u8!()
+                    } else {
+                        0
+                    };
+                    let flags = StringFormatFlags::from_bytes(flags, extended_flags);
 
                     let mut options = StringFormatOptions {
                         alignment: flags.alignment,
+                        representation: flags.representation,
+                        show_sign: flags.show_sign,
+                        alternate: flags.alternate,
+                        thousands_separator: flags.thousands_separator,
                         ..Default::default()
                     };
                     if flags.min_width {
@@ -520,6 +632,9 @@ impl Iterator for InstructionReader {
                     if flags.fill_character {
                         options.fill_character = Some(get_var_u32!().into());
                     }
+                    if flags.format_string {
+                        options.format_string = Some(get_var_u32!().into());
+                    }
 
                     Some(options)
                 } else {
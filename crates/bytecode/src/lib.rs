@@ -9,10 +9,11 @@ mod instruction;
 mod instruction_reader;
 mod loader;
 mod op;
+mod type_checker;
 
 pub use crate::{
     chunk::{Chunk, DebugInfo},
-    compiler::{Compiler, CompilerError, CompilerSettings},
+    compiler::{Compiler, CompilerError, CompilerSettings, CompilerWarning},
     instruction::{FunctionFlags, Instruction, StringFormatFlags},
     instruction_reader::InstructionReader,
     loader::{find_module, Loader, LoaderError},
@@ -154,11 +154,15 @@ impl Loader {
         }
     }
 
-    /// Finds a module from its name, and then compiles it
+    /// Finds a module from its name, and then compiles it with the provided settings
+    ///
+    /// Compiled modules are cached, so the settings only take effect the first time that a module
+    /// is compiled.
     pub fn compile_module(
         &mut self,
         module_name: &str,
         current_script_path: Option<&Path>,
+        settings: CompilerSettings,
     ) -> Result<CompileModuleResult, LoaderError> {
         let mut load_module_from_path = |module_path: PathBuf| {
             let module_path = module_path.canonicalize()?;
@@ -172,11 +176,7 @@ impl Loader {
                 None => {
                     let script = std::fs::read_to_string(&module_path)?;
 
-                    let chunk = self.compile_script(
-                        &script,
-                        Some(&module_path),
-                        CompilerSettings::default(),
-                    )?;
+                    let chunk = self.compile_script(&script, Some(&module_path), settings)?;
 
                     self.chunks.insert(module_path.clone(), chunk.clone());
 
@@ -148,7 +148,8 @@ pub enum Op {
     ///
     /// See [StringFormatFlags](crate::StringFormatFlags) for a description of the the format flags.
     ///
-    /// `[*value, format_flags, ?@min_width, ?@precision, ?@fill_character]`
+    /// `[*value, format_flags, ?extended_flags, ?@min_width, ?@precision, ?@fill_character,
+    ///   ?@format_string]`
     StringPush,
 
     /// Places the finished string in the target register
@@ -233,11 +234,46 @@ pub enum Op {
     /// `[*result, *lhs, *rhs]`
     Divide,
 
+    /// Divides lhs by rhs, rounding the result down to the nearest integer
+    ///
+    /// `[*result, *lhs, *rhs]`
+    IntegerDivide,
+
     /// Performs the remainder operation with lhs and rhs
     ///
     /// `[*result, *lhs, *rhs]`
     Remainder,
 
+    /// Raises lhs to the power of rhs
+    ///
+    /// `[*result, *lhs, *rhs]`
+    Power,
+
+    /// Performs a bitwise and operation with lhs and rhs
+    ///
+    /// `[*result, *lhs, *rhs]`
+    BitwiseAnd,
+
+    /// Performs a bitwise or operation with lhs and rhs
+    ///
+    /// `[*result, *lhs, *rhs]`
+    BitwiseOr,
+
+    /// Performs a bitwise xor operation with lhs and rhs
+    ///
+    /// `[*result, *lhs, *rhs]`
+    BitwiseXor,
+
+    /// Shifts the bits of lhs to the left by rhs
+    ///
+    /// `[*result, *lhs, *rhs]`
+    ShiftLeft,
+
+    /// Shifts the bits of lhs to the right by rhs
+    ///
+    /// `[*result, *lhs, *rhs]`
+    ShiftRight,
+
     /// Add-assign rhs -> lhs
     ///
     /// `[*lhs, *rhs]`
@@ -313,6 +349,16 @@ pub enum Op {
     /// `[*condition, offset[2]]`
     JumpIfFalse,
 
+    /// Causes the instruction pointer to jump forward, if a value is null
+    ///
+    /// `[*value, offset[2]]`
+    JumpIfNull,
+
+    /// Causes the instruction pointer to jump forward, if a value is not null
+    ///
+    /// `[*value, offset[2]]`
+    JumpIfNotNull,
+
     /// Calls a standalone function
     ///
     /// The frame base register (which contains the instance during a method call) will be set to
@@ -508,7 +554,11 @@ pub enum Op {
     /// This is used for type hints on variable declarations, and can be disabled via the
     /// compiler's `enable_type_checks` flag.
     ///
-    /// `[*value, @type constant]`
+    /// The bindings are the values assigned to the names used in the type hint, in the order in
+    /// which the names first appear, allowing hints to refer to values like interfaces.
+    /// The binding count is zero when none of the names refer to local values.
+    ///
+    /// `[*value, @type constant, *bindings start, binding count]`
     AssertType,
 
     /// Checks if the value matches the provided type
@@ -518,31 +568,130 @@ pub enum Op {
     ///
     /// This is used for type hints that can affect conditional logic, like match patterns.
     ///
-    /// `[*value, @type constant, jump_offset[2]]`
+    /// See [Op::AssertType] for a description of the bindings.
+    ///
+    /// `[*value, @type constant, *bindings start, binding count, jump_offset[2]]`
     CheckType,
 
+    /// Throws an error if the value isn't a Map
+    ///
+    /// Used when unpacking map patterns in function arguments and let expressions.
+    ///
+    /// `[*value]`
+    AssertMap,
+
+    /// Checks if the value is a Map
+    ///
+    /// If the value isn't a Map then the instruction pointer will be jumped forward to the
+    /// location referred to by the jump offset.
+    ///
+    /// Used for map patterns in match arms.
+    ///
+    /// `[*value, jump_offset[2]]`
+    CheckMap,
+
+    /// Accesses an entry in a Map via a constant key
+    ///
+    /// An error will be thrown if the map doesn't contain the key.
+    ///
+    /// Used when unpacking map patterns in function arguments and let expressions.
+    ///
+    /// `[*result, *map, @key constant]`
+    AccessMapEntry,
+
+    /// Accesses an entry in a Map via a constant key
+    ///
+    /// If the map doesn't contain the key then the instruction pointer will be jumped forward to
+    /// the location referred to by the jump offset.
+    ///
+    /// Used for map patterns in match arms.
+    ///
+    /// `[*result, *map, @key constant, jump_offset[2]]`
+    CheckMapEntry,
+
+    /// Makes a new Map containing the entries of a map that don't match the provided keys
+    ///
+    /// The keys are taken from consecutive registers.
+    ///
+    /// Used when capturing the remaining entries in a map pattern, e.g. `{foo, rest...}`
+    ///
+    /// `[*result, *map, *keys start, key count]`
+    MapRest,
+
+    /// Checks if a number is contained in a range
+    ///
+    /// The result will be `false` if the value isn't a number.
+    ///
+    /// Used for range patterns in match arms, e.g. `0..10 then ...`
+    ///
+    /// `[*result, *range, *value]`
+    RangeContains,
+
+    /// Freezes the value in the register, preventing further modifications
+    ///
+    /// Lists and maps are frozen along with their contents.
+    ///
+    /// Used for `const` declarations.
+    ///
+    /// `[*value]`
+    Freeze,
+
+    /// Makes a constructor for a record
+    ///
+    /// The record's fields are taken from groups of three consecutive registers, each containing
+    /// the field's name, followed by its type hint (or null if the field doesn't have a type
+    /// hint), followed by a tuple containing the type hint's bindings (or null if the hint
+    /// doesn't have bindings, see [Op::AssertType]).
+    ///
+    /// Used for `record` declarations.
+    ///
+    /// `[*result, *fields start, field count, @name constant]`
+    MakeRecord,
+
+    /// Makes a variant of an enum
+    ///
+    /// Variants with fields are made in the same way as records (see [Op::MakeRecord]),
+    /// producing a constructor for the variant. Variants without fields produce the variant's
+    /// value.
+    ///
+    /// Used for `enum` declarations.
+    ///
+    /// `[*result, *fields start, field count, @enum name constant, @variant name constant]`
+    MakeEnumVariant,
+
+    /// Checks if a value was made by a record's constructor
+    ///
+    /// If the value was made by the constructor, then a tuple containing the values of the
+    /// record's fields is placed in the result register, otherwise the instruction pointer will be
+    /// jumped forward to the location referred to by the jump offset.
+    ///
+    /// Used for record patterns in match arms, e.g. `Shape.Circle(r) then ...`
+    ///
+    /// `[*result, *value, *constructor, jump_offset[2]]`
+    CheckRecord,
+
+    /// Checks if a value is a record's constructor
+    ///
+    /// If the value isn't a record constructor then the instruction pointer will be jumped
+    /// forward to the location referred to by the jump offset.
+    ///
+    /// Used for record patterns in match arms, which are evaluated as call expressions when the
+    /// pattern doesn't refer to a record constructor, e.g. `f(1) then ...`
+    ///
+    /// `[*value, jump_offset[2]]`
+    CheckRecordConstructor,
+
+    /// Makes an interface
+    ///
+    /// The interface's required keys and meta keys are taken from the keys of a map,
+    /// with the map's values being ignored.
+    ///
+    /// Used for `interface` declarations.
+    ///
+    /// `[*result, *keys map, @name constant]`
+    MakeInterface,
+
     // Unused opcodes, allowing for a direct transmutation from a byte to an Op.
-    Unused85,
-    Unused86,
-    Unused87,
-    Unused88,
-    Unused89,
-    Unused90,
-    Unused91,
-    Unused92,
-    Unused93,
-    Unused94,
-    Unused95,
-    Unused96,
-    Unused97,
-    Unused98,
-    Unused99,
-    Unused100,
-    Unused101,
-    Unused102,
-    Unused103,
-    Unused104,
-    Unused105,
     Unused106,
     Unused107,
     Unused108,
@@ -0,0 +1,955 @@
+use crate::compiler::{CompilerWarning, WarningKind};
+use koto_parser::{
+    Ast, AstBinaryOp, AstFor, AstIndex, AstString, AstUnaryOp, ChainNode, ConstantIndex, Function,
+    Node, StringContents, StringNode,
+};
+use std::collections::{HashMap, HashSet};
+
+// A statically inferred type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StaticType<'a> {
+    // The type of the value isn't known at compile time
+    Unknown,
+    // The value is known to have the given type, e.g. `Number`, `String`, `List`
+    Known(&'a str),
+}
+
+impl<'a> StaticType<'a> {
+    fn known(self) -> Option<&'a str> {
+        match self {
+            Self::Known(type_name) => Some(type_name),
+            Self::Unknown => None,
+        }
+    }
+}
+
+// The statically known information about a value that's been assigned to an id
+#[derive(Clone, Copy)]
+struct Binding<'a> {
+    value_type: StaticType<'a>,
+    // If the value is a function then its definition is stored, allowing calls to be checked
+    function: Option<&'a Function>,
+}
+
+// A static type checking pass that reports type hint mismatches as compiler warnings
+//
+// Only 'obvious' mismatches are reported, e.g. when a literal or a value with a known type is
+// assigned to an id with a type hint, or passed as an argument to a function with
+// type-hinted arguments.
+//
+// To keep the analysis simple and conservative, the types of values assigned to ids are only
+// tracked when the id has a single binding site in the whole script. Ids that are assigned
+// more than once are treated as having unknown types.
+pub(crate) struct TypeChecker<'a> {
+    ast: &'a Ast,
+    // The number of places in the script where each id receives a value
+    binding_counts: HashMap<ConstantIndex, usize>,
+    bindings: HashMap<ConstantIndex, Binding<'a>>,
+    // The names of the interfaces that are declared in the script
+    interfaces: HashSet<&'a str>,
+    // The output type hints of the functions that are currently being checked
+    output_types: Vec<Option<OutputType>>,
+    warnings: Vec<CompilerWarning>,
+}
+
+#[derive(Clone, Copy)]
+struct OutputType {
+    type_hint: AstIndex,
+    is_generator: bool,
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Checks the AST, returning warnings for any detected type mismatches
+    pub fn check(ast: &'a Ast) -> Vec<CompilerWarning> {
+        let mut checker = Self {
+            ast,
+            binding_counts: HashMap::new(),
+            bindings: HashMap::new(),
+            interfaces: HashSet::new(),
+            output_types: Vec::new(),
+            warnings: Vec::new(),
+        };
+
+        checker.count_binding_sites();
+
+        if let Some(entry_point) = ast.entry_point() {
+            checker.check_node(entry_point);
+        }
+
+        checker.warnings
+    }
+
+    // Scans through the AST's nodes, counting the number of places where each id is bound
+    //
+    // The names of declared interfaces are also collected, given that values of any type can
+    // conform to an interface.
+    fn count_binding_sites(&mut self) {
+        let ast = self.ast;
+
+        for ast_node in ast.nodes() {
+            match &ast_node.node {
+                Node::Assign { target, .. } | Node::Const { target, .. } => {
+                    self.count_pattern_ids(*target)
+                }
+                Node::MultiAssign { targets, .. } => {
+                    for target in targets.iter() {
+                        self.count_pattern_ids(*target);
+                    }
+                }
+                Node::BinaryOp {
+                    op:
+                        AstBinaryOp::AddAssign
+                        | AstBinaryOp::SubtractAssign
+                        | AstBinaryOp::MultiplyAssign
+                        | AstBinaryOp::DivideAssign
+                        | AstBinaryOp::RemainderAssign,
+                    lhs,
+                    ..
+                } => self.count_pattern_ids(*lhs),
+                Node::Function(Function { args, .. }) | Node::For(AstFor { args, .. }) => {
+                    for arg in args.iter() {
+                        self.count_pattern_ids(*arg);
+                    }
+                }
+                Node::Match { arms, .. } => {
+                    for pattern in arms.iter().flat_map(|arm| arm.patterns.iter()) {
+                        self.count_pattern_ids(*pattern);
+                    }
+                }
+                Node::Try(try_expression) => {
+                    for catch_block in try_expression.catch_blocks.iter() {
+                        self.count_pattern_ids(catch_block.arg);
+                    }
+                }
+                Node::Import { items, .. } => {
+                    for item in items.iter() {
+                        self.count_pattern_ids(item.name.unwrap_or(item.item));
+                    }
+                }
+                Node::MapPattern(entries) => {
+                    for (key, value) in entries.iter() {
+                        match value {
+                            Some(value) => self.count_pattern_ids(*value),
+                            None => self.count_pattern_ids(*key),
+                        }
+                    }
+                }
+                Node::Interface { name, .. } => {
+                    self.interfaces.insert(ast.constants().get_str(*name));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Counts the ids bound by a pattern
+    //
+    // Nested map patterns aren't visited here, they're counted separately in
+    // count_binding_sites.
+    fn count_pattern_ids(&mut self, pattern: AstIndex) {
+        match &self.ast.node(pattern).node {
+            Node::Id(id, _) | Node::Ellipsis(Some(id)) => {
+                *self.binding_counts.entry(*id).or_default() += 1;
+            }
+            Node::Tuple(nested)
+            | Node::TempTuple(nested)
+            | Node::RecordPattern { fields: nested, .. } => {
+                for nested_pattern in nested.iter() {
+                    self.count_pattern_ids(*nested_pattern);
+                }
+            }
+            // e.g. `import 'foo'`, the imported item is assigned to `foo`
+            Node::Str(AstString {
+                contents: StringContents::Literal(id),
+                ..
+            }) => {
+                *self.binding_counts.entry(*id).or_default() += 1;
+            }
+            _ => {}
+        }
+    }
+
+    fn has_single_binding_site(&self, id: ConstantIndex) -> bool {
+        self.binding_counts.get(&id) == Some(&1)
+    }
+
+    // Checks a node and its children, returning the node's inferred type
+    fn check_node(&mut self, node_index: AstIndex) -> StaticType<'a> {
+        use StaticType::*;
+
+        let ast = self.ast;
+
+        match &ast.node(node_index).node {
+            Node::Null => Known("Null"),
+            Node::BoolTrue | Node::BoolFalse => Known("Bool"),
+            Node::SmallInt(_) | Node::Int(_) | Node::Float(_) => Known("Number"),
+            Node::Str(string) => {
+                if let StringContents::Interpolated(nodes) = &string.contents {
+                    for node in nodes {
+                        if let StringNode::Expression { expression, .. } = node {
+                            self.check_node(*expression);
+                        }
+                    }
+                }
+                Known("String")
+            }
+            Node::List(elements) => {
+                self.check_nodes(elements);
+                Known("List")
+            }
+            Node::Tuple(elements) | Node::TempTuple(elements) => {
+                self.check_nodes(elements);
+                Known("Tuple")
+            }
+            Node::Range { start, end, .. } => {
+                self.check_node(*start);
+                self.check_node(*end);
+                Known("Range")
+            }
+            Node::RangeFrom { start } => {
+                self.check_node(*start);
+                Known("Range")
+            }
+            Node::RangeTo { end, .. } => {
+                self.check_node(*end);
+                Known("Range")
+            }
+            Node::RangeFull => Known("Range"),
+            Node::Map(entries) => {
+                let mut has_meta_keys = false;
+                for (key, value) in entries.iter() {
+                    match &ast.node(*key).node {
+                        Node::Meta(..) => has_meta_keys = true,
+                        Node::Str(_) => {
+                            self.check_node(*key);
+                        }
+                        _ => {}
+                    }
+                    if let Some(value) = value {
+                        self.check_node(*value);
+                    }
+                }
+                // Maps with metakeys could have any type (e.g. via `@type` or `@base`)
+                if has_meta_keys {
+                    Unknown
+                } else {
+                    Known("Map")
+                }
+            }
+            Node::Nested(nested) => self.check_node(*nested),
+            Node::Id(id, _) => self
+                .bindings
+                .get(id)
+                .map_or(Unknown, |binding| binding.value_type),
+            Node::Chain(_) => self.check_chain(node_index),
+            Node::MainBlock { body, .. } => {
+                self.check_nodes(body);
+                Unknown
+            }
+            Node::Block(body) => {
+                let mut result = Unknown;
+                for expression in body.iter() {
+                    result = self.check_node(*expression);
+                }
+                result
+            }
+            Node::Function(function) => {
+                self.check_function(function);
+                Known("Function")
+            }
+            Node::Import { from, items } => {
+                self.check_nodes(from);
+                for item in items.iter() {
+                    self.check_node(item.item);
+                }
+                Unknown
+            }
+            Node::Export(expression) => self.check_node(*expression),
+            Node::Assign { target, expression } | Node::Const { target, expression } => {
+                self.check_assign(*target, *expression)
+            }
+            Node::MultiAssign {
+                targets,
+                expression,
+            } => {
+                self.check_multi_assign(targets, *expression);
+                Unknown
+            }
+            Node::UnaryOp { op, value } => {
+                let value_type = self.check_node(*value);
+                match (op, value_type) {
+                    (AstUnaryOp::Negate, Known("Number")) => Known("Number"),
+                    (AstUnaryOp::Not, _) => Known("Bool"),
+                    _ => Unknown,
+                }
+            }
+            Node::BinaryOp { op, lhs, rhs } => {
+                let lhs_type = self.check_node(*lhs);
+                let rhs_type = self.check_node(*rhs);
+                binary_op_type(*op, lhs_type, rhs_type)
+            }
+            Node::If(if_expression) => {
+                self.check_node(if_expression.condition);
+                let mut branch_types = vec![self.check_node(if_expression.then_node)];
+                for (condition, block) in if_expression.else_if_blocks.iter() {
+                    self.check_node(*condition);
+                    branch_types.push(self.check_node(*block));
+                }
+                match if_expression.else_node {
+                    Some(else_node) => {
+                        branch_types.push(self.check_node(else_node));
+                        common_type(&branch_types)
+                    }
+                    None => Unknown,
+                }
+            }
+            Node::Match { expression, arms } => {
+                self.check_node(*expression);
+                for arm in arms.iter() {
+                    self.check_nodes(&arm.patterns);
+                    if let Some(condition) = arm.condition {
+                        self.check_node(condition);
+                    }
+                    self.check_node(arm.expression);
+                }
+                Unknown
+            }
+            Node::Switch(arms) => {
+                for arm in arms.iter() {
+                    if let Some(condition) = arm.condition {
+                        self.check_node(condition);
+                    }
+                    self.check_node(arm.expression);
+                }
+                Unknown
+            }
+            Node::For(for_loop) => {
+                self.check_node(for_loop.iterable);
+                self.check_node(for_loop.body);
+                Unknown
+            }
+            Node::Loop { body } => {
+                self.check_node(*body);
+                Unknown
+            }
+            Node::While { condition, body } | Node::Until { condition, body } => {
+                self.check_node(*condition);
+                self.check_node(*body);
+                Unknown
+            }
+            Node::Break(Some(expression)) | Node::Throw(expression) => {
+                self.check_node(*expression);
+                Unknown
+            }
+            Node::Return(maybe_expression) => {
+                let return_type = match maybe_expression {
+                    Some(expression) => self.check_node(*expression),
+                    None => Known("Null"),
+                };
+                if let Some(Some(output_type)) = self.output_types.last().copied() {
+                    if !output_type.is_generator {
+                        let span_node = maybe_expression.unwrap_or(node_index);
+                        self.check_output_type(output_type.type_hint, return_type, span_node);
+                    }
+                }
+                Unknown
+            }
+            Node::Yield(expression) => {
+                let yield_type = self.check_node(*expression);
+                if let Some(Some(output_type)) = self.output_types.last().copied() {
+                    if output_type.is_generator {
+                        self.check_output_type(output_type.type_hint, yield_type, *expression);
+                    }
+                }
+                Unknown
+            }
+            Node::Try(try_expression) => {
+                self.check_node(try_expression.try_block);
+                for catch_block in try_expression.catch_blocks.iter() {
+                    self.check_node(catch_block.block);
+                }
+                if let Some(finally_block) = try_expression.finally_block {
+                    self.check_node(finally_block);
+                }
+                Unknown
+            }
+            Node::Debug { expression, .. } => self.check_node(*expression),
+            Node::Enum { .. } => Known("Map"),
+            Node::Interface { .. } => Known("Interface"),
+            Node::RecordPattern {
+                constructor,
+                fields,
+            } => {
+                self.check_node(*constructor);
+                self.check_nodes(fields);
+                Unknown
+            }
+            Node::Record { .. }
+            | Node::Meta(..)
+            | Node::Self_
+            | Node::Wildcard(..)
+            | Node::Ellipsis(_)
+            | Node::MapPattern(_)
+            | Node::Break(None)
+            | Node::Continue
+            | Node::Type { .. }
+            | Node::TypeUnion { .. } => Unknown,
+        }
+    }
+
+    fn check_nodes(&mut self, nodes: &[AstIndex]) {
+        for node in nodes {
+            self.check_node(*node);
+        }
+    }
+
+    fn check_function(&mut self, function: &'a Function) {
+        let ast = self.ast;
+
+        // Arguments with type hints are checked when the function is called,
+        // so their types are known in the function body.
+        for arg in function.args.iter() {
+            if let Node::Id(id, Some(type_hint)) = &ast.node(*arg).node {
+                if self.has_single_binding_site(*id) {
+                    self.bindings.insert(
+                        *id,
+                        Binding {
+                            value_type: self.type_hint_type(*type_hint),
+                            function: None,
+                        },
+                    );
+                }
+            }
+        }
+
+        let output_type = function.output_type.map(|type_hint| OutputType {
+            type_hint,
+            is_generator: function.is_generator,
+        });
+
+        self.output_types.push(output_type);
+        let body_type = self.check_node(function.body);
+        self.output_types.pop();
+
+        if let Some(output_type) = output_type {
+            if !output_type.is_generator {
+                // The function's result is the value of the last expression in its body
+                let span_node = match &ast.node(function.body).node {
+                    Node::Block(body) => body.last().copied().unwrap_or(function.body),
+                    _ => function.body,
+                };
+                self.check_output_type(output_type.type_hint, body_type, span_node);
+            }
+        }
+    }
+
+    fn check_assign(&mut self, target: AstIndex, expression: AstIndex) -> StaticType<'a> {
+        let ast = self.ast;
+        let expression_type = self.check_node(expression);
+
+        if let Node::Chain(_) = &ast.node(target).node {
+            self.check_node(target);
+        }
+
+        if let Node::Id(id, maybe_type_hint) = &ast.node(target).node {
+            let mut value_type = expression_type;
+
+            if let Some(type_hint) = maybe_type_hint {
+                self.check_type(*type_hint, expression_type, expression);
+                // The value is checked at runtime, so the type hint can be trusted
+                value_type = self.type_hint_type(*type_hint);
+            }
+
+            if self.has_single_binding_site(*id) {
+                let function = match &ast.node(expression).node {
+                    Node::Function(function) => Some(function),
+                    _ => None,
+                };
+                self.bindings.insert(
+                    *id,
+                    Binding {
+                        value_type,
+                        function,
+                    },
+                );
+            }
+        }
+
+        expression_type
+    }
+
+    fn check_multi_assign(&mut self, targets: &[AstIndex], expression: AstIndex) {
+        let ast = self.ast;
+
+        // Check the types of the values when they're provided as a comma-separated list
+        // e.g. `let x: Number, y: String = 1, 'hello'`
+        if let Node::TempTuple(values) = &ast.node(expression).node {
+            if values.len() == targets.len() {
+                for (target, value) in targets.iter().zip(values.iter()) {
+                    let value_type = self.check_node(*value);
+                    if let Node::Id(_, Some(type_hint)) = &ast.node(*target).node {
+                        self.check_type(*type_hint, value_type, *value);
+                    }
+                }
+                return;
+            }
+        }
+
+        self.check_node(expression);
+    }
+
+    // Checks a chain, e.g. `foo.bar(42)`, checking the arguments of any known function calls
+    fn check_chain(&mut self, chain_index: AstIndex) -> StaticType<'a> {
+        use StaticType::*;
+
+        let ast = self.ast;
+
+        // The type of the value at the current position in the chain
+        let mut current_type = Unknown;
+        // The definition of the function at the current position in the chain
+        let mut current_function = None;
+        // A core library module and function name, used when calling core library functions
+        let mut core_lib_function: Option<CoreLibFunction<'a>> = None;
+        // The name of the core library module that the chain's root refers to
+        let mut core_lib_module = None;
+
+        let mut next = Some(chain_index);
+
+        while let Some(next_index) = next {
+            let Node::Chain((chain_node, next_node)) = &ast.node(next_index).node else {
+                break;
+            };
+            next = *next_node;
+
+            let mut next_function = None;
+            let mut next_core_lib_function = None;
+            let mut next_core_lib_module = None;
+
+            current_type = match chain_node {
+                ChainNode::Root(root) => {
+                    let root_type = self.check_node(*root);
+
+                    if let Node::Id(id, _) = &ast.node(*root).node {
+                        if let Some(binding) = self.bindings.get(id) {
+                            next_function = binding.function;
+                        } else if !self.binding_counts.contains_key(id) {
+                            // The id isn't assigned locally, so it could refer to a core
+                            // library module or to a function that's imported in the prelude.
+                            let name = ast.constants().get_str(*id);
+                            next_core_lib_module = CORE_LIB_MODULES
+                                .iter()
+                                .find(|(module, _)| *module == name)
+                                .map(|(module, _)| *module);
+                            next_core_lib_function = match name {
+                                "size" | "type" => Some(CoreLibFunction {
+                                    module: "koto",
+                                    name,
+                                    instance_type: None,
+                                }),
+                                _ => None,
+                            };
+                        }
+                    }
+
+                    root_type
+                }
+                ChainNode::Id(id) => {
+                    let name = ast.constants().get_str(*id);
+                    if let Some(module) = core_lib_module {
+                        next_core_lib_function = Some(CoreLibFunction {
+                            module,
+                            name,
+                            instance_type: None,
+                        });
+                    } else if let Known(type_name) = current_type {
+                        // Maps aren't included here given that entries can shadow the
+                        // map module's functions.
+                        next_core_lib_function = CORE_LIB_MODULES
+                            .iter()
+                            .find(|(_, module_type)| *module_type == Some(type_name))
+                            .filter(|(module, _)| *module != "map")
+                            .map(|(module, _)| CoreLibFunction {
+                                module,
+                                name,
+                                instance_type: Some(type_name),
+                            });
+                    }
+                    Unknown
+                }
+                ChainNode::Str(_) | ChainNode::Index(_) | ChainNode::NullCheck => {
+                    if let ChainNode::Index(index) = chain_node {
+                        self.check_node(*index);
+                    }
+                    Unknown
+                }
+                ChainNode::Call { args, .. } => {
+                    let arg_types = args
+                        .iter()
+                        .map(|arg| self.check_node(*arg))
+                        .collect::<Vec<_>>();
+
+                    if let Some(function) = current_function {
+                        self.check_call_args(function, args, &arg_types)
+                    } else if let Some(core_lib_function) = core_lib_function {
+                        self.check_core_lib_call(core_lib_function, args, &arg_types)
+                    } else {
+                        Unknown
+                    }
+                }
+            };
+
+            if matches!(chain_node, ChainNode::NullCheck) {
+                // The rest of the chain may be short-circuited, so the result is unknown
+                while let Some(next_index) = next {
+                    match &ast.node(next_index).node {
+                        Node::Chain((ChainNode::Index(index), next_node)) => {
+                            self.check_node(*index);
+                            next = *next_node;
+                        }
+                        Node::Chain((ChainNode::Call { args, .. }, next_node)) => {
+                            self.check_nodes(args);
+                            next = *next_node;
+                        }
+                        Node::Chain((_, next_node)) => next = *next_node,
+                        _ => break,
+                    }
+                }
+                return Unknown;
+            }
+
+            current_function = next_function;
+            core_lib_function = next_core_lib_function;
+            core_lib_module = next_core_lib_module;
+        }
+
+        current_type
+    }
+
+    fn check_call_args(
+        &mut self,
+        function: &'a Function,
+        args: &[AstIndex],
+        arg_types: &[StaticType<'a>],
+    ) -> StaticType<'a> {
+        let ast = self.ast;
+
+        let arg_count = if function.is_variadic {
+            function.args.len().saturating_sub(1)
+        } else {
+            function.args.len()
+        };
+
+        for (arg_index, (function_arg, arg_type)) in function
+            .args
+            .iter()
+            .take(arg_count)
+            .zip(arg_types.iter())
+            .enumerate()
+        {
+            let type_hint = match &ast.node(*function_arg).node {
+                Node::Id(_, Some(type_hint)) | Node::Wildcard(_, Some(type_hint)) => *type_hint,
+                _ => continue,
+            };
+
+            if let Some(found) = arg_type.known() {
+                if !self.type_hint_accepts(type_hint, found) {
+                    self.push_warning(
+                        WarningKind::UnexpectedArgType {
+                            arg: arg_index + 1,
+                            expected: self.type_hint_string(type_hint).into(),
+                            found: found.into(),
+                        },
+                        args[arg_index],
+                    );
+                }
+            }
+        }
+
+        if function.is_generator {
+            StaticType::Known("Iterator")
+        } else {
+            function
+                .output_type
+                .map_or(StaticType::Unknown, |output_type| {
+                    self.type_hint_type(output_type)
+                })
+        }
+    }
+
+    fn check_core_lib_call(
+        &mut self,
+        function: CoreLibFunction<'a>,
+        args: &[AstIndex],
+        arg_types: &[StaticType<'a>],
+    ) -> StaticType<'a> {
+        let Some(return_type) = core_lib_return_type(function.module, function.name) else {
+            return StaticType::Unknown;
+        };
+
+        // Module functions expect the first argument to match the module's type,
+        // e.g. `string.to_uppercase 'abc'`, with the exception of constructors like `set.new`.
+        if function.instance_type.is_none() && !matches!(function.name, "new" | "big" | "decimal") {
+            let module_type = CORE_LIB_MODULES
+                .iter()
+                .find(|(module, _)| *module == function.module)
+                .and_then(|(_, module_type)| *module_type);
+            if let (Some(expected), Some(StaticType::Known(found))) =
+                (module_type, arg_types.first())
+            {
+                if !type_matches(expected, found) {
+                    self.push_warning(
+                        WarningKind::UnexpectedArgType {
+                            arg: 1,
+                            expected: expected.into(),
+                            found: (*found).into(),
+                        },
+                        args[0],
+                    );
+                }
+            }
+        }
+
+        StaticType::Known(return_type)
+    }
+
+    fn check_type(&mut self, type_hint: AstIndex, found: StaticType, span_node: AstIndex) {
+        if let Some(found) = found.known() {
+            if !self.type_hint_accepts(type_hint, found) {
+                self.push_warning(
+                    WarningKind::UnexpectedType {
+                        expected: self.type_hint_string(type_hint).into(),
+                        found: found.into(),
+                    },
+                    span_node,
+                );
+            }
+        }
+    }
+
+    fn check_output_type(&mut self, type_hint: AstIndex, found: StaticType, span_node: AstIndex) {
+        if let Some(found) = found.known() {
+            if !self.type_hint_accepts(type_hint, found) {
+                self.push_warning(
+                    WarningKind::UnexpectedOutputType {
+                        expected: self.type_hint_string(type_hint).into(),
+                        found: found.into(),
+                    },
+                    span_node,
+                );
+            }
+        }
+    }
+
+    // Returns the static type of a value that has passed a check for the given type hint
+    //
+    // Only the outer type is tracked, so e.g. a value hinted with `List<Number>` is a `List`.
+    // Optional types and unions can match more than one type, so their types are unknown.
+    fn type_hint_type(&self, type_hint: AstIndex) -> StaticType<'a> {
+        let ast = self.ast;
+        match &ast.node(type_hint).node {
+            Node::Type {
+                name,
+                optional: false,
+                ..
+            } => match ast.constants().get_str(*name) {
+                // Abstract types can be matched by more than one type
+                "Any" | "Callable" | "Indexable" | "Iterable" => StaticType::Unknown,
+                type_name if self.interfaces.contains(type_name) => StaticType::Unknown,
+                type_name => StaticType::Known(type_name),
+            },
+            _ => StaticType::Unknown,
+        }
+    }
+
+    // Returns true if a value with the `found` type would pass a runtime check for the type hint
+    //
+    // Nested types aren't checked given that only the outer types of values are tracked.
+    fn type_hint_accepts(&self, type_hint: AstIndex, found: &str) -> bool {
+        let ast = self.ast;
+        match &ast.node(type_hint).node {
+            Node::Type { name, optional, .. } => {
+                let expected = ast.constants().get_str(*name);
+                (*optional && found == "Null")
+                    || self.interfaces.contains(expected)
+                    || type_matches(expected, found)
+            }
+            Node::TypeUnion { types, .. } => types
+                .iter()
+                .any(|member| self.type_hint_accepts(*member, found)),
+            _ => true,
+        }
+    }
+
+    fn type_hint_string(&self, type_hint: AstIndex) -> &'a str {
+        let ast = self.ast;
+        match &ast.node(type_hint).node {
+            Node::Type { hint, .. } | Node::TypeUnion { hint, .. } => {
+                ast.constants().get_str(*hint)
+            }
+            _ => "?",
+        }
+    }
+
+    fn push_warning(&mut self, warning: WarningKind, span_node: AstIndex) {
+        let span = *self.ast.span(self.ast.node(span_node).span);
+        self.warnings.push(CompilerWarning::new(warning, span));
+    }
+}
+
+// A function from the core library, along with the type of the instance that it's called on
+#[derive(Clone, Copy)]
+struct CoreLibFunction<'a> {
+    module: &'static str,
+    name: &'a str,
+    // The type of the value that the function is being called on, e.g. `'abc'.to_uppercase()`
+    instance_type: Option<&'a str>,
+}
+
+// The core library modules that are available in the prelude, along with the type of value that
+// the module's functions expect as their first argument
+const CORE_LIB_MODULES: &[(&str, Option<&str>)] = &[
+    ("bytes", None),
+    ("iterator", None),
+    ("koto", None),
+    ("list", Some("List")),
+    ("map", Some("Map")),
+    ("number", Some("Number")),
+    ("range", Some("Range")),
+    ("set", Some("Set")),
+    ("string", Some("String")),
+    ("tuple", Some("Tuple")),
+];
+
+// Returns the type of the value returned by a core library function, if known
+fn core_lib_return_type(module: &str, function: &str) -> Option<&'static str> {
+    let result = match (module, function) {
+        ("bytes", "from_base64" | "from_hex" | "new") => "Bytes",
+        ("koto", "size") => "Number",
+        ("koto", "type") => "String",
+        ("list", "contains" | "is_empty") => "Bool",
+        (
+            "list",
+            "clear" | "extend" | "fill" | "insert" | "push" | "resize" | "resize_with" | "retain"
+            | "reverse" | "sort" | "transform",
+        ) => "List",
+        ("list", "to_tuple") => "Tuple",
+        ("map", "contains_key" | "is_empty") => "Bool",
+        ("map", "keys" | "values") => "Iterator",
+        ("number", "big") => "BigInt",
+        ("number", "decimal") => "Decimal",
+        ("number", "is_nan") => "Bool",
+        (
+            "number",
+            "abs" | "acos" | "acosh" | "and" | "asin" | "asinh" | "atan" | "atan2" | "atanh"
+            | "ceil" | "clamp" | "cos" | "cosh" | "exp" | "exp2" | "flip_bits" | "floor" | "lerp"
+            | "ln" | "log10" | "log2" | "max" | "min" | "or" | "pow" | "recip" | "round"
+            | "saturating_add" | "saturating_mul" | "saturating_pow" | "saturating_sub"
+            | "shift_left" | "shift_right" | "sin" | "sinh" | "sqrt" | "tan" | "tanh" | "to_int"
+            | "wrapping_add" | "wrapping_mul" | "wrapping_pow" | "wrapping_sub" | "xor",
+        ) => "Number",
+        ("range", "contains" | "is_inclusive") => "Bool",
+        ("range", "expanded") => "Range",
+        (
+            "set",
+            "contains" | "insert" | "is_disjoint" | "is_empty" | "is_subset" | "is_superset"
+            | "remove",
+        ) => "Bool",
+        (
+            "set",
+            "clear"
+            | "difference"
+            | "extend"
+            | "intersection"
+            | "new"
+            | "symmetric_difference"
+            | "union",
+        ) => "Set",
+        ("string", "contains" | "ends_with" | "is_empty" | "starts_with") => "Bool",
+        ("string", "bytes" | "chars" | "char_indices" | "lines" | "split") => "Iterator",
+        ("string", "escape" | "repeat" | "replace" | "to_lowercase" | "to_uppercase" | "trim") => {
+            "String"
+        }
+        ("tuple", "contains") => "Bool",
+        ("tuple", "sort_copy") => "Tuple",
+        ("tuple", "to_list") => "List",
+        // Iterable values fall back to the iterator module
+        ("list" | "range" | "set" | "string" | "tuple" | "iterator", _) => {
+            return iterator_return_type(function)
+        }
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+fn iterator_return_type(function: &str) -> Option<&'static str> {
+    let result = match function {
+        "all" | "any" => "Bool",
+        "count" => "Number",
+        "to_list" => "List",
+        "to_map" => "Map",
+        "to_set" => "Set",
+        "to_string" => "String",
+        "to_tuple" => "Tuple",
+        "chain" | "chunks" | "cycle" | "each" | "enumerate" | "flatten" | "intersperse"
+        | "iter" | "keep" | "peekable" | "reversed" | "skip" | "step" | "take" | "windows"
+        | "zip" => "Iterator",
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+// Returns true if a value with the `found` type would pass a runtime check for `expected`
+fn type_matches(expected: &str, found: &str) -> bool {
+    match expected {
+        "Any" => true,
+        "Callable" => found == "Function",
+        "Indexable" => matches!(found, "Bytes" | "List" | "Map" | "String" | "Tuple"),
+        "Iterable" => matches!(
+            found,
+            "Bytes" | "Iterator" | "List" | "Map" | "Range" | "Set" | "String" | "Tuple"
+        ),
+        _ => expected == found,
+    }
+}
+
+// Infers the type of a binary operation's result
+//
+// Results are only inferred for operations on core value types, where the operations can't be
+// overridden by metamaps.
+fn binary_op_type<'a>(op: AstBinaryOp, lhs: StaticType<'a>, rhs: StaticType<'a>) -> StaticType<'a> {
+    use AstBinaryOp::*;
+    use StaticType::*;
+
+    let (Known(lhs), Known(rhs)) = (lhs, rhs) else {
+        return Unknown;
+    };
+
+    match op {
+        Add | Subtract | Multiply | Divide | IntegerDivide | Remainder | Power | BitwiseAnd
+        | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight
+            if lhs == "Number" && rhs == "Number" =>
+        {
+            Known("Number")
+        }
+        Add if lhs == "String" && rhs == "String" => Known("String"),
+        Equal | NotEqual | Less | LessOrEqual | Greater | GreaterOrEqual
+            if lhs != "Map" && rhs != "Map" =>
+        {
+            Known("Bool")
+        }
+        And | Or if lhs == "Bool" && rhs == "Bool" => Known("Bool"),
+        NullCoalesce if lhs == "Null" => Known(rhs),
+        NullCoalesce => Known(lhs),
+        _ => Unknown,
+    }
+}
+
+// Returns the common type of a series of branches, or Unknown if the types differ
+fn common_type<'a>(types: &[StaticType<'a>]) -> StaticType<'a> {
+    match types.split_first() {
+        Some((first, rest)) if rest.iter().all(|t| t == first) => *first,
+        _ => StaticType::Unknown,
+    }
+}
@@ -152,6 +152,57 @@ export x
             fn list() {
                 let source = "
 export [1, 2, 3]
+";
+                check_compilation_fails(source);
+            }
+        }
+
+        mod const_reassignment {
+            use super::*;
+
+            #[test]
+            fn assignment() {
+                let source = "
+const x = 1
+x = 2
+";
+                check_compilation_fails(source);
+            }
+
+            #[test]
+            fn compound_assignment() {
+                let source = "
+const x = 1
+x += 2
+";
+                check_compilation_fails(source);
+            }
+
+            #[test]
+            fn redeclaration() {
+                let source = "
+const x = 1
+const x = 2
+";
+                check_compilation_fails(source);
+            }
+
+            #[test]
+            fn for_loop_arg() {
+                let source = "
+const x = 1
+for x in 0..10
+  x
+";
+                check_compilation_fails(source);
+            }
+
+            #[test]
+            fn assignment_in_nested_block() {
+                let source = "
+const x = 1
+if true
+  x = 2
 ";
                 check_compilation_fails(source);
             }
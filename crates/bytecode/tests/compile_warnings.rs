@@ -0,0 +1,259 @@
+mod bytecode {
+    use koto_bytecode::{Compiler, CompilerSettings};
+    use koto_parser::Parser;
+
+    fn check_warning_count(source: &str, expected_count: usize) {
+        check_warning_count_with_settings(source, expected_count, CompilerSettings::default())
+    }
+
+    fn check_static_type_warning_count(source: &str, expected_count: usize) {
+        check_warning_count_with_settings(
+            source,
+            expected_count,
+            CompilerSettings {
+                enable_static_type_checks: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn check_warning_count_with_settings(
+        source: &str,
+        expected_count: usize,
+        settings: CompilerSettings,
+    ) {
+        match Parser::parse(source) {
+            Ok(ast) => match Compiler::compile(&ast, settings) {
+                Ok((_, debug_info)) => {
+                    assert_eq!(
+                        debug_info.warnings.len(),
+                        expected_count,
+                        "\nUnexpected warning count while compiling: {source}"
+                    );
+                }
+                Err(error) => panic!("Failure while compiling:\n{source}\n{error}"),
+            },
+            Err(parser_error) => {
+                panic!("Failure while parsing:\n{source}\n{parser_error}");
+            }
+        }
+    }
+
+    mod non_exhaustive_match {
+        use super::*;
+
+        #[test]
+        fn bool_missing_false() {
+            let source = "
+match x
+  true then 1
+";
+            check_warning_count(source, 1);
+        }
+
+        #[test]
+        fn bool_with_condition() {
+            let source = "
+match x
+  true then 1
+  false if y then 0
+";
+            check_warning_count(source, 1);
+        }
+
+        #[test]
+        fn bool_exhaustive() {
+            let source = "
+match x
+  true then 1
+  false then 0
+";
+            check_warning_count(source, 0);
+        }
+
+        #[test]
+        fn bool_with_else() {
+            let source = "
+match x
+  true then 1
+  else 0
+";
+            check_warning_count(source, 0);
+        }
+
+        #[test]
+        fn unknown_set_of_values() {
+            let source = "
+match x
+  true then 1
+  0..10 then 0
+";
+            check_warning_count(source, 0);
+        }
+    }
+
+    mod static_type_checks {
+        use super::*;
+
+        #[test]
+        fn let_with_mismatched_literal() {
+            let source = "
+let x: Number = 'hello'
+";
+            check_static_type_warning_count(source, 1);
+        }
+
+        #[test]
+        fn let_with_matching_literal() {
+            let source = "
+let x: Number = 42
+let y: Iterable = [1, 2, 3]
+let z: Any = 'hello'
+";
+            check_static_type_warning_count(source, 0);
+        }
+
+        #[test]
+        fn let_with_optional_and_union_types() {
+            let source = "
+let a: String? = null
+let b: Number | String = 'hello'
+let c: List<Number> = [1, 2, 3]
+let d: String? = 42
+let e: Number | String = true
+";
+            check_static_type_warning_count(source, 2);
+        }
+
+        #[test]
+        fn function_with_union_output_type() {
+            let source = "
+f = |x: (Number | Null)| -> Number | String
+  if x == null
+    return 'none'
+  x
+g = |x: Number?| -> String | Null
+  x
+";
+            check_static_type_warning_count(source, 0);
+        }
+
+        #[test]
+        fn static_checks_are_disabled_by_default() {
+            let source = "
+let x: Number = 'hello'
+";
+            check_warning_count(source, 0);
+        }
+
+        #[test]
+        fn function_call_with_mismatched_args() {
+            let source = "
+f = |x: Number, y: String| y.repeat x
+f 'hello', 1
+";
+            check_static_type_warning_count(source, 2);
+        }
+
+        #[test]
+        fn function_call_with_matching_args() {
+            let source = "
+f = |x: Number, y: String| y.repeat x
+s = 'abc'
+f s.size(), s.to_uppercase()
+";
+            // s.size() isn't a known core library function, so its type isn't known
+            check_static_type_warning_count(source, 0);
+        }
+
+        #[test]
+        fn function_output_type() {
+            let source = "
+f = |x: Number| -> String
+  if x > 0
+    return x
+  'negative'
+let y: Number = f 1
+";
+            check_static_type_warning_count(source, 2);
+        }
+
+        #[test]
+        fn generator_output_type() {
+            let source = "
+f = || -> Number
+  yield 1
+  yield 'two'
+";
+            check_static_type_warning_count(source, 1);
+        }
+
+        #[test]
+        fn core_lib_return_types() {
+            let source = "
+let a: String = 'abc'.to_uppercase()
+let b: Number = 'abc'.contains 'a'
+let c: Bool = number.abs -1
+let d: Number = size [1, 2, 3]
+";
+            check_static_type_warning_count(source, 2);
+        }
+
+        #[test]
+        fn bytes_return_types() {
+            let source = "
+let a: Bytes = bytes.new [1, 2, 3]
+let b: Indexable = bytes.from_hex 'ff'
+let c: String = bytes.from_base64 'aGk='
+";
+            check_static_type_warning_count(source, 1);
+        }
+
+        #[test]
+        fn big_number_return_types() {
+            let source = "
+let a: BigInt = number.big '12345678901234567890'
+let b: Decimal = number.decimal '0.1'
+let c: Number = number.decimal 1
+";
+            check_static_type_warning_count(source, 1);
+        }
+
+        #[test]
+        fn core_lib_module_call_with_mismatched_arg() {
+            let source = "
+string.to_uppercase 42
+";
+            check_static_type_warning_count(source, 1);
+        }
+
+        #[test]
+        fn reassigned_ids_have_unknown_types() {
+            let source = "
+x = 1
+x = 'hello'
+let y: String = x
+";
+            check_static_type_warning_count(source, 0);
+        }
+
+        #[test]
+        fn shadowed_core_lib_module() {
+            let source = "
+string = {to_uppercase: |x| x}
+let x: Number = string.to_uppercase 42
+";
+            check_static_type_warning_count(source, 0);
+        }
+
+        #[test]
+        fn interface_type_hints() {
+            let source = "
+interface Sized
+  @size
+let x: Sized = [1, 2, 3]
+";
+            check_static_type_warning_count(source, 0);
+        }
+    }
+}
@@ -48,14 +48,17 @@ impl Help {
         result.add_help_from_guide();
 
         let core_lib_files = [
+            include_doc!("core_lib/bytes.md"),
             include_doc!("core_lib/io.md"),
             include_doc!("core_lib/iterator.md"),
             include_doc!("core_lib/koto.md"),
             include_doc!("core_lib/list.md"),
+            include_doc!("core_lib/log.md"),
             include_doc!("core_lib/map.md"),
             include_doc!("core_lib/number.md"),
             include_doc!("core_lib/os.md"),
             include_doc!("core_lib/range.md"),
+            include_doc!("core_lib/set.md"),
             include_doc!("core_lib/string.md"),
             include_doc!("core_lib/test.md"),
             include_doc!("core_lib/tuple.md"),
@@ -67,6 +70,8 @@ impl Help {
 
         let extra_lib_files = [
             include_doc!("libs/color.md"),
+            include_doc!("libs/csv.md"),
+            include_doc!("libs/encoding.md"),
             include_doc!("libs/geometry.md"),
             include_doc!("libs/json.md"),
             include_doc!("libs/random.md"),
@@ -3,7 +3,7 @@ mod repl;
 
 use anyhow::{bail, Context, Result};
 use crossterm::tty::IsTty;
-use koto::prelude::*;
+use koto::{parser::format_source_excerpt, prelude::*, runtime::PathAccess, ErrorKind};
 use repl::{Repl, ReplSettings};
 use rustyline::EditMode;
 use std::{
@@ -11,6 +11,7 @@ use std::{
     error::Error,
     fs, io,
     path::{Path, PathBuf},
+    process::ExitCode,
 };
 
 #[global_allocator]
@@ -29,10 +30,19 @@ FLAGS:
     -b, --show_bytecode      Show the script's compiled bytecode
     -t, --tests              Run the script's tests before running the script
     -T, --import_tests       Run the script's tests, along with any tests in imported modules
+    -C, --check_types        Check the script's type hints before running the script
     -c, --config PATH        Config file to load when using the REPL
+    --sandbox                Deny the script access to the filesystem, processes, and env vars
+    --allow_read DIR         Allow reading from files in DIR, can be used multiple times
+    --allow_write DIR        Allow writing to files in DIR, can be used multiple times
+    --allow_run              Allow spawning processes
+    --allow_env              Allow accessing environment variables
     -v, --version            Prints version information
     -h, --help               Prints help information
 
+    Using any of the --allow flags implies --sandbox, with only the specified permissions
+    being granted.
+
 ARGS:
     <script>     The koto script to run, as a file path, or as a string when --eval is set
     <args>...    Arguments to pass into the script
@@ -70,11 +80,38 @@ struct KotoArgs {
     eval_script: bool,
     run_tests: bool,
     run_import_tests: bool,
+    check_types: bool,
     show_bytecode: bool,
     show_instructions: bool,
     script: Option<String>,
     script_args: Vec<String>,
     config_file: Option<String>,
+    sandbox: bool,
+    allow_read: Vec<PathBuf>,
+    allow_write: Vec<PathBuf>,
+    allow_run: bool,
+    allow_env: bool,
+}
+
+impl KotoArgs {
+    fn permissions(&self) -> KotoPermissions {
+        let sandboxed = self.sandbox
+            || !self.allow_read.is_empty()
+            || !self.allow_write.is_empty()
+            || self.allow_run
+            || self.allow_env;
+
+        if sandboxed {
+            KotoPermissions {
+                read: PathAccess::Within(self.allow_read.clone()),
+                write: PathAccess::Within(self.allow_write.clone()),
+                allow_process_spawning: self.allow_run,
+                allow_env_access: self.allow_env,
+            }
+        } else {
+            KotoPermissions::all()
+        }
+    }
 }
 
 fn parse_arguments() -> Result<KotoArgs> {
@@ -85,9 +122,15 @@ fn parse_arguments() -> Result<KotoArgs> {
     let show_bytecode = args.contains(["-b", "--show_bytecode"]);
     let run_tests = args.contains(["-t", "--tests"]);
     let run_import_tests = args.contains(["-T", "--import_tests"]);
+    let check_types = args.contains(["-C", "--check_types"]);
     let help = args.contains(["-h", "--help"]);
     let version = args.contains(["-v", "--version"]);
     let config_file = args.opt_value_from_str(["-c", "--config"])?;
+    let sandbox = args.contains("--sandbox");
+    let allow_read = args.values_from_str("--allow_read")?;
+    let allow_write = args.values_from_str("--allow_write")?;
+    let allow_run = args.contains("--allow_run");
+    let allow_env = args.contains("--allow_env");
 
     let script = args.subcommand()?;
 
@@ -107,15 +150,21 @@ fn parse_arguments() -> Result<KotoArgs> {
         eval_script,
         run_tests,
         run_import_tests,
+        check_types,
         show_bytecode,
         show_instructions,
         script,
         script_args,
         config_file,
+        sandbox,
+        allow_read,
+        allow_write,
+        allow_run,
+        allow_env,
     })
 }
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     let args = match parse_arguments() {
         Ok(args) => args,
         Err(error) => {
@@ -125,18 +174,20 @@ fn main() -> Result<()> {
 
     if args.help {
         println!("{}", help_string());
-        return Ok(());
+        return Ok(ExitCode::SUCCESS);
     }
 
     if args.version {
         println!("{}", version_string());
-        return Ok(());
+        return Ok(ExitCode::SUCCESS);
     }
 
     let koto_settings = KotoSettings {
         run_tests: args.run_tests || args.run_import_tests,
+        enable_static_type_checks: args.check_types,
         vm_settings: KotoVmSettings {
             run_import_tests: args.run_import_tests,
+            permissions: args.permissions(),
             ..Default::default()
         },
         ..Default::default()
@@ -175,6 +226,16 @@ fn main() -> Result<()> {
 
         match koto.compile(&script) {
             Ok(chunk) => {
+                for warning in chunk.debug_info.warnings.iter() {
+                    eprintln!(
+                        "Warning: {warning}.\n{}",
+                        format_source_excerpt(
+                            &script,
+                            &warning.span,
+                            script_path.as_deref().map(Path::new)
+                        )
+                    );
+                }
                 if args.show_bytecode {
                     println!("{}\n", &Chunk::bytes_as_string(&chunk));
                 }
@@ -189,7 +250,11 @@ fn main() -> Result<()> {
                 }
                 koto.set_args(&args.script_args)?;
                 match koto.run() {
-                    Ok(_) => {}
+                    Ok(_) => Ok(ExitCode::SUCCESS),
+                    Err(koto::Error {
+                        error: ErrorKind::Exit(code),
+                        ..
+                    }) => Ok(u8::try_from(code).map_or(ExitCode::FAILURE, ExitCode::from)),
                     Err(error) if error.source().is_some() => {
                         bail!("{error}\n{}", error.source().unwrap())
                     }
@@ -202,8 +267,6 @@ fn main() -> Result<()> {
                 bail!("{error}")
             }
         }
-
-        Ok(())
     } else {
         let config = load_config(args.config_file.as_ref())?;
 
@@ -223,6 +286,8 @@ fn main() -> Result<()> {
 fn add_modules(koto: &Koto) {
     let prelude = koto.prelude();
     prelude.insert("color", koto_color::make_module());
+    prelude.insert("csv", koto_csv::make_module());
+    prelude.insert("encoding", koto_encoding::make_module());
     prelude.insert("geometry", koto_geometry::make_module());
     prelude.insert("json", koto_json::make_module());
     prelude.insert("random", koto_random::make_module());
@@ -2,6 +2,7 @@ use std::{
     fmt,
     io::{self, Stdout, Write},
     path::PathBuf,
+    process::ExitCode,
 };
 
 use anyhow::Result;
@@ -10,7 +11,7 @@ use crossterm::{
     terminal::{self},
     tty::IsTty,
 };
-use koto::prelude::*;
+use koto::{prelude::*, Error, ErrorKind};
 use rustyline::{error::ReadlineError, Config, DefaultEditor, EditMode};
 
 use crate::help::Help;
@@ -49,6 +50,8 @@ pub struct Repl {
     continued_lines: Vec<String>,
     indent: usize,
     colored_output: bool,
+    // Set when a script calls `os.exit`
+    exit_code: Option<i32>,
 }
 
 fn history_dir() -> Option<PathBuf> {
@@ -97,11 +100,12 @@ impl Repl {
             stdout,
             continued_lines: Vec::new(),
             indent: 0,
+            exit_code: None,
             colored_output,
         })
     }
 
-    pub fn run(&mut self) -> Result<()> {
+    pub fn run(&mut self) -> Result<ExitCode> {
         let version = env!("CARGO_PKG_VERSION");
         writeln!(self.stdout, "Welcome to Koto v{version}")?;
 
@@ -117,6 +121,9 @@ impl Repl {
             match result {
                 Ok(line) => {
                     self.on_line(&line)?;
+                    if self.exit_code.is_some() {
+                        break;
+                    }
                 }
                 Err(ReadlineError::Interrupted) => {
                     writeln!(self.stdout, "^C")?;
@@ -139,7 +146,7 @@ impl Repl {
             self.editor.save_history(&path)?;
         }
 
-        Ok(())
+        Ok(ExitCode::from(self.exit_code.unwrap_or(0) as u8))
     }
 
     fn on_line(&mut self, line: &str) -> Result<()> {
@@ -184,6 +191,10 @@ impl Repl {
                                 )?;
                             }
                         },
+                        Err(Error {
+                            error: ErrorKind::Exit(code),
+                            ..
+                        }) => self.exit_code = Some(code),
                         Err(error) => {
                             if let Some(help) = self.run_help(&input) {
                                 print_wrapped!(self.stdout, "{}\n", help)?;
@@ -34,6 +34,8 @@ pub struct Koto {
     run_tests: bool,
     export_top_level_ids: bool,
     enable_type_checks: bool,
+    enable_static_type_checks: bool,
+    freeze_const_values: bool,
     script_path: Option<PathBuf>,
     chunk: Option<Ptr<Chunk>>,
 }
@@ -52,11 +54,25 @@ impl Koto {
 
     /// Creates a new instance of Koto with the given settings
     pub fn with_settings(settings: KotoSettings) -> Self {
+        // Imported modules are compiled with the same settings as the main script,
+        // apart from top-level exports which are only needed by the REPL.
+        let vm_settings = KotoVmSettings {
+            module_compiler_settings: CompilerSettings {
+                export_top_level_ids: false,
+                enable_type_checks: settings.enable_type_checks,
+                enable_static_type_checks: settings.enable_static_type_checks,
+                freeze_const_values: settings.freeze_const_values,
+            },
+            ..settings.vm_settings
+        };
+
         Self {
-            runtime: KotoVm::with_settings(settings.vm_settings),
+            runtime: KotoVm::with_settings(vm_settings),
             run_tests: settings.run_tests,
             export_top_level_ids: settings.export_top_level_ids,
             enable_type_checks: settings.enable_type_checks,
+            enable_static_type_checks: settings.enable_static_type_checks,
+            freeze_const_values: settings.freeze_const_values,
             chunk: None,
             script_path: None,
         }
@@ -87,6 +103,8 @@ impl Koto {
             CompilerSettings {
                 export_top_level_ids: self.export_top_level_ids,
                 enable_type_checks: self.enable_type_checks,
+                enable_static_type_checks: self.enable_static_type_checks,
+                freeze_const_values: self.freeze_const_values,
             },
         )?;
 
@@ -251,6 +269,18 @@ pub struct KotoSettings {
     ///
     /// Enabled by default.
     pub enable_type_checks: bool,
+    /// When enabled, type hints will be checked by the compiler before the script is run
+    ///
+    /// Any detected type mismatches are reported as warnings in the compiled chunk's debug info.
+    ///
+    /// Disabled by default.
+    pub enable_static_type_checks: bool,
+    /// When enabled, values assigned in `const` declarations will be frozen
+    ///
+    /// Frozen lists and maps will throw an error when modified.
+    ///
+    /// Enabled by default.
+    pub freeze_const_values: bool,
     /// Settings that apply to the runtime
     pub vm_settings: KotoVmSettings,
 }
@@ -304,6 +334,18 @@ impl KotoSettings {
         }
     }
 
+    /// Helper for conveniently defining a custom log sink
+    #[must_use]
+    pub fn with_log_sink(self, log_sink: impl KotoLogSink + 'static) -> Self {
+        Self {
+            vm_settings: KotoVmSettings {
+                log_sink: Some(make_ptr!(log_sink)),
+                ..self.vm_settings
+            },
+            ..self
+        }
+    }
+
     /// Convenience function for declaring the 'module imported' callback
     #[must_use]
     pub fn with_module_imported_callback(
@@ -326,6 +368,8 @@ impl Default for KotoSettings {
             run_tests: true,
             export_top_level_ids: false,
             enable_type_checks: true,
+            enable_static_type_checks: false,
+            freeze_const_values: true,
             vm_settings: KotoVmSettings::default(),
         }
     }
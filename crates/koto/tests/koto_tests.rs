@@ -99,6 +99,8 @@ mod koto_tests {
     use super::*;
 
     koto_test!(assignment);
+    koto_test!(big_numbers);
+    koto_test!(bytes);
     koto_test!(comments);
     koto_test!(control_flow);
     koto_test!(enums);
@@ -121,6 +123,8 @@ mod koto_tests {
     koto_test!(os);
     koto_test!(primes);
     koto_test!(ranges);
+    koto_test!(sets);
+    koto_test!(records);
     koto_test!(strings);
     koto_test!(tests);
     koto_test!(tuples);
@@ -129,3 +133,79 @@ mod koto_tests {
     koto_test!(error_handling, "error_handling_module/main.koto");
     koto_test!(import, "test_module/baz.koto", "test_module/main.koto");
 }
+
+mod imported_module_settings {
+    use super::*;
+
+    // Writes a module and a script that imports it to a temporary directory,
+    // then runs the script with the provided settings.
+    fn run_with_module(
+        test_name: &str,
+        module: &str,
+        settings: KotoSettings,
+    ) -> koto::Result<KValue> {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("koto_imported_module_settings_{test_name}"));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let module_path = dir.join("module.koto");
+        std::fs::write(&module_path, module).unwrap();
+        let script_path = dir.join("main.koto");
+        let script = "import module\nmodule.result";
+        std::fs::write(&script_path, script).unwrap();
+
+        let mut koto = Koto::with_settings(settings);
+        koto.set_script_path(Some(&script_path)).unwrap();
+        let result = koto.compile_and_run(script);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        result
+    }
+
+    const MODIFIED_CONST: &str = "
+const x = [1, 2]
+x.push 3
+export result = size x
+";
+
+    const MISMATCHED_TYPE_HINT: &str = "
+let x: String = 42
+export result = x
+";
+
+    #[test]
+    fn const_values_are_frozen_by_default() {
+        assert!(run_with_module("frozen", MODIFIED_CONST, KotoSettings::default()).is_err());
+    }
+
+    #[test]
+    fn const_value_freezing_can_be_disabled() {
+        let result = run_with_module(
+            "unfrozen",
+            MODIFIED_CONST,
+            KotoSettings {
+                freeze_const_values: false,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Ok(KValue::Number(n)) if n == 3));
+    }
+
+    #[test]
+    fn type_checks_are_enabled_by_default() {
+        assert!(run_with_module("checked", MISMATCHED_TYPE_HINT, KotoSettings::default()).is_err());
+    }
+
+    #[test]
+    fn type_checks_can_be_disabled() {
+        let result = run_with_module(
+            "unchecked",
+            MISMATCHED_TYPE_HINT,
+            KotoSettings {
+                enable_type_checks: false,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Ok(KValue::Number(n)) if n == 42));
+    }
+}
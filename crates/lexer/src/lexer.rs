@@ -28,6 +28,7 @@ pub enum Token {
     Dot,
     Ellipsis,
     Function,
+    QuestionMark,
     RoundOpen,
     RoundClose,
     SquareOpen,
@@ -42,7 +43,14 @@ pub enum Token {
     Subtract,
     Multiply,
     Divide,
+    IntegerDivide,
     Remainder,
+    Power,
+
+    BitwiseAnd,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
 
     Assign,
     AddAssign,
@@ -59,6 +67,7 @@ pub enum Token {
     LessOrEqual,
 
     Arrow,
+    NullCoalesce,
 
     // Keywords
     As,
@@ -117,6 +126,8 @@ impl Token {
 pub enum StringType {
     /// A normal string
     Normal(StringQuote),
+    /// A multi-line string, delimited by three quotation marks, e.g. `'''`
+    MultiLine(StringQuote),
     /// A raw string
     Raw(RawStringDelimiter),
 }
@@ -138,6 +149,16 @@ pub enum StringQuote {
     Single,
 }
 
+impl StringQuote {
+    /// Returns the delimiter used for multi-line strings with this quotation mark
+    pub fn multi_line_delimiter(&self) -> &'static str {
+        match self {
+            Self::Double => "\"\"\"",
+            Self::Single => "'''",
+        }
+    }
+}
+
 impl TryFrom<char> for StringQuote {
     type Error = ();
 
@@ -155,6 +176,8 @@ impl TryFrom<char> for StringQuote {
 enum StringMode {
     // Inside a string literal, expecting an end quote or the start of a template expression
     Literal(StringQuote),
+    // Inside a multi-line string literal, expecting an end delimiter or a template expression
+    MultiLineLiteral(StringQuote),
     // Inside a string template, e.g. '{...}'
     TemplateExpr,
     // Inside an inline map in a template expression, e.g. '{foo({bar: 42})}'
@@ -336,11 +359,25 @@ impl<'a> TokenLexer<'a> {
         }
     }
 
+    fn consume_string_start(&mut self, remaining: &str, quote: StringQuote) -> Token {
+        if remaining.starts_with(quote.multi_line_delimiter()) {
+            self.advance_line(3);
+            self.string_mode_stack
+                .push(StringMode::MultiLineLiteral(quote));
+            Token::StringStart(StringType::MultiLine(quote))
+        } else {
+            self.advance_line(1);
+            self.string_mode_stack.push(StringMode::Literal(quote));
+            Token::StringStart(StringType::Normal(quote))
+        }
+    }
+
     fn consume_string_literal(&mut self, mut chars: Peekable<Chars>) -> Token {
         use Token::*;
 
-        let end_quote = match self.string_mode_stack.last() {
-            Some(StringMode::Literal(quote)) => *quote,
+        let (end_quote, multi_line) = match self.string_mode_stack.last() {
+            Some(StringMode::Literal(quote)) => (*quote, false),
+            Some(StringMode::MultiLineLiteral(quote)) => (*quote, true),
             _ => return Error,
         };
 
@@ -349,7 +386,10 @@ impl<'a> TokenLexer<'a> {
 
         while let Some(c) = chars.peek().cloned() {
             match c {
-                _ if c.try_into() == Ok(end_quote) => {
+                _ if c.try_into() == Ok(end_quote)
+                    && (!multi_line
+                        || chars.clone().take(3).all(|c| c.try_into() == Ok(end_quote))) =>
+                {
                     self.advance_to_position(string_bytes, position);
                     return StringLiteral;
                 }
@@ -712,10 +752,15 @@ impl<'a> TokenLexer<'a> {
 
         check_symbol!("->", Arrow);
 
+        check_symbol!("??", NullCoalesce);
+        check_symbol!("?", QuestionMark);
+
         check_symbol!("==", Equal);
         check_symbol!("!=", NotEqual);
         check_symbol!(">=", GreaterOrEqual);
         check_symbol!("<=", LessOrEqual);
+        check_symbol!(">>", ShiftRight);
+        check_symbol!("<<", ShiftLeft);
         check_symbol!(">", Greater);
         check_symbol!("<", Less);
 
@@ -726,12 +771,18 @@ impl<'a> TokenLexer<'a> {
         check_symbol!("/=", DivideAssign);
         check_symbol!("%=", RemainderAssign);
 
+        check_symbol!("**", Power);
+        check_symbol!("//", IntegerDivide);
+
         check_symbol!("+", Add);
         check_symbol!("-", Subtract);
         check_symbol!("*", Multiply);
         check_symbol!("/", Divide);
         check_symbol!("%", Remainder);
 
+        check_symbol!("&", BitwiseAnd);
+        check_symbol!("^", BitwiseXor);
+
         check_symbol!("@", At);
         check_symbol!(":", Colon);
         check_symbol!(",", Comma);
@@ -777,6 +828,19 @@ impl<'a> TokenLexer<'a> {
                         }
                         _ => self.consume_string_literal(chars),
                     },
+                    Some(StringMode::MultiLineLiteral(quote)) => match next_char {
+                        _ if remaining.starts_with(quote.multi_line_delimiter()) => {
+                            self.advance_line(3);
+                            self.string_mode_stack.pop();
+                            StringEnd
+                        }
+                        '{' => {
+                            self.advance_line(1);
+                            self.string_mode_stack.push(StringMode::TemplateExpr);
+                            CurlyOpen
+                        }
+                        _ => self.consume_string_literal(chars),
+                    },
                     Some(StringMode::RawStart(delimiter)) => {
                         self.consume_raw_string_contents(chars, delimiter)
                     }
@@ -793,18 +857,8 @@ impl<'a> TokenLexer<'a> {
                         }
                         '\r' | '\n' => self.consume_newline(chars),
                         '#' => self.consume_comment(chars),
-                        '"' => {
-                            self.advance_line(1);
-                            self.string_mode_stack
-                                .push(StringMode::Literal(StringQuote::Double));
-                            StringStart(StringType::Normal(StringQuote::Double))
-                        }
-                        '\'' => {
-                            self.advance_line(1);
-                            self.string_mode_stack
-                                .push(StringMode::Literal(StringQuote::Single));
-                            StringStart(StringType::Normal(StringQuote::Single))
-                        }
+                        '"' => self.consume_string_start(remaining, StringQuote::Double),
+                        '\'' => self.consume_string_start(remaining, StringQuote::Single),
                         '0'..='9' => self.consume_number(chars),
                         c if is_id_start(c) => self.consume_id_or_keyword(chars),
                         '_' => self.consume_wildcard(chars),
@@ -1094,6 +1148,10 @@ mod tests {
             Token::StringStart(StringType::Normal(quote))
         }
 
+        fn multi_line_string(quote: StringQuote) -> Token {
+            Token::StringStart(StringType::MultiLine(quote))
+        }
+
         fn raw_string(quote: StringQuote, hash_count: u8) -> Token {
             Token::StringStart(StringType::Raw(RawStringDelimiter { quote, hash_count }))
         }
@@ -1208,6 +1266,43 @@ false #
             );
         }
 
+        #[test]
+        fn multi_line_strings() {
+            let input = r#"
+'''
+  'foo'
+  {x}
+'''
+"""
+"bar"
+"""
+''''''
+"#;
+
+            use StringQuote::*;
+            check_lexer_output(
+                input,
+                &[
+                    (NewLine, None, 0),
+                    (multi_line_string(Single), None, 1),
+                    (StringLiteral, Some("\n  'foo'\n  "), 1),
+                    (CurlyOpen, None, 3),
+                    (Id, Some("x"), 3),
+                    (CurlyClose, None, 3),
+                    (StringLiteral, Some("\n"), 3),
+                    (StringEnd, None, 4),
+                    (NewLine, None, 4),
+                    (multi_line_string(Double), None, 5),
+                    (StringLiteral, Some("\n\"bar\"\n"), 5),
+                    (StringEnd, None, 7),
+                    (NewLine, None, 7),
+                    (multi_line_string(Single), None, 8),
+                    (StringEnd, None, 8),
+                    (NewLine, None, 8),
+                ],
+            );
+        }
+
         #[test]
         fn raw_strings() {
             let input = r#"
@@ -1335,6 +1430,53 @@ r#''bar''#
             );
         }
 
+        #[test]
+        fn arithmetic_and_bitwise_operators() {
+            let input = "a // b ** c & d | e ^ f << g >> h";
+
+            check_lexer_output(
+                input,
+                &[
+                    (Id, Some("a"), 0),
+                    (IntegerDivide, None, 0),
+                    (Id, Some("b"), 0),
+                    (Power, None, 0),
+                    (Id, Some("c"), 0),
+                    (BitwiseAnd, None, 0),
+                    (Id, Some("d"), 0),
+                    (Function, None, 0),
+                    (Id, Some("e"), 0),
+                    (BitwiseXor, None, 0),
+                    (Id, Some("f"), 0),
+                    (ShiftLeft, None, 0),
+                    (Id, Some("g"), 0),
+                    (ShiftRight, None, 0),
+                    (Id, Some("h"), 0),
+                ],
+            );
+        }
+
+        #[test]
+        fn null_coalescing_and_optional_chaining() {
+            let input = "a?.b ?? c?[0]";
+
+            check_lexer_output(
+                input,
+                &[
+                    (Id, Some("a"), 0),
+                    (QuestionMark, None, 0),
+                    (Dot, None, 0),
+                    (Id, Some("b"), 0),
+                    (NullCoalesce, None, 0),
+                    (Id, Some("c"), 0),
+                    (QuestionMark, None, 0),
+                    (SquareOpen, None, 0),
+                    (Number, Some("0"), 0),
+                    (SquareClose, None, 0),
+                ],
+            );
+        }
+
         #[test]
         fn numbers() {
             let input = "\
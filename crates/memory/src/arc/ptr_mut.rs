@@ -86,6 +86,15 @@ impl<'a, T: ?Sized> Borrow<'a, T> {
         Self(RwLockReadGuard::map(guard, |x| x))
     }
 
+    /// Makes a new Borrow for a component of the borrowed data.
+    pub fn map<U, F>(borrowed: Self, f: F) -> Borrow<'a, U>
+    where
+        F: FnOnce(&T) -> &U,
+        U: ?Sized,
+    {
+        Borrow(MappedRwLockReadGuard::map(borrowed.0, f))
+    }
+
     /// Makes a new Borrow for an optional component of the borrowed data.
     /// If the closure returns None then the original borrow is returned as the error.
     pub fn filter_map<U, F>(borrowed: Self, f: F) -> Result<Borrow<'a, U>, Self>
@@ -122,6 +131,15 @@ impl<'a, T: ?Sized> BorrowMut<'a, T> {
         Self(RwLockWriteGuard::map(guard, |x| x))
     }
 
+    /// Makes a new BorrowMut for a component of the borrowed data.
+    pub fn map<U, F>(borrowed: Self, f: F) -> BorrowMut<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+        U: ?Sized,
+    {
+        BorrowMut(MappedRwLockWriteGuard::map(borrowed.0, f))
+    }
+
     /// Makes a new BorrowMut for an optional component of the borrowed data.
     /// If the closure returns None then the original borrow is returned as the error.
     pub fn filter_map<U, F>(borrowed: Self, f: F) -> Result<BorrowMut<'a, U>, Self>
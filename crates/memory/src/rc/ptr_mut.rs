@@ -83,6 +83,15 @@ impl<'a, T: ?Sized> Borrow<'a, T> {
         Self(Ref::map(guard, |x| x))
     }
 
+    /// Makes a new Borrow for a component of the borrowed data.
+    pub fn map<U, F>(borrowed: Self, f: F) -> Borrow<'a, U>
+    where
+        F: FnOnce(&T) -> &U,
+        U: ?Sized,
+    {
+        Borrow(Ref::map(borrowed.0, f))
+    }
+
     /// Makes a new Borrow for an optional component of the borrowed data.
     /// If the closure returns None then the original borrow is returned as the error.
     pub fn filter_map<U, F>(borrowed: Self, f: F) -> Result<Borrow<'a, U>, Self>
@@ -117,6 +126,15 @@ impl<'a, T: ?Sized> BorrowMut<'a, T> {
         Self(RefMut::map(guard, |x| x))
     }
 
+    /// Makes a new BorrowMut for a component of the borrowed data.
+    pub fn map<U, F>(borrowed: Self, f: F) -> BorrowMut<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+        U: ?Sized,
+    {
+        BorrowMut(RefMut::map(borrowed.0, f))
+    }
+
     /// Makes a new BorrowMut for an optional component of the borrowed data.
     /// If the closure returns None then the original borrow is returned as the error.
     pub fn filter_map<U, F>(borrowed: Self, f: F) -> Result<BorrowMut<'a, U>, Self>
@@ -45,8 +45,12 @@ pub enum ExpectedIndentation {
     ElseBlock,
     #[error("Expected indented block for 'else if'.")]
     ElseIfBlock,
+    #[error("Expected indented variants for enum declaration")]
+    EnumVariants,
     #[error("Expected indented block for finally expression")]
     FinallyBody,
+    #[error("Expected indented entries for interface declaration")]
+    InterfaceEntries,
     #[error("Expected indented block as for loop body")]
     ForBody,
     #[error("Expected function body")]
@@ -87,10 +91,16 @@ pub enum SyntaxError {
     ExpectedCatch,
     #[error("Expected closing parenthesis ')'")]
     ExpectedCloseParen,
+    #[error("Expected '=' after const id")]
+    ExpectedConstAssignment,
+    #[error("Expected id after 'const'")]
+    ExpectedConstId,
     #[error("Expected expression after 'else'.")]
     ExpectedElseExpression,
     #[error("Expected condition for 'else if'.")]
     ExpectedElseIfCondition,
+    #[error("Expected enum variant")]
+    ExpectedEnumVariant,
     #[error("Expected expression")]
     ExpectedExpression,
     #[error("Expected arguments in for loop")]
@@ -109,6 +119,8 @@ pub enum SyntaxError {
     ExpectedIfCondition,
     #[error("Expected import after from")]
     ExpectedImportAfterFrom,
+    #[error("Expected key or meta key for interface entry")]
+    ExpectedInterfaceEntry,
     #[error("Expected module ID in import expression")]
     ExpectedImportModuleId,
     #[error("Expected index end ']'")]
@@ -129,6 +141,10 @@ pub enum SyntaxError {
     ExpectedMapKey,
     #[error("Expected value after ':' in Map")]
     ExpectedMapValue,
+    #[error("Expected '}}' at end of map pattern")]
+    ExpectedMapPatternEnd,
+    #[error("Expected an id or string literal as a key in map pattern")]
+    ExpectedMapPatternKey,
     #[error("Expected expression in match arm")]
     ExpectedMatchArmExpression,
     #[error("Expected expression after then in match arm")]
@@ -137,8 +153,16 @@ pub enum SyntaxError {
     ExpectedMatchCondition,
     #[error("Expected expression after match")]
     ExpectedMatchExpression,
+    #[error("Expected '>' at the end of nested types")]
+    ExpectedNestedTypesEnd,
+    #[error("Expected '.', '[', or '(' after '?'")]
+    ExpectedNullCheckedChainNode,
     #[error("Expected pattern for match arm")]
     ExpectedMatchPattern,
+    #[error("Expected a number literal as a bound for the range pattern")]
+    ExpectedRangePatternBound,
+    #[error("Expected id for record field")]
+    ExpectedRecordField,
     #[error("Expected id after @meta")]
     ExpectedMetaId,
     #[error("Expected a module path after 'from'")]
@@ -171,8 +195,6 @@ pub enum SyntaxError {
     MatchEllipsisOutsideOfNestedPatterns,
     #[error("'else' can only be used in the last arm in a match expression")]
     MatchElseNotInLastArm,
-    #[error("Nested types aren't currently supported")]
-    NestedTypesArentSupported,
     #[error("Keyword reserved for future use")]
     ReservedKeyword,
     #[error("'self' doesn't need to be declared as an argument")]
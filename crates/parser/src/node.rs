@@ -171,6 +171,50 @@ pub enum Node {
         expression: AstIndex,
     },
 
+    /// A `const` declaration
+    ///
+    /// e.g. `const x = 42`
+    Const {
+        /// The declared id, an [Node::Id] with an optional type hint
+        target: AstIndex,
+        /// The expression to be assigned
+        expression: AstIndex,
+    },
+
+    /// A record declaration
+    ///
+    /// Records are also used for the variants of [Node::Enum] declarations.
+    ///
+    /// e.g. `record Point(x: Number, y: Number)`
+    Record {
+        /// The record's name
+        name: ConstantIndex,
+        /// The record's fields, [Node::Id]s with optional type hints
+        fields: AstVec<AstIndex>,
+    },
+
+    /// An enum declaration
+    ///
+    /// The enum's variants are declared on indented lines following the enum's name,
+    /// e.g. `enum Shape` followed by `Circle(radius: Number)` and `Empty`.
+    Enum {
+        /// The enum's name
+        name: ConstantIndex,
+        /// The enum's variants, [Node::Record]s
+        variants: AstVec<AstIndex>,
+    },
+
+    /// An interface declaration
+    ///
+    /// The keys and meta keys that conforming values need to provide are declared on indented
+    /// lines following the interface's name, e.g. `interface Sized` followed by `@size`.
+    Interface {
+        /// The interface's name
+        name: ConstantIndex,
+        /// The interface's required entries, [Node::Id]s, [Node::Str]s, or [Node::Meta]s
+        entries: AstVec<AstIndex>,
+    },
+
     /// A unary operation
     UnaryOp {
         /// The operator to use
@@ -213,9 +257,29 @@ pub enum Node {
 
     /// The `...` operator
     ///
-    /// Used when capturing variadic arguments, and when unpacking list or tuple values.
+    /// Used when capturing variadic arguments, and when unpacking list, tuple, or map values.
     Ellipsis(Option<ConstantIndex>),
 
+    /// A map pattern, used when unpacking maps in function args, let expressions, and match arms
+    ///
+    /// Keys will either be Id or String nodes, followed by an optional pattern for the entry's
+    /// value. If the value pattern is omitted then the key must be an Id, which receives the value.
+    ///
+    /// An Ellipsis can be used as the last key, capturing the remaining entries in a new map.
+    ///
+    /// e.g. `{name, age: a, rest...}`
+    MapPattern(AstVec<(AstIndex, Option<AstIndex>)>),
+
+    /// A record pattern, used in match arms to match records and enum variants
+    ///
+    /// e.g. `Shape.Circle(r) then ...`
+    RecordPattern {
+        /// The record's constructor, an [Node::Id] or [Node::Chain]
+        constructor: AstIndex,
+        /// Patterns for the record's fields, matched in the order they were declared
+        fields: AstVec<AstIndex>,
+    },
+
     /// A `for` loop
     For(AstFor),
 
@@ -271,7 +335,34 @@ pub enum Node {
     ///
     /// e.g. `let x: Number = 0`
     ///            ^~~ This is the beginning of the type hint
-    Type(ConstantIndex),
+    Type {
+        /// The full type hint as a string, used when checking the type at runtime
+        ///
+        /// e.g. `List<Number>?`
+        hint: ConstantIndex,
+        /// The name of the outer type
+        ///
+        /// e.g. `List` in `List<Number>`
+        name: ConstantIndex,
+        /// The type's nested types
+        ///
+        /// e.g. `String` and `Number` in `Map<String, Number>`
+        nested: AstVec<AstIndex>,
+        /// True if the type is optional, i.e. `null` is also accepted
+        ///
+        /// e.g. `String?`
+        optional: bool,
+    },
+
+    /// A union of type hints
+    ///
+    /// e.g. `let x: Number | String = 0`
+    TypeUnion {
+        /// The full type hint as a string, used when checking the type at runtime
+        hint: ConstantIndex,
+        /// The union's member types
+        types: AstVec<AstIndex>,
+    },
 }
 
 /// A function definition
@@ -383,7 +474,14 @@ pub enum AstBinaryOp {
     Subtract,
     Multiply,
     Divide,
+    IntegerDivide,
     Remainder,
+    Power,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
     AddAssign,
     SubtractAssign,
     MultiplyAssign,
@@ -397,6 +495,7 @@ pub enum AstBinaryOp {
     GreaterOrEqual,
     And,
     Or,
+    NullCoalesce,
     Pipe,
 }
 
@@ -424,7 +523,8 @@ pub struct AstCatch {
 ///
 /// Chains are any expressions that contain two or more nodes in a sequence.
 ///
-/// In other words, some series of operations involving indexing, `.` accesses, and function calls.
+/// In other words, some series of operations involving indexing, `.` accesses, function calls,
+/// and `?` null checks.
 ///
 /// e.g.
 /// `foo.bar."baz"[0](42)`
@@ -457,6 +557,11 @@ pub enum ChainNode {
         ///   `99 -> foo.bar(42)` is equivalent to `foo.bar(42)(99)`.
         with_parens: bool,
     },
+    /// A `?` null check, which short-circuits the rest of the chain if the value is null
+    ///
+    /// e.g.
+    ///   `foo?.bar` evaluates to null if `foo` is null, otherwise `foo.bar` is accessed.
+    NullCheck,
 }
 
 /// An arm in a match expression
@@ -513,8 +618,22 @@ pub enum MetaKeyId {
     Multiply,
     /// @/
     Divide,
+    /// @//
+    IntegerDivide,
     /// @%
     Remainder,
+    /// @**
+    Power,
+    /// @&
+    BitwiseAnd,
+    /// @|
+    BitwiseOr,
+    /// @^
+    BitwiseXor,
+    /// @<<
+    ShiftLeft,
+    /// @>>
+    ShiftRight,
     /// @+=
     AddAssign,
     /// @-=
@@ -542,6 +661,8 @@ pub enum MetaKeyId {
 
     /// @display
     Display,
+    /// @format
+    Format,
     /// @iterator
     Iterator,
     /// @next
@@ -607,7 +728,14 @@ impl fmt::Display for MetaKeyId {
                 Subtract => "-",
                 Multiply => "*",
                 Divide => "/",
+                IntegerDivide => "//",
                 Remainder => "%",
+                Power => "**",
+                BitwiseAnd => "&",
+                BitwiseOr => "|",
+                BitwiseXor => "^",
+                ShiftLeft => "<<",
+                ShiftRight => ">>",
                 AddAssign => "+=",
                 SubtractAssign => "-=",
                 MultiplyAssign => "*=",
@@ -621,6 +749,7 @@ impl fmt::Display for MetaKeyId {
                 NotEqual => "!=",
                 Index => "[]",
                 Display => "display",
+                Format => "format",
                 Iterator => "iterator",
                 Next => "next",
                 NextBack => "next_back",
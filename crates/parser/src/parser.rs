@@ -594,7 +594,15 @@ impl<'source> Parser<'source> {
                         Subtract => AstBinaryOp::Subtract,
                         Multiply => AstBinaryOp::Multiply,
                         Divide => AstBinaryOp::Divide,
+                        IntegerDivide => AstBinaryOp::IntegerDivide,
                         Remainder => AstBinaryOp::Remainder,
+                        Power => AstBinaryOp::Power,
+
+                        BitwiseAnd => AstBinaryOp::BitwiseAnd,
+                        Function => AstBinaryOp::BitwiseOr,
+                        BitwiseXor => AstBinaryOp::BitwiseXor,
+                        ShiftLeft => AstBinaryOp::ShiftLeft,
+                        ShiftRight => AstBinaryOp::ShiftRight,
 
                         AddAssign => AstBinaryOp::AddAssign,
                         SubtractAssign => AstBinaryOp::SubtractAssign,
@@ -612,6 +620,7 @@ impl<'source> Parser<'source> {
 
                         And => AstBinaryOp::And,
                         Or => AstBinaryOp::Or,
+                        NullCoalesce => AstBinaryOp::NullCoalesce,
 
                         Arrow => AstBinaryOp::Pipe,
 
@@ -671,6 +680,8 @@ impl<'source> Parser<'source> {
                     self.frame_mut()?.add_local_id_assignment(id_index);
                 }
                 Node::Meta { .. } | Node::Chain(_) | Node::Wildcard(..) => {}
+                // Ids in map patterns have already been noted when parsing the pattern
+                Node::MapPattern(_) => {}
                 _ => return self.error(SyntaxError::ExpectedAssignmentTarget),
             }
 
@@ -747,7 +758,12 @@ impl<'source> Parser<'source> {
                     self.check_for_chain_after_node(string_node, &string.context)
                 }
             }
-            Token::Id => self.consume_id_expression(context),
+            Token::Id => match self.peeked_type_declaration(&peeked) {
+                Some(TypeDeclaration::Record) => self.consume_record_declaration(context),
+                Some(TypeDeclaration::Enum) => self.consume_enum_declaration(context),
+                Some(TypeDeclaration::Interface) => self.consume_interface_declaration(context),
+                None => self.consume_id_expression(context),
+            },
             Token::Self_ => self.consume_self_expression(context),
             Token::At => {
                 let map_block_allowed =
@@ -858,9 +874,9 @@ impl<'source> Parser<'source> {
             Token::Export => self.consume_export(context),
             Token::Try => self.consume_try_expression(context),
             Token::Let => self.consume_let_expression(context),
+            Token::Const => self.consume_const_expression(context),
             // Reserved keywords
             Token::Await => self.consume_token_and_error(SyntaxError::ReservedKeyword),
-            Token::Const => self.consume_token_and_error(SyntaxError::ReservedKeyword),
             // An error occurred in the lexer
             Token::Error => self.consume_token_and_error(SyntaxError::LexerError),
             _ => return Ok(None),
@@ -881,6 +897,10 @@ impl<'source> Parser<'source> {
 
         let span_start = self.current_span().start;
 
+        // The function's frame is pushed before parsing the args, so that names accessed in type
+        // hints are captured by the function.
+        self.frame_stack.push(Frame::default());
+
         // Parse function's args
         let mut arg_nodes = AstVec::new();
         let mut arg_ids = AstVec::new();
@@ -895,7 +915,7 @@ impl<'source> Parser<'source> {
                 Some(IdOrWildcard::Id(constant_index)) => {
                     arg_ids.push(constant_index);
                     let arg_span = self.current_span();
-                    let type_hint = self.parse_type_hint(&args_context)?;
+                    let type_hint = self.parse_function_arg_type_hint(&args_context)?;
                     arg_nodes.push(
                         self.push_node_with_span(Node::Id(constant_index, type_hint), arg_span)?,
                     );
@@ -911,7 +931,7 @@ impl<'source> Parser<'source> {
                 }
                 Some(IdOrWildcard::Wildcard(maybe_id)) => {
                     let arg_span = self.current_span();
-                    let type_hint = self.parse_type_hint(&args_context)?;
+                    let type_hint = self.parse_function_arg_type_hint(&args_context)?;
                     arg_nodes.push(
                         self.push_node_with_span(Node::Wildcard(maybe_id, type_hint), arg_span)?,
                     );
@@ -937,6 +957,10 @@ impl<'source> Parser<'source> {
                             nested_span_start,
                         )?);
                     }
+                    Some(Token::CurlyOpen) => {
+                        self.consume_token();
+                        arg_nodes.push(self.parse_map_pattern(false, &mut arg_ids)?);
+                    }
                     _ => break,
                 },
             }
@@ -961,18 +985,20 @@ impl<'source> Parser<'source> {
         // Check for output type hint
         let output_type = if self.peek_next_token_on_same_line() == Some(Token::Arrow) {
             self.consume_token_with_context(context); // ->
-            let Some((output_type, _)) = self.parse_id(context)? else {
-                return self.consume_token_and_error(SyntaxError::ExpectedType);
-            };
-            Some(self.push_node(Node::Type(output_type))?)
+            let mut pending_close = false;
+            let (output_type, _) = self.parse_type(context, true, &mut pending_close)?;
+            if pending_close {
+                return self.error(SyntaxError::UnexpectedToken);
+            }
+            Some(output_type)
         } else {
             None
         };
 
         // body
-        let mut function_frame = Frame::default();
-        function_frame.ids_assigned_in_frame.extend(arg_ids.iter());
-        self.frame_stack.push(function_frame);
+        self.frame_mut()?
+            .ids_assigned_in_frame
+            .extend(arg_ids.iter());
 
         let body = if let Some(block) = self.parse_indented_block()? {
             block
@@ -1019,6 +1045,25 @@ impl<'source> Parser<'source> {
     // let x: String = 'hello'
     //      ^ You are here
     fn parse_type_hint(&mut self, context: &ExpressionContext) -> Result<Option<AstIndex>> {
+        self.parse_type_hint_with_unions(context, true)
+    }
+
+    // Parses a type hint following a top-level function argument
+    //
+    // The `|` token ends the function's arguments, so type unions need to be wrapped in
+    // parentheses, e.g. `|x: (Number | String)|`.
+    fn parse_function_arg_type_hint(
+        &mut self,
+        context: &ExpressionContext,
+    ) -> Result<Option<AstIndex>> {
+        self.parse_type_hint_with_unions(context, false)
+    }
+
+    fn parse_type_hint_with_unions(
+        &mut self,
+        context: &ExpressionContext,
+        allow_unions: bool,
+    ) -> Result<Option<AstIndex>> {
         let Some(PeekInfo {
             token: Token::Colon,
             ..
@@ -1028,17 +1073,190 @@ impl<'source> Parser<'source> {
         };
 
         self.consume_token_with_context(context); // :
-        let Some((type_hint, _)) = self.parse_id(context)? else {
+
+        let mut pending_close = false;
+        let (result, _) = self.parse_type(context, allow_unions, &mut pending_close)?;
+        if pending_close {
+            return self.error(SyntaxError::UnexpectedToken);
+        }
+
+        Ok(Some(result))
+    }
+
+    // Parses a type, along with any types that are joined with it in a union
+    //
+    // The type's node is returned along with its string representation.
+    //
+    // `>>` is lexed as a single token, so when a nested type list is ended with `>>`,
+    // `pending_close` is set to indicate that the enclosing nested type list is also closed.
+    fn parse_type(
+        &mut self,
+        context: &ExpressionContext,
+        allow_unions: bool,
+        pending_close: &mut bool,
+    ) -> Result<(AstIndex, String)> {
+        let first = self.parse_optional_type(context, pending_close)?;
+
+        if !allow_unions
+            || *pending_close
+            || self.peek_next_token_on_same_line() != Some(Token::Function)
+        {
+            return Ok(first);
+        }
+
+        let mut members = vec![first];
+        while !*pending_close && self.peek_next_token_on_same_line() == Some(Token::Function) {
+            self.consume_next_token_on_same_line(); // |
+            members
+                .push(self.parse_optional_type(&ExpressionContext::restricted(), pending_close)?);
+        }
+
+        self.push_type_union(members)
+    }
+
+    // Parses a single type, including nested types and an optional `?` suffix
+    fn parse_optional_type(
+        &mut self,
+        context: &ExpressionContext,
+        pending_close: &mut bool,
+    ) -> Result<(AstIndex, String)> {
+        if matches!(
+            self.peek_token_with_context(context),
+            Some(PeekInfo {
+                token: Token::RoundOpen,
+                ..
+            })
+        ) {
+            self.consume_token_with_context(context); // (
+
+            let mut inner_pending_close = false;
+            let inner = self.parse_type(
+                &ExpressionContext::restricted(),
+                true,
+                &mut inner_pending_close,
+            )?;
+            if inner_pending_close {
+                return self.error(SyntaxError::UnexpectedToken);
+            }
+            if self.consume_next_token_on_same_line() != Some(Token::RoundClose) {
+                return self.error(SyntaxError::ExpectedCloseParen);
+            }
+
+            // A grouped type followed by `?` is treated as a union that includes Null
+            if self.peek_next_token_on_same_line() == Some(Token::QuestionMark) {
+                self.consume_next_token_on_same_line(); // ?
+                let null_name = self.add_string_constant("Null")?;
+                let null_type = self.push_node(Node::Type {
+                    hint: null_name,
+                    name: null_name,
+                    nested: AstVec::new(),
+                    optional: false,
+                })?;
+                return self.push_type_union(vec![inner, (null_type, "Null".into())]);
+            }
+
+            return Ok(inner);
+        }
+
+        let Some((name, _)) = self.parse_id(context)? else {
             return self.consume_token_and_error(SyntaxError::ExpectedType);
         };
+        // The type's name might refer to a local value, e.g. an interface, so it's counted as an
+        // access to make sure that the value is captured by nested functions.
+        if self
+            .frame_stack
+            .iter()
+            .any(|frame| frame.ids_assigned_in_frame.contains(&name))
+        {
+            self.frame_mut()?.add_id_access(name);
+        }
+        let mut hint_string = self.constants.get_str(name).to_string();
 
-        // Check for the start of a nested type
+        // Nested types, e.g. `List<Number>`
+        let mut nested = AstVec::new();
         if self.peek_next_token_on_same_line() == Some(Token::Less) {
-            return self.consume_token_and_error(SyntaxError::NestedTypesArentSupported);
+            self.consume_next_token_on_same_line(); // <
+
+            let mut nested_strings = Vec::new();
+            loop {
+                let mut nested_pending_close = false;
+                let (nested_type, nested_string) = self.parse_type(
+                    &ExpressionContext::restricted(),
+                    true,
+                    &mut nested_pending_close,
+                )?;
+                nested.push(nested_type);
+                nested_strings.push(nested_string);
+
+                if nested_pending_close {
+                    // The nested type ended with `>>`, which also closes this type's nested list
+                    break;
+                }
+
+                match self.consume_next_token_on_same_line() {
+                    Some(Token::Comma) => {}
+                    Some(Token::Greater) => break,
+                    Some(Token::ShiftRight) => {
+                        *pending_close = true;
+                        break;
+                    }
+                    _ => return self.error(SyntaxError::ExpectedNestedTypesEnd),
+                }
+            }
+
+            hint_string.push('<');
+            hint_string.push_str(&nested_strings.join(", "));
+            hint_string.push('>');
+        }
+
+        // Optional types, e.g. `String?`
+        let optional = if !*pending_close
+            && self.peek_next_token_on_same_line() == Some(Token::QuestionMark)
+        {
+            self.consume_next_token_on_same_line(); // ?
+            hint_string.push('?');
+            true
+        } else {
+            false
         };
 
-        let result = self.push_node(Node::Type(type_hint))?;
-        Ok(Some(result))
+        let hint = if nested.is_empty() && !optional {
+            name
+        } else {
+            self.add_string_constant(&hint_string)?
+        };
+
+        let result = self.push_node(Node::Type {
+            hint,
+            name,
+            nested,
+            optional,
+        })?;
+
+        Ok((result, hint_string))
+    }
+
+    // Makes a type union node from a series of types, flattening any nested unions
+    fn push_type_union(&mut self, members: Vec<(AstIndex, String)>) -> Result<(AstIndex, String)> {
+        let mut types = AstVec::new();
+        let mut member_strings = Vec::with_capacity(members.len());
+
+        for (member, member_string) in members {
+            match &self.ast.node(member).node {
+                Node::TypeUnion {
+                    types: nested_types,
+                    ..
+                } => types.extend(nested_types.iter().copied()),
+                _ => types.push(member),
+            }
+            member_strings.push(member_string);
+        }
+
+        let hint_string = member_strings.join(" | ");
+        let hint = self.add_string_constant(&hint_string)?;
+        let result = self.push_node(Node::TypeUnion { hint, types })?;
+
+        Ok((result, hint_string))
     }
 
     // Helper for parse_function() that recursively parses nested function arguments
@@ -1095,6 +1313,10 @@ impl<'source> Parser<'source> {
                             self.push_node_with_start_span(Node::Tuple(tuple_args), span_start)?,
                         );
                     }
+                    Some(Token::CurlyOpen) => {
+                        self.consume_token();
+                        nested_args.push(self.parse_map_pattern(false, arg_ids)?);
+                    }
                     Some(Token::Ellipsis) => {
                         self.consume_token();
                         nested_args.push(self.push_node(Node::Ellipsis(None))?);
@@ -1113,6 +1335,185 @@ impl<'source> Parser<'source> {
         Ok(nested_args)
     }
 
+    // Parses a map pattern, used when unpacking maps in function args, let expressions,
+    // and match arms
+    //
+    // Any ids that receive values from the pattern are added to `assigned_ids`.
+    //
+    // e.g.
+    //   f = |{name, age: a, rest...}|
+    //   #     ^ You are here
+    fn parse_map_pattern(
+        &mut self,
+        is_match_pattern: bool,
+        assigned_ids: &mut AstVec<ConstantIndex>,
+    ) -> Result<AstIndex> {
+        let start_span = self.current_span();
+        let mut entries = AstVec::new();
+        let mut entry_context = ExpressionContext::braced_items_start();
+
+        while self.peek_token_with_context(&entry_context).is_some() {
+            self.consume_until_token_with_context(&entry_context);
+
+            match self.peek_token() {
+                Some(Token::Id) => {
+                    let Some((id, _)) = self.parse_id(&ExpressionContext::restricted())? else {
+                        return self.error(InternalError::IdParseFailure);
+                    };
+                    let key_span = self.current_span();
+
+                    match self.peek_token() {
+                        Some(Token::Ellipsis) => {
+                            // e.g. `{foo, rest...}`
+                            self.consume_token();
+                            let rest =
+                                self.push_node_with_span(Node::Ellipsis(Some(id)), key_span)?;
+                            entries.push((rest, None));
+                            assigned_ids.push(id);
+                            break;
+                        }
+                        Some(Token::Colon) => {
+                            // e.g. `{foo: x}`
+                            let key = self.push_node_with_span(Node::Id(id, None), key_span)?;
+                            let value =
+                                self.parse_map_pattern_value(is_match_pattern, assigned_ids)?;
+                            entries.push((key, Some(value)));
+                        }
+                        _ => {
+                            // e.g. `{foo}`
+                            let key = self.push_node_with_span(Node::Id(id, None), key_span)?;
+                            entries.push((key, None));
+                            assigned_ids.push(id);
+                        }
+                    }
+                }
+                Some(Token::StringStart(_)) => {
+                    let Some(key_string) = self.parse_string(&ExpressionContext::restricted())?
+                    else {
+                        return self.error(InternalError::UnexpectedToken);
+                    };
+                    if matches!(key_string.string.contents, StringContents::Interpolated(_)) {
+                        return Err(Error::new(
+                            SyntaxError::ExpectedMapPatternKey.into(),
+                            key_string.span,
+                        ));
+                    }
+                    if self.peek_token() != Some(Token::Colon) {
+                        return self.consume_token_and_error(SyntaxError::ExpectedMapColon);
+                    }
+
+                    let key =
+                        self.push_node_with_span(Node::Str(key_string.string), key_string.span)?;
+                    let value = self.parse_map_pattern_value(is_match_pattern, assigned_ids)?;
+                    entries.push((key, Some(value)));
+                }
+                Some(Token::Ellipsis) => {
+                    // e.g. `{foo, ...}`
+                    self.consume_token();
+                    entries.push((self.push_node(Node::Ellipsis(None))?, None));
+                    break;
+                }
+                Some(Token::CurlyClose) => break,
+                _ => return self.consume_token_and_error(SyntaxError::ExpectedMapPatternKey),
+            }
+
+            if matches!(
+                self.peek_token_with_context(&entry_context),
+                Some(PeekInfo {
+                    token: Token::Comma,
+                    ..
+                })
+            ) {
+                self.consume_token_with_context(&entry_context);
+                entry_context = ExpressionContext::braced_items_continued();
+            } else {
+                break;
+            }
+        }
+
+        if !matches!(
+            self.consume_token_with_context(&ExpressionContext::permissive()),
+            Some((Token::CurlyClose, _))
+        ) {
+            return self.error(SyntaxError::ExpectedMapPatternEnd);
+        }
+
+        self.push_node_with_start_span(Node::MapPattern(entries), start_span)
+    }
+
+    // Parses the pattern that follows a key in a map pattern
+    //
+    // e.g.
+    //   let {foo: (x, y), bar: z: Number} = ...
+    //           ^ You are here
+    //   #                    ^ ...or here
+    fn parse_map_pattern_value(
+        &mut self,
+        is_match_pattern: bool,
+        assigned_ids: &mut AstVec<ConstantIndex>,
+    ) -> Result<AstIndex> {
+        self.consume_token(); // ':'
+
+        let value_context = ExpressionContext::restricted();
+
+        let value = if is_match_pattern {
+            self.parse_match_pattern(false)?
+        } else {
+            match self.parse_id_or_wildcard(&value_context)? {
+                Some(IdOrWildcard::Id(id)) => {
+                    if self.constants.get_str(id) == "self" {
+                        return self.error(SyntaxError::SelfArg);
+                    }
+                    let id_span = self.current_span();
+                    let type_hint = self.parse_type_hint(&value_context)?;
+                    assigned_ids.push(id);
+                    Some(self.push_node_with_span(Node::Id(id, type_hint), id_span)?)
+                }
+                Some(IdOrWildcard::Wildcard(maybe_id)) => {
+                    let wildcard_span = self.current_span();
+                    let type_hint = self.parse_type_hint(&value_context)?;
+                    Some(
+                        self.push_node_with_span(
+                            Node::Wildcard(maybe_id, type_hint),
+                            wildcard_span,
+                        )?,
+                    )
+                }
+                None => match self.peek_token_with_context(&value_context) {
+                    Some(PeekInfo {
+                        token: Token::RoundOpen,
+                        ..
+                    }) => {
+                        self.consume_token_with_context(&value_context);
+                        let span_start = self.current_span();
+
+                        let tuple_args = self.parse_nested_function_args(assigned_ids)?;
+                        if !matches!(
+                            self.consume_token_with_context(&ExpressionContext::permissive()),
+                            Some((Token::RoundClose, _))
+                        ) {
+                            return self.error(SyntaxError::ExpectedCloseParen);
+                        }
+                        Some(self.push_node_with_start_span(Node::Tuple(tuple_args), span_start)?)
+                    }
+                    Some(PeekInfo {
+                        token: Token::CurlyOpen,
+                        ..
+                    }) => {
+                        self.consume_token_with_context(&value_context);
+                        Some(self.parse_map_pattern(false, assigned_ids)?)
+                    }
+                    _ => None,
+                },
+            }
+        };
+
+        match value {
+            Some(value) => Ok(value),
+            None => self.consume_token_and_error(SyntaxError::ExpectedMapValue),
+        }
+    }
+
     // Attempts to parse whitespace-separated call args
     //
     // The context is used to determine what kind of argument separation is allowed.
@@ -1141,6 +1542,18 @@ impl<'source> Parser<'source> {
                     break;
                 }
 
+                // A `|` followed by whitespace is a bitwise or rather than the start of a function
+                // e.g. `f | x` vs. `f |x| x`
+                if args.is_empty()
+                    && peeked.token == Token::Function
+                    && matches!(
+                        self.peek_token_n(peeked.peek_count + 1),
+                        Some(token) if token.is_whitespace_including_newline()
+                    )
+                {
+                    break;
+                }
+
                 if let Some(expression) = self
                     .parse_expression_with_min_precedence(MIN_PRECEDENCE_AFTER_PIPE, &arg_context)?
                 {
@@ -1320,15 +1733,19 @@ impl<'source> Parser<'source> {
     fn next_token_is_chain_start(&mut self, context: &ExpressionContext) -> bool {
         use Token::*;
 
-        if matches!(self.peek_token(), Some(Dot | SquareOpen | RoundOpen)) {
-            true
-        } else if context.allow_linebreaks {
-            matches!(
-                self.peek_token_with_context(context),
-                Some(peeked) if peeked.token == Dot
-            )
-        } else {
-            false
+        match self.peek_token() {
+            Some(Dot | SquareOpen | RoundOpen) => true,
+            Some(QuestionMark) => {
+                matches!(self.peek_token_n(1), Some(Dot | SquareOpen | RoundOpen))
+            }
+            _ if context.allow_linebreaks => match self.peek_token_with_context(context) {
+                Some(peeked) if peeked.token == Dot => true,
+                Some(peeked) if peeked.token == QuestionMark => {
+                    self.peek_token_n(peeked.peek_count + 1) == Some(Dot)
+                }
+                _ => false,
+            },
+            _ => false,
         }
     }
 
@@ -1384,6 +1801,22 @@ impl<'source> Parser<'source> {
                         return self.error(SyntaxError::ExpectedIndexEnd);
                     }
                 }
+                // Null check
+                Token::QuestionMark => {
+                    self.consume_token();
+
+                    // The `?` must be directly followed by the next node in the chain,
+                    // e.g. `x?.foo`, `x?[0]`, `x?()`
+                    if !matches!(
+                        self.peek_token(),
+                        Some(Token::Dot | Token::SquareOpen | Token::RoundOpen)
+                    ) {
+                        return self
+                            .consume_token_and_error(SyntaxError::ExpectedNullCheckedChainNode);
+                    }
+
+                    chain.push((ChainNode::NullCheck, node_start_span));
+                }
                 // Map access
                 Token::Dot => {
                     self.consume_token();
@@ -1408,7 +1841,9 @@ impl<'source> Parser<'source> {
                     let Some(peeked) = self.peek_token_with_context(&node_context) else {
                         break;
                     };
-                    if peeked.token == Token::Dot {
+                    let null_checked_dot = peeked.token == Token::QuestionMark
+                        && self.peek_token_n(peeked.peek_count + 1) == Some(Token::Dot);
+                    if peeked.token == Token::Dot || null_checked_dot {
                         // Indented Dot on a following line?
 
                         // Consume up until the Dot,
@@ -2010,7 +2445,13 @@ impl<'source> Parser<'source> {
             Some(Token::Subtract) => MetaKeyId::Subtract,
             Some(Token::Multiply) => MetaKeyId::Multiply,
             Some(Token::Divide) => MetaKeyId::Divide,
+            Some(Token::IntegerDivide) => MetaKeyId::IntegerDivide,
             Some(Token::Remainder) => MetaKeyId::Remainder,
+            Some(Token::Power) => MetaKeyId::Power,
+            Some(Token::BitwiseAnd) => MetaKeyId::BitwiseAnd,
+            Some(Token::BitwiseXor) => MetaKeyId::BitwiseXor,
+            Some(Token::ShiftLeft) => MetaKeyId::ShiftLeft,
+            Some(Token::ShiftRight) => MetaKeyId::ShiftRight,
             Some(Token::AddAssign) => MetaKeyId::AddAssign,
             Some(Token::SubtractAssign) => MetaKeyId::SubtractAssign,
             Some(Token::MultiplyAssign) => MetaKeyId::MultiplyAssign,
@@ -2024,6 +2465,7 @@ impl<'source> Parser<'source> {
             Some(Token::NotEqual) => MetaKeyId::NotEqual,
             Some(Token::Id) => match self.current_token.slice(self.source) {
                 "display" => MetaKeyId::Display,
+                "format" => MetaKeyId::Format,
                 "iterator" => MetaKeyId::Iterator,
                 "next" => MetaKeyId::Next,
                 "next_back" => MetaKeyId::NextBack,
@@ -2057,10 +2499,15 @@ impl<'source> Parser<'source> {
                 Some(Token::SquareClose) => MetaKeyId::Index,
                 _ => return self.error(SyntaxError::UnexpectedMetaKey),
             },
-            Some(Token::Function) => match self.consume_token() {
-                Some(Token::Function) => MetaKeyId::Call,
-                _ => return self.error(SyntaxError::UnexpectedMetaKey),
-            },
+            Some(Token::Function) => {
+                // `@||` is the call operator, a single `|` is bitwise or
+                if self.peek_token() == Some(Token::Function) {
+                    self.consume_token();
+                    MetaKeyId::Call
+                } else {
+                    MetaKeyId::BitwiseOr
+                }
+            }
             _ => return self.error(SyntaxError::UnexpectedMetaKey),
         };
 
@@ -2497,12 +2944,29 @@ impl<'source> Parser<'source> {
 
         let result = match self.peek_token_with_context(&pattern_context) {
             Some(peeked) => match peeked.token {
-                True | False | Null | Number | StringStart { .. } | Subtract => {
+                True | False | Null | StringStart { .. } => {
                     return self.parse_term(&pattern_context)
                 }
+                Number | Subtract => {
+                    let number = self.parse_term(&pattern_context)?;
+                    if matches!(
+                        self.peek_next_token_on_same_line(),
+                        Some(Range | RangeInclusive)
+                    ) {
+                        Some(self.consume_range_pattern(number)?)
+                    } else {
+                        number
+                    }
+                }
+                Range | RangeInclusive => {
+                    self.consume_token_with_context(&pattern_context);
+                    Some(self.consume_range_pattern(None)?)
+                }
                 Id => match self.parse_id(&pattern_context)? {
                     Some((id, _)) => {
-                        let result = if self.peek_token() == Some(Ellipsis) {
+                        let result = if self.next_is_record_pattern() {
+                            self.consume_record_pattern(id)?
+                        } else if self.peek_token() == Some(Ellipsis) {
                             self.consume_token();
                             if in_nested_patterns {
                                 self.frame_mut()?.ids_assigned_in_frame.insert(id);
@@ -2552,6 +3016,17 @@ impl<'source> Parser<'source> {
                         Some(self.push_node(Node::Tuple(tuple_patterns))?)
                     }
                 }
+                CurlyOpen => {
+                    self.consume_token_with_context(&pattern_context);
+
+                    let mut assigned_ids = AstVec::new();
+                    let map_pattern = self.parse_map_pattern(true, &mut assigned_ids)?;
+                    self.frame_mut()?
+                        .ids_assigned_in_frame
+                        .extend(assigned_ids.iter());
+
+                    Some(map_pattern)
+                }
                 Ellipsis if in_nested_patterns => {
                     self.consume_token_with_context(&pattern_context);
                     Some(self.push_node(Node::Ellipsis(None))?)
@@ -2564,6 +3039,127 @@ impl<'source> Parser<'source> {
         Ok(result)
     }
 
+    // Returns true if the id that was just consumed is the start of a record pattern
+    //
+    // Record patterns start with the name of a record or enum variant, with optional `.` accesses,
+    // followed directly by `(`. The record's name needs to start with an uppercase letter,
+    // which distinguishes record patterns from most function calls. Calls that match the same
+    // form, e.g. `Square(3)`, are evaluated as calls by the compiler when the name doesn't refer
+    // to a record constructor.
+    //
+    // e.g.
+    //   match x
+    //     Point(x, y) then ...
+    //     Shape.Circle(r) then ...
+    fn next_is_record_pattern(&mut self) -> bool {
+        let mut name = self.current_token.slice(self.source);
+        let mut peek_count = 0;
+
+        loop {
+            match self.peek_token_n(peek_count) {
+                Some(Token::Dot) => match self.lexer.peek(peek_count + 1) {
+                    Some(peeked) if peeked.token == Token::Id => {
+                        name = peeked.slice(self.source);
+                        peek_count += 2;
+                    }
+                    _ => return false,
+                },
+                Some(Token::RoundOpen) => return name.starts_with(char::is_uppercase),
+                _ => return false,
+            }
+        }
+    }
+
+    // Parses a record pattern in a match arm
+    //
+    // e.g.
+    //   match x
+    //     Shape.Circle(r) then ...
+    //   #      ^ You are here
+    fn consume_record_pattern(&mut self, root_id: ConstantIndex) -> Result<AstIndex> {
+        let start_span = self.current_span();
+
+        self.frame_mut()?.add_id_access(root_id);
+        let root = self.push_node(Node::Id(root_id, None))?;
+
+        let mut chain = vec![(ChainNode::Root(root), start_span)];
+        while self.peek_token() == Some(Token::Dot) {
+            self.consume_token();
+            match self.parse_id(&ExpressionContext::restricted())? {
+                Some((id, _)) => chain.push((ChainNode::Id(id), self.current_span())),
+                None => return self.error(InternalError::IdParseFailure),
+            }
+        }
+
+        let constructor = if chain.len() == 1 {
+            root
+        } else {
+            let mut next_index = None;
+            for (node, span) in chain.into_iter().rev() {
+                next_index = Some(self.push_node_with_span(Node::Chain((node, next_index)), span)?);
+            }
+            next_index.ok_or_else(|| self.make_error(InternalError::ChainParseFailure))?
+        };
+
+        self.consume_token(); // (
+        let fields = self.parse_nested_match_patterns()?;
+        if self.consume_next_token_on_same_line() != Some(Token::RoundClose) {
+            return self.error(SyntaxError::ExpectedCloseParen);
+        }
+
+        self.push_node_with_start_span(
+            Node::RecordPattern {
+                constructor,
+                fields,
+            },
+            start_span,
+        )
+    }
+
+    // Parses a range in a match pattern, with optional number literals as bounds
+    //
+    // e.g.
+    //   match x
+    //     0..10 then ...
+    //   #  ^ You are here
+    //     ..=-1 then ...
+    //   #   ^...or here
+    fn consume_range_pattern(&mut self, start: Option<AstIndex>) -> Result<AstIndex> {
+        use Node::{Range, RangeFrom, RangeTo};
+
+        let start_span = self.current_span();
+
+        if start.is_some() {
+            self.consume_next_token_on_same_line(); // Token::Range or Token::RangeInclusive
+        }
+
+        let inclusive = match self.current_token.token {
+            Token::Range => false,
+            Token::RangeInclusive => true,
+            _ => return self.error(InternalError::UnexpectedToken),
+        };
+
+        let end = match self.peek_token() {
+            Some(Token::Number | Token::Subtract) => {
+                self.parse_term(&ExpressionContext::restricted())?
+            }
+            _ => None,
+        };
+
+        let range_node = match (start, end) {
+            (Some(start), Some(end)) => Range {
+                start,
+                end,
+                inclusive,
+            },
+            (Some(start), None) if !inclusive => RangeFrom { start },
+            (None, Some(end)) => RangeTo { end, inclusive },
+            _ => return self.error(SyntaxError::ExpectedRangePatternBound),
+        };
+
+        self.push_node_with_start_span(range_node, start_span)
+    }
+
     // Recursively parses nested match patterns
     //
     // e.g.
@@ -2796,21 +3392,39 @@ impl<'source> Parser<'source> {
 
         let mut targets = vec![];
 
-        while let Some(id_or_wildcard) =
-            self.parse_id_or_wildcard(&ExpressionContext::permissive())?
-        {
-            let target_span = self.current_span();
-            let target_node = match id_or_wildcard {
-                IdOrWildcard::Id(constant_index) => {
-                    let type_hint_index = self.parse_type_hint(context)?;
-                    Node::Id(constant_index, type_hint_index)
-                }
-                IdOrWildcard::Wildcard(maybe_id) => {
-                    let type_hint_index = self.parse_type_hint(context)?;
-                    Node::Wildcard(maybe_id, type_hint_index)
+        loop {
+            let target_context = ExpressionContext::permissive();
+
+            if let Some(PeekInfo {
+                token: Token::CurlyOpen,
+                ..
+            }) = self.peek_token_with_context(&target_context)
+            {
+                // e.g. `let {foo, bar} = x`
+                self.consume_token_with_context(&target_context);
+
+                let mut assigned_ids = AstVec::new();
+                targets.push(self.parse_map_pattern(false, &mut assigned_ids)?);
+
+                for id in assigned_ids {
+                    self.frame_mut()?.add_local_id_assignment(id);
                 }
-            };
-            targets.push(self.push_node_with_span(target_node, target_span)?);
+            } else if let Some(id_or_wildcard) = self.parse_id_or_wildcard(&target_context)? {
+                let target_span = self.current_span();
+                let target_node = match id_or_wildcard {
+                    IdOrWildcard::Id(constant_index) => {
+                        let type_hint_index = self.parse_type_hint(context)?;
+                        Node::Id(constant_index, type_hint_index)
+                    }
+                    IdOrWildcard::Wildcard(maybe_id) => {
+                        let type_hint_index = self.parse_type_hint(context)?;
+                        Node::Wildcard(maybe_id, type_hint_index)
+                    }
+                };
+                targets.push(self.push_node_with_span(target_node, target_span)?);
+            } else {
+                break;
+            }
 
             if let Some(Token::Comma) = self
                 .peek_token_with_context(context)
@@ -2830,15 +3444,290 @@ impl<'source> Parser<'source> {
         }
     }
 
+    fn consume_const_expression(&mut self, context: &ExpressionContext) -> Result<AstIndex> {
+        self.consume_token_with_context(context); // Token::Const
+
+        let Some((id, _)) = self.parse_id(&ExpressionContext::permissive())? else {
+            return self.consume_token_and_error(SyntaxError::ExpectedConstId);
+        };
+        let target_span = self.current_span();
+        let type_hint = self.parse_type_hint(context)?;
+        let target = self.push_node_with_span(Node::Id(id, type_hint), target_span)?;
+
+        if !matches!(
+            self.peek_token_with_context(context),
+            Some(PeekInfo {
+                token: Token::Assign,
+                ..
+            })
+        ) {
+            return self.consume_token_and_error(SyntaxError::ExpectedConstAssignment);
+        }
+
+        self.frame_mut()?.add_local_id_assignment(id);
+
+        self.consume_token_with_context(context); // =
+        let assign_span = self.current_span();
+
+        match self.parse_expressions(context, TempResult::No)? {
+            Some(expression) => {
+                self.push_node_with_span(Node::Const { target, expression }, assign_span)
+            }
+            None => {
+                self.consume_token_on_same_line_and_error(ExpectedIndentation::AssignmentExpression)
+            }
+        }
+    }
+
+    // Checks if the peeked id is the start of a record, enum, or interface declaration
+    //
+    // `record`, `enum`, and `interface` aren't reserved keywords, so they're only treated as
+    // declarations when they're followed by a name and then the declaration's body,
+    // e.g. `record Point(x, y)`, or `enum Shape` followed by an indented block of variants.
+    fn peeked_type_declaration(&mut self, peeked: &PeekInfo) -> Option<TypeDeclaration> {
+        let declaration = match peeked.info.slice(self.source) {
+            "record" => TypeDeclaration::Record,
+            "enum" => TypeDeclaration::Enum,
+            "interface" => TypeDeclaration::Interface,
+            _ => return None,
+        };
+
+        let name_index = peeked.peek_count + 2;
+        if self.peek_token_n(peeked.peek_count + 1) != Some(Token::Whitespace)
+            || self.peek_token_n(name_index) != Some(Token::Id)
+        {
+            return None;
+        }
+
+        match declaration {
+            TypeDeclaration::Record => {
+                (self.peek_token_n(name_index + 1) == Some(Token::RoundOpen)).then_some(declaration)
+            }
+            TypeDeclaration::Enum | TypeDeclaration::Interface => self
+                .peeked_indented_block(name_index + 1, peeked.info.indent)
+                .then_some(declaration),
+        }
+    }
+
+    // Checks if the line ends at the given peek position, and is followed by an indented block
+    fn peeked_indented_block(&mut self, mut peek_count: usize, indent: usize) -> bool {
+        // The rest of the line should be empty
+        loop {
+            match self.peek_token_n(peek_count) {
+                Some(token) if token.is_whitespace() => peek_count += 1,
+                Some(Token::NewLine) => break,
+                _ => return false,
+            }
+        }
+
+        // The first token of the following non-empty line should be indented
+        while let Some(peeked) = self.lexer.peek(peek_count) {
+            if peeked.token.is_whitespace_including_newline() {
+                peek_count += 1;
+            } else {
+                return peeked.indent > indent;
+            }
+        }
+
+        false
+    }
+
+    // Parses a record declaration, assigning the record's constructor to the record's name
+    //
+    // e.g.
+    //   record Point(x: Number, y: Number)
+    //   # ^ You are here
+    fn consume_record_declaration(&mut self, context: &ExpressionContext) -> Result<AstIndex> {
+        self.consume_token_with_context(context); // record
+        let start_span = self.current_span();
+
+        let Some((name, _)) = self.parse_id(&ExpressionContext::restricted())? else {
+            return self.error(InternalError::IdParseFailure);
+        };
+        let target = self.push_node(Node::Id(name, None))?;
+        self.frame_mut()?.add_local_id_assignment(name);
+
+        let expression = self.consume_record_fields(name)?;
+
+        self.push_node_with_start_span(Node::Assign { target, expression }, start_span)
+    }
+
+    // Parses an enum declaration, assigning a map containing the enum's variants to the enum's name
+    //
+    // e.g.
+    //   enum Shape
+    //   # ^ You are here
+    //     Circle(radius: Number)
+    //     Empty
+    fn consume_enum_declaration(&mut self, context: &ExpressionContext) -> Result<AstIndex> {
+        self.consume_token_with_context(context); // enum
+        let start_span = self.current_span();
+        let start_indent = self.current_indent();
+
+        let Some((name, _)) = self.parse_id(&ExpressionContext::restricted())? else {
+            return self.error(InternalError::IdParseFailure);
+        };
+        let target = self.push_node(Node::Id(name, None))?;
+        self.frame_mut()?.add_local_id_assignment(name);
+
+        let variants_context = ExpressionContext::permissive();
+        match self.peek_token_with_context(&variants_context) {
+            Some(peeked) if peeked.info.indent > start_indent => {}
+            _ => {
+                return self.consume_token_on_same_line_and_error(ExpectedIndentation::EnumVariants)
+            }
+        }
+        let variants_context = self
+            .consume_until_token_with_context(&variants_context)
+            .unwrap(); // Safe to unwrap here given that we've just peeked
+        let enum_span = self.current_span();
+
+        let mut variants = AstVec::new();
+        loop {
+            let Some((variant, _)) = self.parse_id(&variants_context)? else {
+                return self.consume_token_and_error(SyntaxError::ExpectedEnumVariant);
+            };
+            variants.push(self.consume_record_fields(variant)?);
+
+            match self.peek_next_token_on_same_line() {
+                None => break,
+                Some(Token::NewLine) => {}
+                _ => return self.consume_token_and_error(SyntaxError::UnexpectedToken),
+            }
+
+            // Peek ahead to see if there are more variants on the following lines
+            if self.peek_token_with_context(&variants_context).is_none() {
+                break;
+            }
+
+            self.consume_until_token_with_context(&variants_context);
+        }
+
+        let expression = self.push_node_with_span(Node::Enum { name, variants }, enum_span)?;
+
+        self.push_node_with_start_span(Node::Assign { target, expression }, start_span)
+    }
+
+    // Parses an interface declaration, assigning the interface to its name
+    //
+    // e.g.
+    //   interface Container
+    //   # ^ You are here
+    //     size
+    //     @[]
+    fn consume_interface_declaration(&mut self, context: &ExpressionContext) -> Result<AstIndex> {
+        self.consume_token_with_context(context); // interface
+        let start_span = self.current_span();
+        let start_indent = self.current_indent();
+
+        let Some((name, _)) = self.parse_id(&ExpressionContext::restricted())? else {
+            return self.error(InternalError::IdParseFailure);
+        };
+        let target = self.push_node(Node::Id(name, None))?;
+        self.frame_mut()?.add_local_id_assignment(name);
+
+        let entries_context = ExpressionContext::permissive();
+        match self.peek_token_with_context(&entries_context) {
+            Some(peeked) if peeked.info.indent > start_indent => {}
+            _ => {
+                return self
+                    .consume_token_on_same_line_and_error(ExpectedIndentation::InterfaceEntries)
+            }
+        }
+        let entries_context = self
+            .consume_until_token_with_context(&entries_context)
+            .unwrap(); // Safe to unwrap here given that we've just peeked
+        let interface_span = self.current_span();
+
+        let mut entries = AstVec::new();
+        loop {
+            let Some(entry) = self.parse_map_key()? else {
+                return self.consume_token_and_error(SyntaxError::ExpectedInterfaceEntry);
+            };
+            entries.push(entry);
+
+            match self.peek_next_token_on_same_line() {
+                None => break,
+                Some(Token::NewLine) => {}
+                _ => return self.consume_token_and_error(SyntaxError::UnexpectedToken),
+            }
+
+            // Peek ahead to see if there are more entries on the following lines
+            if self.peek_token_with_context(&entries_context).is_none() {
+                break;
+            }
+
+            self.consume_until_token_with_context(&entries_context);
+        }
+
+        let expression =
+            self.push_node_with_span(Node::Interface { name, entries }, interface_span)?;
+
+        self.push_node_with_start_span(Node::Assign { target, expression }, start_span)
+    }
+
+    // Parses the optional fields that follow a record's name
+    //
+    // e.g.
+    //   record Point(x: Number, y: Number)
+    //   #           ^ You are here
+    fn consume_record_fields(&mut self, name: ConstantIndex) -> Result<AstIndex> {
+        let start_span = self.current_span();
+        let mut fields = AstVec::new();
+
+        if self.peek_token() == Some(Token::RoundOpen) {
+            self.consume_token();
+
+            let start_indent = self.current_indent();
+            let mut fields_context = ExpressionContext::permissive();
+            // Set to false when a field is missing its following comma
+            let mut expecting_field = true;
+
+            while self.peek_token_with_context(&fields_context).is_some() {
+                fields_context = self
+                    .consume_until_token_with_context(&fields_context)
+                    .unwrap();
+
+                let Some((field, _)) = self.parse_id(&ExpressionContext::inline())? else {
+                    break;
+                };
+                let field_span = self.current_span();
+                let type_hint = self.parse_type_hint(&ExpressionContext::inline())?;
+                fields.push(self.push_node_with_span(Node::Id(field, type_hint), field_span)?);
+
+                if self.peek_next_token_on_same_line() == Some(Token::Comma) {
+                    self.consume_next_token_on_same_line();
+                } else {
+                    expecting_field = false;
+                    break;
+                }
+            }
+
+            let mut fields_end_context = ExpressionContext::permissive();
+            fields_end_context.expected_indentation = Indentation::Equal(start_indent);
+            match self.consume_token_with_context(&fields_end_context) {
+                Some((Token::RoundClose, _)) => {}
+                _ if expecting_field => return self.error(SyntaxError::ExpectedRecordField),
+                _ => return self.error(SyntaxError::ExpectedCloseParen),
+            }
+        }
+
+        self.push_node_with_start_span(Node::Record { name, fields }, start_span)
+    }
+
     fn parse_string(&mut self, context: &ExpressionContext) -> Result<Option<ParseStringOutput>> {
         use SyntaxError::*;
         use Token::*;
 
-        let quote = match self.peek_token_with_context(context) {
+        let (quote, multi_line) = match self.peek_token_with_context(context) {
             Some(PeekInfo {
                 token: StringStart(StringType::Normal(quote)),
                 ..
-            }) => quote,
+            }) => (quote, false),
+            Some(PeekInfo {
+                token: StringStart(StringType::MultiLine(quote)),
+                ..
+            }) => (quote, true),
             Some(PeekInfo {
                 token: StringStart(StringType::Raw { .. }),
                 ..
@@ -2849,11 +3738,19 @@ impl<'source> Parser<'source> {
         let (_, string_context) = self.consume_token_with_context(context).unwrap();
         let start_span = self.current_span();
         let mut nodes = Vec::new();
+        let mut dedented_literals = if multi_line {
+            self.peek_dedented_string_literals().into_iter()
+        } else {
+            Vec::new().into_iter()
+        };
 
         while let Some(next_token) = self.consume_token() {
             match next_token {
                 StringLiteral => {
-                    let string_literal = self.current_token.slice(self.source);
+                    let dedented = dedented_literals.next();
+                    let string_literal = dedented
+                        .as_deref()
+                        .unwrap_or_else(|| self.current_token.slice(self.source));
 
                     let mut contents = String::with_capacity(string_literal.len());
                     let mut chars = string_literal.chars().peekable();
@@ -2910,6 +3807,41 @@ impl<'source> Parser<'source> {
         self.error(UnterminatedString)
     }
 
+    // Peeks through the remaining tokens of a multi-line string, and returns its literals with
+    // their common indentation removed
+    //
+    // The first line of the string is skipped if it's empty, along with the last line if it only
+    // contains whitespace.
+    fn peek_dedented_string_literals(&mut self) -> Vec<String> {
+        let mut literals = Vec::new();
+        let mut starts_with_literal = false;
+        let mut ends_with_literal = false;
+        let mut previous_token = None;
+        let mut nested_strings = 0;
+
+        for n in 0.. {
+            let Some(peeked) = self.lexer.peek(n) else {
+                break;
+            };
+            match peeked.token {
+                Token::StringStart(_) => nested_strings += 1,
+                Token::StringEnd if nested_strings == 0 => {
+                    ends_with_literal = previous_token == Some(Token::StringLiteral);
+                    break;
+                }
+                Token::StringEnd => nested_strings -= 1,
+                Token::StringLiteral if nested_strings == 0 => {
+                    starts_with_literal |= n == 0;
+                    literals.push(peeked.slice(self.source).to_string());
+                }
+                _ => {}
+            }
+            previous_token = Some(peeked.token);
+        }
+
+        dedent_string_literals(literals, starts_with_literal, ends_with_literal)
+    }
+
     fn consume_format_options(&mut self) -> Result<StringFormatOptions> {
         use SyntaxError::*;
 
@@ -3362,13 +4294,21 @@ fn operator_precedence(op: Token) -> Option<(u8, u8)> {
         AddAssign | SubtractAssign | MultiplyAssign | DivideAssign | RemainderAssign => {
             (4, MIN_PRECEDENCE_AFTER_PIPE)
         }
+        NullCoalesce => (5, 6),
         Or => (7, 8),
         And => (9, 10),
         // Chained comparisons require right-associativity
         Equal | NotEqual => (12, 11),
         Greater | GreaterOrEqual | Less | LessOrEqual => (14, 13),
-        Add | Subtract => (15, 16),
-        Multiply | Divide | Remainder => (17, 18),
+        // `|` is lexed as Token::Function, in operator position it's a bitwise or
+        Function => (15, 16),
+        BitwiseXor => (17, 18),
+        BitwiseAnd => (19, 20),
+        ShiftLeft | ShiftRight => (21, 22),
+        Add | Subtract => (23, 24),
+        Multiply | Divide | IntegerDivide | Remainder => (25, 26),
+        // Exponentiation is right-associative
+        Power => (28, 27),
         _ => return None,
     };
     Some(priority)
@@ -3382,6 +4322,14 @@ struct PeekInfo {
     info: LexedToken,
 }
 
+// Returned by Parser::peeked_type_declaration()
+#[derive(Clone, Copy)]
+enum TypeDeclaration {
+    Record,
+    Enum,
+    Interface,
+}
+
 // Returned by Parser::parse_id_or_wildcard()
 #[derive(Debug)]
 enum IdOrWildcard {
@@ -3395,3 +4343,106 @@ struct ParseStringOutput {
     span: Span,
     context: ExpressionContext,
 }
+
+// Removes the common leading indentation from the literals of a multi-line string
+//
+// Lines that only contain whitespace don't contribute to the common indentation.
+// Expressions are treated as non-whitespace content.
+fn dedent_string_literals(
+    mut literals: Vec<String>,
+    starts_with_literal: bool,
+    ends_with_literal: bool,
+) -> Vec<String> {
+    // Skip the first line if it's empty
+    if starts_with_literal {
+        if let Some(first) = literals.first_mut() {
+            if let Some(newline) = first.find('\n') {
+                if first[..newline].trim().is_empty() {
+                    first.drain(..=newline);
+                }
+            }
+        }
+    }
+
+    // Skip the last line if it only contains whitespace
+    let literal_count = literals.len();
+    if ends_with_literal {
+        if let Some(last) = literals.last_mut() {
+            let line_start = line_starts(last, literal_count == 1 && starts_with_literal).pop();
+            if let Some(line_start) = line_start.filter(|start| last[*start..].trim().is_empty()) {
+                last.truncate(line_start.saturating_sub(1));
+                if last.ends_with('\r') {
+                    last.pop();
+                }
+            }
+        }
+    }
+
+    let line_info = |literal: &str, index: usize, line_start: usize| {
+        let indent = literal[line_start..]
+            .chars()
+            .take_while(|c| matches!(c, ' ' | '\t'))
+            .count();
+        let rest = &literal[line_start + indent..];
+        let is_last = index == literal_count - 1 && ends_with_literal;
+        let is_blank =
+            rest.starts_with('\n') || rest.starts_with("\r\n") || (rest.is_empty() && is_last);
+        (indent, is_blank)
+    };
+
+    let common_indent = literals
+        .iter()
+        .enumerate()
+        .flat_map(|(i, literal)| {
+            line_starts(literal, i == 0 && starts_with_literal)
+                .into_iter()
+                .map(move |line_start| line_info(literal, i, line_start))
+        })
+        .filter_map(|(indent, is_blank)| (!is_blank).then_some(indent))
+        // A string that starts with an expression has no indentation on its first line
+        .chain((!starts_with_literal).then_some(0))
+        .min()
+        .unwrap_or(0);
+
+    if common_indent == 0 {
+        return literals;
+    }
+
+    literals
+        .iter()
+        .enumerate()
+        .map(|(i, literal)| {
+            let mut result = String::with_capacity(literal.len());
+            let mut position = 0;
+            for line_start in line_starts(literal, i == 0 && starts_with_literal) {
+                let (indent, _) = line_info(literal, i, line_start);
+                result.push_str(&literal[position..line_start]);
+                // Indentation is made up of single-byte characters
+                position = line_start + indent.min(common_indent);
+            }
+            result.push_str(&literal[position..]);
+            result
+        })
+        .collect()
+}
+
+// Returns the byte positions of the starts of lines in a string literal
+//
+// Escaped newlines are skipped. The start of the literal is included if `includes_start` is true.
+fn line_starts(literal: &str, includes_start: bool) -> Vec<usize> {
+    let mut result = Vec::new();
+    if includes_start {
+        result.push(0);
+    }
+    let mut chars = literal.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '\n' => result.push(i + 1),
+            _ => {}
+        }
+    }
+    result
+}
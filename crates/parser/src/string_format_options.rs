@@ -16,14 +16,54 @@ pub struct StringFormatOptions {
     pub precision: Option<u32>,
     /// The character that padded strings should use to fill empty space
     pub fill_character: Option<ConstantIndex>,
+    /// The representation that should be used when formatting numbers
+    pub representation: StringFormatRepresentation,
+    /// True if a `+` sign should be shown for non-negative numbers
+    pub show_sign: bool,
+    /// True if the alternate form of the representation should be used
+    ///
+    /// e.g. `0x` is used as a prefix for hexadecimal numbers.
+    pub alternate: bool,
+    /// True if thousands separators should be used when formatting numbers
+    pub thousands_separator: bool,
+    /// A custom format string, as it appears in the source
+    ///
+    /// The format string is only kept when it doesn't contain standard formatting options.
+    /// Custom format strings are only supported by values that implement `@format`.
+    pub format_string: Option<ConstantIndex>,
 }
 
 impl StringFormatOptions {
     /// Parses a format string
+    ///
+    /// Format strings that don't start with standard formatting options are accepted as custom
+    /// format strings, see [StringFormatOptions::format_string]. Errors are returned for format
+    /// strings that start with standard options but are then malformed, e.g. `>5.2q`.
     pub(crate) fn parse(
         format_string: &str,
         constants: &mut ConstantPoolBuilder,
     ) -> Result<Self, StringFormatError> {
+        match Self::parse_standard_options(format_string, constants)? {
+            Some(result) => Ok(result),
+            None => {
+                let format_string_constant = constants
+                    .add_string(format_string)
+                    .map_err(|_| StringFormatError::InternalError)?;
+                Ok(Self {
+                    format_string: Some(format_string_constant),
+                    ..Default::default()
+                })
+            }
+        }
+    }
+
+    // Parses standard formatting options
+    //
+    // None is returned if the format string doesn't start with standard options.
+    fn parse_standard_options(
+        format_string: &str,
+        constants: &mut ConstantPoolBuilder,
+    ) -> Result<Option<Self>, StringFormatError> {
         use FormatParsePosition::*;
         let mut position = Start;
         let mut result = Self::default();
@@ -49,30 +89,58 @@ impl StringFormatOptions {
                     result.fill_character =
                         Some(add_string_constant(&format_string[0..next.len_utf8()])?);
                     result.alignment = char_to_alignment(chars.next().unwrap());
-                    position = MinWidth;
+                    position = Sign;
                 }
                 ('<' | '^' | '>', _, Start | Alignment) => {
                     result.alignment = char_to_alignment(next);
+                    position = Sign;
+                }
+                ('+', _, Start | Sign) => {
+                    result.show_sign = true;
+                    position = Alternate;
+                }
+                ('#', _, Start | Sign | Alternate) => {
+                    result.alternate = true;
                     position = MinWidth;
                 }
-                ('0', Some('0'..='9'), Start | MinWidth) => {
+                ('0', Some('0'..='9'), Start | Sign | Alternate | MinWidth) => {
                     result.fill_character = Some(add_string_constant("0")?);
                     position = MinWidth;
                 }
-                ('0'..='9', _, Start | MinWidth) => {
+                ('0'..='9', _, Start | Sign | Alternate | MinWidth) => {
                     result.min_width = Some(consume_u32(next, &mut chars)?);
+                    position = Separator;
+                }
+                (',', _, Start | Sign | Alternate | MinWidth | Separator) => {
+                    result.thousands_separator = true;
                     position = Precision;
                 }
-                ('.', Some(_), Start | MinWidth | Precision) => {
+                ('.', Some(_), Start | Sign | Alternate | MinWidth | Separator | Precision) => {
                     let first_digit = chars.next().unwrap();
                     result.precision = Some(consume_u32(first_digit, &mut chars)?);
-                    position = End;
+                    position = Representation;
+                }
+                ('b' | 'o' | 'x' | 'X' | 'e' | 'E', None, position) if position != Alignment => {
+                    result.representation = match next {
+                        'b' => StringFormatRepresentation::Binary,
+                        'o' => StringFormatRepresentation::Octal,
+                        'x' => StringFormatRepresentation::HexLower,
+                        'X' => StringFormatRepresentation::HexUpper,
+                        'e' => StringFormatRepresentation::ExpLower,
+                        'E' => StringFormatRepresentation::ExpUpper,
+                        _ => unreachable!(),
+                    };
                 }
                 (_, _, Start) => {
                     // Unwrapping here is fine, format_string is valid UTF-8
                     let fill = format_string.graphemes(true).next().unwrap();
                     // The fill grapheme cluster can only appear at the start of the format string
                     chars = format_string[fill.len()..].chars().peekable();
+                    // A fill character that isn't followed by an alignment character means that
+                    // the format string is a custom format string.
+                    if chars.peek().is_some_and(|c| !matches!(c, '<' | '^' | '>')) {
+                        return Ok(None);
+                    }
                     result.fill_character = Some(add_string_constant(fill)?);
                     position = Alignment;
                 }
@@ -82,18 +150,21 @@ impl StringFormatOptions {
             }
         }
 
-        Ok(result)
+        Ok(Some(result))
     }
 }
 
 // Used during parsing of a format string, see [StringFormatOptions::parse]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum FormatParsePosition {
     Start,
     Alignment,
+    Sign,
+    Alternate,
     MinWidth,
+    Separator,
     Precision,
-    End,
+    Representation,
 }
 
 fn consume_u32(first: char, chars: &mut Peekable<Chars>) -> Result<u32, StringFormatError> {
@@ -131,6 +202,27 @@ pub enum StringAlignment {
     Right,
 }
 
+/// The representation that should be used when formatting numbers
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum StringFormatRepresentation {
+    /// Numbers are formatted as decimals
+    #[default]
+    Default,
+    /// `b` - Integers are formatted in binary
+    Binary,
+    /// `o` - Integers are formatted in octal
+    Octal,
+    /// `x` - Integers are formatted in hexadecimal, with lowercase letters
+    HexLower,
+    /// `X` - Integers are formatted in hexadecimal, with uppercase letters
+    HexUpper,
+    /// `e` - Numbers are formatted in scientific notation, with a lowercase `e`
+    ExpLower,
+    /// `E` - Numbers are formatted in scientific notation, with an uppercase `E`
+    ExpUpper,
+}
+
 /// An error that represents a problem with the Parser's internal logic, rather than a user error
 #[derive(Error, Clone, Debug)]
 #[allow(missing_docs)]
@@ -222,6 +314,7 @@ mod tests {
                     fill_character: Some(0.into()),
                     min_width: Some(20),
                     precision: Some(10),
+                    ..Default::default()
                 },
             ),
             (
@@ -252,4 +345,114 @@ mod tests {
             ),
         ])
     }
+
+    #[test]
+    fn sign_and_alternate_form() {
+        test_parse_format_string(&[
+            (
+                "+",
+                StringFormatOptions {
+                    show_sign: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                "#x",
+                StringFormatOptions {
+                    alternate: true,
+                    representation: StringFormatRepresentation::HexLower,
+                    ..Default::default()
+                },
+            ),
+            (
+                "+#010b",
+                StringFormatOptions {
+                    show_sign: true,
+                    alternate: true,
+                    fill_character: Some(0.into()),
+                    min_width: Some(10),
+                    representation: StringFormatRepresentation::Binary,
+                    ..Default::default()
+                },
+            ),
+            (
+                "x<4",
+                StringFormatOptions {
+                    alignment: StringAlignment::Left,
+                    fill_character: Some(0.into()),
+                    min_width: Some(4),
+                    ..Default::default()
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn separator_and_representation() {
+        test_parse_format_string(&[
+            (
+                ",",
+                StringFormatOptions {
+                    thousands_separator: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                ">12,.2",
+                StringFormatOptions {
+                    alignment: StringAlignment::Right,
+                    min_width: Some(12),
+                    thousands_separator: true,
+                    precision: Some(2),
+                    ..Default::default()
+                },
+            ),
+            (
+                ".3E",
+                StringFormatOptions {
+                    precision: Some(3),
+                    representation: StringFormatRepresentation::ExpUpper,
+                    ..Default::default()
+                },
+            ),
+            (
+                "o",
+                StringFormatOptions {
+                    representation: StringFormatRepresentation::Octal,
+                    ..Default::default()
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn custom_format_strings() {
+        test_parse_format_string(&[
+            (
+                "%Y-%m-%d",
+                StringFormatOptions {
+                    format_string: Some(0.into()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "dollars",
+                StringFormatOptions {
+                    format_string: Some(0.into()),
+                    ..Default::default()
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn malformed_standard_options() {
+        for format_string in [">5.2q", "10xyz", "+#z"] {
+            let mut constants = ConstantPoolBuilder::default();
+            assert!(
+                StringFormatOptions::parse(format_string, &mut constants).is_err(),
+                "Expected an error for '{format_string}'"
+            );
+        }
+    }
 }
@@ -62,7 +62,12 @@ mod parser {
     }
 
     fn type_hint(constant: u32) -> Node {
-        Node::Type(constant.into())
+        Node::Type {
+            hint: constant.into(),
+            name: constant.into(),
+            nested: astvec![],
+            optional: false,
+        }
     }
 
     fn int(constant: u32) -> Node {
@@ -149,6 +154,10 @@ mod parser {
         Node::Chain((ChainNode::Index(index.into()), next.map(AstIndex::from)))
     }
 
+    fn chain_null_check(next: Option<u32>) -> Node {
+        Node::Chain((ChainNode::NullCheck, next.map(AstIndex::from)))
+    }
+
     fn chain_root(index: u32, next: Option<u32>) -> Node {
         Node::Chain((ChainNode::Root(index.into()), next.map(AstIndex::from)))
     }
@@ -384,6 +393,7 @@ null"#;
                                     min_width: Some(3),
                                     precision: Some(2),
                                     fill_character: Some(2.into()),
+                                    ..Default::default()
                                 },
                             },
                             StringNode::Literal(0.into()),
@@ -397,6 +407,46 @@ null"#;
                 Some(&[Constant::Str("!"), Constant::Str("a"), Constant::Str("_")]),
             )
         }
+        #[test]
+        fn multi_line_string() {
+            let source = r#"
+x = '''
+    foo
+      {y}
+    bar
+    '''
+"#;
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    id(2),
+                    Str(AstString {
+                        quote: StringQuote::Single,
+                        contents: StringContents::Interpolated(vec![
+                            StringNode::Literal(1.into()),
+                            StringNode::Expression {
+                                expression: 1.into(),
+                                format: StringFormatOptions::default(),
+                            },
+                            StringNode::Literal(3.into()),
+                        ]),
+                    }),
+                    assign(0, 2),
+                    MainBlock {
+                        body: nodes(&[3]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("x"),
+                    Constant::Str("foo\n  "),
+                    Constant::Str("y"),
+                    Constant::Str("\nbar"),
+                ]),
+            )
+        }
+
         #[test]
         fn raw_strings() {
             let source = r###"
@@ -1410,6 +1460,123 @@ x %= 4";
             )
         }
 
+        #[test]
+        fn nested_and_optional_type_hint() {
+            let source = "let a: Map<String, List<Int>>? = x";
+
+            check_ast(
+                source,
+                &[
+                    type_hint(2), // String
+                    type_hint(4), // Int
+                    Node::Type {
+                        hint: 5.into(),
+                        name: 3.into(),
+                        nested: nodes(&[1]),
+                        optional: false,
+                    },
+                    Node::Type {
+                        hint: 6.into(),
+                        name: 1.into(),
+                        nested: nodes(&[0, 2]),
+                        optional: true,
+                    },
+                    id_with_type_hint(0, 3), // a
+                    id(7),                   // x - 5
+                    Assign {
+                        target: 4.into(),
+                        expression: 5.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[6]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("a"),
+                    Constant::Str("Map"),
+                    Constant::Str("String"),
+                    Constant::Str("List"),
+                    Constant::Str("Int"),
+                    Constant::Str("List<Int>"),
+                    Constant::Str("Map<String, List<Int>>?"),
+                    Constant::Str("x"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn union_type_hint() {
+            let source = "let a: Int | String? = x";
+
+            check_ast(
+                source,
+                &[
+                    type_hint(1), // Int
+                    Node::Type {
+                        hint: 3.into(),
+                        name: 2.into(),
+                        nested: astvec![],
+                        optional: true,
+                    },
+                    TypeUnion {
+                        hint: 4.into(),
+                        types: nodes(&[0, 1]),
+                    },
+                    id_with_type_hint(0, 2), // a
+                    id(5),                   // x
+                    Assign {
+                        target: 3.into(),
+                        expression: 4.into(),
+                    }, // 5
+                    MainBlock {
+                        body: nodes(&[5]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("a"),
+                    Constant::Str("Int"),
+                    Constant::Str("String"),
+                    Constant::Str("String?"),
+                    Constant::Str("Int | String?"),
+                    Constant::Str("x"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn map_pattern() {
+            let source = "let {foo, bar: x: Int} = baz";
+
+            check_ast(
+                source,
+                &[
+                    id(0),                   // foo
+                    id(1),                   // bar
+                    type_hint(3),            // Int
+                    id_with_type_hint(2, 2), // x
+                    MapPattern(astvec![(0.into(), None), (1.into(), Some(3.into()))]),
+                    id(4), // 5 - baz
+                    Assign {
+                        target: 4.into(),
+                        expression: 5.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[6]),
+                        local_count: 2,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("foo"),
+                    Constant::Str("bar"),
+                    Constant::Str("x"),
+                    Constant::Str("Int"),
+                    Constant::Str("baz"),
+                ]),
+            )
+        }
+
         #[test]
         fn multiple_targets() {
             let source = "let foo: String, bar: Int = baz";
@@ -1527,6 +1694,240 @@ x %= 4";
         }
     }
 
+    mod const_expression {
+        use super::*;
+
+        #[test]
+        fn const_number() {
+            let source = "const x = 1";
+
+            check_ast(
+                source,
+                &[
+                    id(0), // x
+                    SmallInt(1),
+                    Const {
+                        target: 0.into(),
+                        expression: 1.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[2]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[Constant::Str("x")]),
+            )
+        }
+
+        #[test]
+        fn const_with_type_hint() {
+            let source = "const x: List = [1]";
+
+            check_ast(
+                source,
+                &[
+                    type_hint(1),            // List
+                    id_with_type_hint(0, 0), // x
+                    SmallInt(1),
+                    List(nodes(&[2])),
+                    Const {
+                        target: 1.into(),
+                        expression: 3.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[4]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[Constant::Str("x"), Constant::Str("List")]),
+            )
+        }
+    }
+
+    mod records_and_enums {
+        use super::*;
+
+        #[test]
+        fn record_declaration() {
+            let source = "record Point(x: Number, y)";
+
+            check_ast(
+                source,
+                &[
+                    id(0), // Point
+                    type_hint(2),
+                    id_with_type_hint(1, 1), // x
+                    id(3),                   // y
+                    Record {
+                        name: 0.into(),
+                        fields: nodes(&[2, 3]),
+                    },
+                    Assign {
+                        target: 0.into(),
+                        expression: 4.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[5]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("Point"),
+                    Constant::Str("x"),
+                    Constant::Str("Number"),
+                    Constant::Str("y"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn record_declaration_multiline() {
+            let source = "
+record Point(
+  x,
+  y,
+)
+";
+
+            check_ast(
+                source,
+                &[
+                    id(0), // Point
+                    id(1), // x
+                    id(2), // y
+                    Record {
+                        name: 0.into(),
+                        fields: nodes(&[1, 2]),
+                    },
+                    Assign {
+                        target: 0.into(),
+                        expression: 3.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[4]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("Point"),
+                    Constant::Str("x"),
+                    Constant::Str("y"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn enum_declaration() {
+            let source = "
+enum Shape
+  Circle(r)
+  Empty()
+";
+
+            check_ast(
+                source,
+                &[
+                    id(0), // Shape
+                    id(2), // r
+                    Record {
+                        name: 1.into(),
+                        fields: nodes(&[1]),
+                    },
+                    Record {
+                        name: 3.into(),
+                        fields: nodes(&[]),
+                    },
+                    Enum {
+                        name: 0.into(),
+                        variants: nodes(&[2, 3]),
+                    },
+                    Assign {
+                        target: 0.into(),
+                        expression: 4.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[5]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("Shape"),
+                    Constant::Str("Circle"),
+                    Constant::Str("r"),
+                    Constant::Str("Empty"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn interface_declaration() {
+            let source = "
+interface Container
+  size
+  'first'
+  @[]
+";
+
+            check_ast(
+                source,
+                &[
+                    id(0), // Container
+                    id(1), // size
+                    string_literal(2, StringQuote::Single),
+                    Meta(MetaKeyId::Index, None),
+                    Interface {
+                        name: 0.into(),
+                        entries: nodes(&[1, 2, 3]),
+                    },
+                    Assign {
+                        target: 0.into(),
+                        expression: 4.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[5]),
+                        local_count: 1,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("Container"),
+                    Constant::Str("size"),
+                    Constant::Str("first"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn record_and_enum_as_ids() {
+            let source = "
+record = 1
+enum = record
+";
+
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    SmallInt(1),
+                    Assign {
+                        target: 0.into(),
+                        expression: 1.into(),
+                    },
+                    id(1),
+                    id(0),
+                    Assign {
+                        target: 3.into(),
+                        expression: 4.into(),
+                    },
+                    MainBlock {
+                        body: nodes(&[2, 5]),
+                        local_count: 2,
+                    },
+                ],
+                Some(&[Constant::Str("record"), Constant::Str("enum")]),
+            )
+        }
+    }
+
     mod export {
         use super::*;
 
@@ -1730,11 +2131,77 @@ export
                     SmallInt(4),
                     binary_op(AstBinaryOp::Remainder, 2, 3),
                     MainBlock {
-                        body: nodes(&[4]),
+                        body: nodes(&[4]),
+                        local_count: 0,
+                    },
+                ],
+                None,
+            )
+        }
+
+        #[test]
+        fn integer_divide_then_power() {
+            let source = "9 // 2 ** 3 ** 2";
+            check_ast(
+                source,
+                &[
+                    SmallInt(9),
+                    SmallInt(2),
+                    SmallInt(3),
+                    SmallInt(2),
+                    binary_op(AstBinaryOp::Power, 2, 3),
+                    binary_op(AstBinaryOp::Power, 1, 4), // 5
+                    binary_op(AstBinaryOp::IntegerDivide, 0, 5),
+                    MainBlock {
+                        body: nodes(&[6]),
+                        local_count: 0,
+                    },
+                ],
+                None,
+            )
+        }
+
+        #[test]
+        fn bitwise_operators() {
+            let source = "1 | 2 ^ 3 & 4 << 1 + 1";
+            check_ast(
+                source,
+                &[
+                    SmallInt(1),
+                    SmallInt(2),
+                    SmallInt(3),
+                    SmallInt(4),
+                    SmallInt(1),
+                    SmallInt(1), // 5
+                    binary_op(AstBinaryOp::Add, 4, 5),
+                    binary_op(AstBinaryOp::ShiftLeft, 3, 6),
+                    binary_op(AstBinaryOp::BitwiseAnd, 2, 7),
+                    binary_op(AstBinaryOp::BitwiseXor, 1, 8),
+                    binary_op(AstBinaryOp::BitwiseOr, 0, 9), // 10
+                    MainBlock {
+                        body: nodes(&[10]),
+                        local_count: 0,
+                    },
+                ],
+                None,
+            )
+        }
+
+        #[test]
+        fn bitwise_or_with_ids() {
+            let sources = ["x | y", "x|y"];
+            check_ast_for_equivalent_sources(
+                &sources,
+                &[
+                    id(0),
+                    id(1),
+                    binary_op(AstBinaryOp::BitwiseOr, 0, 1),
+                    MainBlock {
+                        body: nodes(&[2]),
                         local_count: 0,
                     },
                 ],
-                None,
+                Some(&[Constant::Str("x"), Constant::Str("y")]),
             )
         }
 
@@ -1895,6 +2362,33 @@ a = (1
             )
         }
 
+        #[test]
+        fn null_coalesce() {
+            let source = "a ?? b or c ?? d";
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    id(1),
+                    id(2),
+                    binary_op(AstBinaryOp::Or, 1, 2),
+                    binary_op(AstBinaryOp::NullCoalesce, 0, 3),
+                    id(3), // 5
+                    binary_op(AstBinaryOp::NullCoalesce, 4, 5),
+                    MainBlock {
+                        body: nodes(&[6]),
+                        local_count: 0,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("a"),
+                    Constant::Str("b"),
+                    Constant::Str("c"),
+                    Constant::Str("d"),
+                ]),
+            )
+        }
+
         #[test]
         fn chained_comparisons() {
             let source = "0 < 1 <= 1";
@@ -2370,6 +2864,44 @@ a()";
             )
         }
 
+        #[test]
+        fn arg_with_grouped_union_type_hint() {
+            let source = "|x: (Int | Null)| x";
+
+            check_ast(
+                source,
+                &[
+                    type_hint(1), // Int
+                    type_hint(2), // Null
+                    TypeUnion {
+                        hint: 3.into(),
+                        types: nodes(&[0, 1]),
+                    },
+                    id_with_type_hint(0, 2), // x
+                    id(0),                   // x
+                    Function(koto_parser::Function {
+                        args: nodes(&[3]),
+                        local_count: 1,
+                        accessed_non_locals: constants(&[]),
+                        body: 4.into(),
+                        is_variadic: false,
+                        is_generator: false,
+                        output_type: None,
+                    }),
+                    MainBlock {
+                        body: nodes(&[5]),
+                        local_count: 0,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("x"),
+                    Constant::Str("Int"),
+                    Constant::Str("Null"),
+                    Constant::Str("Int | Null"),
+                ]),
+            )
+        }
+
         #[test]
         fn output_type_hint() {
             let sources = [
@@ -3415,6 +3947,45 @@ z = y [0..20], |x| x > 1
                 ]),
             )
         }
+
+        #[test]
+        fn unpack_map_arg() {
+            let source = "|{a, 'b': c, rest...}| a";
+            check_ast(
+                source,
+                &[
+                    id(0),                                  // a
+                    string_literal(1, StringQuote::Single), // b
+                    id(2),                                  // c
+                    Ellipsis(Some(3.into())),               // rest
+                    MapPattern(astvec![
+                        (0.into(), None),
+                        (1.into(), Some(2.into())),
+                        (3.into(), None)
+                    ]),
+                    id(0),
+                    Function(koto_parser::Function {
+                        args: nodes(&[4]),
+                        local_count: 3,
+                        accessed_non_locals: constants(&[]),
+                        body: 5.into(),
+                        is_variadic: false,
+                        is_generator: false,
+                        output_type: None,
+                    }),
+                    MainBlock {
+                        body: nodes(&[6]),
+                        local_count: 0,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("a"),
+                    Constant::Str("b"),
+                    Constant::Str("c"),
+                    Constant::Str("rest"),
+                ]),
+            )
+        }
     }
 
     mod chains {
@@ -3774,6 +4345,48 @@ x.takes_a_map
             )
         }
 
+        #[test]
+        fn null_checks() {
+            let source = "a?.b?[0]";
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    SmallInt(0),
+                    chain_index(1, None),
+                    chain_null_check(Some(2)),
+                    chain_id(1, Some(3)),
+                    chain_null_check(Some(4)), // 5
+                    chain_root(0, Some(5)),
+                    MainBlock {
+                        body: nodes(&[6]),
+                        local_count: 0,
+                    },
+                ],
+                Some(&[Constant::Str("a"), Constant::Str("b")]),
+            )
+        }
+
+        #[test]
+        fn null_check_before_call() {
+            let source = "f?(x)";
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    id(1),
+                    chain_call(&[1], true, None),
+                    chain_null_check(Some(2)),
+                    chain_root(0, Some(3)),
+                    MainBlock {
+                        body: nodes(&[4]),
+                        local_count: 0,
+                    },
+                ],
+                Some(&[Constant::Str("f"), Constant::Str("x")]),
+            )
+        }
+
         #[test]
         fn index_on_call_result() {
             let source = "(f x)[0]";
@@ -4917,6 +5530,176 @@ match (x, y, z)
             )
         }
 
+        #[test]
+        fn match_range_patterns() {
+            let source = r#"
+match x
+  0..10 then 0
+  ..=-1 then 1
+  10.. then 2
+"#;
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    SmallInt(0),
+                    SmallInt(10),
+                    Range {
+                        start: 1.into(),
+                        end: 2.into(),
+                        inclusive: false,
+                    },
+                    SmallInt(0),
+                    SmallInt(-1), // 5
+                    RangeTo {
+                        end: 5.into(),
+                        inclusive: true,
+                    },
+                    SmallInt(1),
+                    SmallInt(10),
+                    RangeFrom { start: 8.into() },
+                    SmallInt(2), // 10
+                    Match {
+                        expression: 0.into(),
+                        arms: vec![
+                            MatchArm {
+                                patterns: nodes(&[3]),
+                                condition: None,
+                                expression: 4.into(),
+                            },
+                            MatchArm {
+                                patterns: nodes(&[6]),
+                                condition: None,
+                                expression: 7.into(),
+                            },
+                            MatchArm {
+                                patterns: nodes(&[9]),
+                                condition: None,
+                                expression: 10.into(),
+                            },
+                        ],
+                    },
+                    MainBlock {
+                        body: nodes(&[11]),
+                        local_count: 0,
+                    },
+                ],
+                Some(&[Constant::Str("x")]),
+            )
+        }
+
+        #[test]
+        fn match_record_pattern() {
+            let source = "
+match x
+  Shape.Circle(r) then r
+  Point(a, _) then a
+";
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    id(1),
+                    chain_id(2, None),
+                    chain_root(1, Some(2)),
+                    id(3),
+                    RecordPattern {
+                        constructor: 3.into(),
+                        fields: nodes(&[4]),
+                    }, // 5
+                    id(3),
+                    id(4),
+                    id(5),
+                    Wildcard(None, None),
+                    RecordPattern {
+                        constructor: 7.into(),
+                        fields: nodes(&[8, 9]),
+                    }, // 10
+                    id(5),
+                    Match {
+                        expression: 0.into(),
+                        arms: vec![
+                            MatchArm {
+                                patterns: nodes(&[5]),
+                                condition: None,
+                                expression: 6.into(),
+                            },
+                            MatchArm {
+                                patterns: nodes(&[10]),
+                                condition: None,
+                                expression: 11.into(),
+                            },
+                        ],
+                    },
+                    MainBlock {
+                        body: nodes(&[12]),
+                        local_count: 2,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("x"),
+                    Constant::Str("Shape"),
+                    Constant::Str("Circle"),
+                    Constant::Str("r"),
+                    Constant::Str("Point"),
+                    Constant::Str("a"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn match_map_pattern() {
+            let source = r#"
+match x
+  {a: 0, b} then b
+  {a: (1, c), rest...} then c
+"#;
+            check_ast(
+                source,
+                &[
+                    id(0),
+                    id(1),
+                    SmallInt(0),
+                    id(2),
+                    MapPattern(astvec![(1.into(), Some(2.into())), (3.into(), None)]),
+                    id(2), // 5
+                    id(1),
+                    SmallInt(1),
+                    id(3),
+                    Tuple(nodes(&[7, 8])),
+                    Ellipsis(Some(4.into())), // 10
+                    MapPattern(astvec![(6.into(), Some(9.into())), (10.into(), None)]),
+                    id(3),
+                    Match {
+                        expression: 0.into(),
+                        arms: vec![
+                            MatchArm {
+                                patterns: nodes(&[4]),
+                                condition: None,
+                                expression: 5.into(),
+                            },
+                            MatchArm {
+                                patterns: nodes(&[11]),
+                                condition: None,
+                                expression: 12.into(),
+                            },
+                        ],
+                    },
+                    MainBlock {
+                        body: nodes(&[13]),
+                        local_count: 3,
+                    },
+                ],
+                Some(&[
+                    Constant::Str("x"),
+                    Constant::Str("a"),
+                    Constant::Str("b"),
+                    Constant::Str("c"),
+                    Constant::Str("rest"),
+                ]),
+            )
+        }
+
         #[test]
         fn match_tuple_subslice() {
             let source = r#"
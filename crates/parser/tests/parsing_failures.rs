@@ -360,6 +360,11 @@ switch
                 check_parsing_fails("'${1 + }'");
             }
 
+            #[test]
+            fn malformed_format_options() {
+                check_parsing_fails("'{x:>5.2q}'");
+            }
+
             #[test]
             fn multiline_template_expression() {
                 let source = "
@@ -395,17 +400,89 @@ switch
             }
         }
 
-        mod reserved_keywords {
+        mod const_expressions {
             use super::*;
 
             #[test]
-            fn r#await() {
-                check_parsing_fails("await = 99");
+            fn missing_id() {
+                check_parsing_fails("const = 99");
             }
 
             #[test]
-            fn r#const() {
-                check_parsing_fails("const = 99");
+            fn missing_assignment() {
+                check_parsing_fails("const x");
+            }
+
+            #[test]
+            fn multiple_targets() {
+                check_parsing_fails("const x, y = 1, 2");
+            }
+        }
+
+        mod type_hints {
+            use super::*;
+
+            #[test]
+            fn missing_end_of_nested_types() {
+                check_parsing_fails("let x: List<Number = []");
+            }
+
+            #[test]
+            fn empty_nested_types() {
+                check_parsing_fails("let x: List<> = []");
+            }
+
+            #[test]
+            fn missing_type_in_union() {
+                check_parsing_fails("let x: Number | = 1");
+            }
+
+            #[test]
+            fn missing_close_paren_in_grouped_type() {
+                check_parsing_fails("f = |x: (Number | String| x");
+            }
+        }
+
+        mod records_and_enums {
+            use super::*;
+
+            #[test]
+            fn missing_record_field_id() {
+                check_parsing_fails("record Point(x, 1)");
+            }
+
+            #[test]
+            fn missing_close_paren_in_record() {
+                check_parsing_fails("record Point(x, y");
+            }
+
+            #[test]
+            fn enum_variant_that_isnt_an_id() {
+                let source = "
+enum Shape
+  Circle(r)
+  42
+";
+                check_parsing_fails(source);
+            }
+
+            #[test]
+            fn interface_entry_that_isnt_a_key() {
+                let source = "
+interface Shape
+  area
+  42
+";
+                check_parsing_fails(source);
+            }
+        }
+
+        mod reserved_keywords {
+            use super::*;
+
+            #[test]
+            fn r#await() {
+                check_parsing_fails("await = 99");
             }
         }
     }
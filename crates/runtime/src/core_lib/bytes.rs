@@ -0,0 +1,282 @@
+//! The `bytes` core library module
+
+use super::iterator::collect_pair;
+use crate::{derive::*, prelude::*, KIteratorOutput as Output, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use std::ops::{Deref, DerefMut};
+
+/// Initializes the `bytes` core library module
+pub fn make_module() -> KMap {
+    use KValue::Str;
+
+    let result = KMap::with_type("core.bytes");
+
+    result.add_fn("from_base64", |ctx| match ctx.args() {
+        [Str(s)] => decode_base64(s).map(|result| Bytes::from(result).into()),
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("from_hex", |ctx| match ctx.args() {
+        [Str(s)] => decode_hex(s).map(|result| Bytes::from(result).into()),
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("new", |ctx| match ctx.args() {
+        [] => Ok(Bytes::default().into()),
+        [Str(s)] => Ok(Bytes::from(s.as_bytes().to_vec()).into()),
+        [iterable] if iterable.is_iterable() => {
+            let iterable = iterable.clone();
+            collect_bytes(ctx.vm, iterable).map(|result| Bytes::from(result).into())
+        }
+        unexpected => unexpected_args("||, |String|, or |Iterable|", unexpected),
+    });
+
+    result
+}
+
+/// The Bytes type used in the bytes module
+///
+/// Bytes are a mutable buffer of binary data, with each byte represented in scripts as a number
+/// in the range `0..=255`.
+#[derive(Clone, Default, KotoCopy, KotoType)]
+pub struct Bytes(Vec<u8>);
+
+#[koto_impl(runtime = crate)]
+impl Bytes {
+    #[koto_method]
+    fn clear(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [] => {
+                ctx.instance_mut()?.0.clear();
+                ctx.instance_result()
+            }
+            unexpected => unexpected_args("||", unexpected),
+        }
+    }
+
+    #[koto_method]
+    fn extend(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [KValue::Str(s)] => ctx.instance_mut()?.0.extend_from_slice(s.as_bytes()),
+            [KValue::Object(o)] if o.is_a::<Self>() => {
+                // Clone the other object's data first, in case it's the same instance
+                let other = o.cast::<Self>()?.0.clone();
+                ctx.instance_mut()?.0.extend(other);
+            }
+            [iterable] if iterable.is_iterable() => {
+                let mut vm = ctx.vm.spawn_shared_vm();
+                let new_bytes = collect_bytes(&mut vm, iterable.clone())?;
+                ctx.instance_mut()?.0.extend(new_bytes);
+            }
+            unexpected => return unexpected_args("|String|, or |Iterable|", unexpected),
+        }
+        ctx.instance_result()
+    }
+
+    #[koto_method]
+    fn is_empty(&self) -> KValue {
+        self.0.is_empty().into()
+    }
+
+    #[koto_method]
+    fn push(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [KValue::Number(n)] => {
+                ctx.instance_mut()?.0.push(number_to_byte(*n)?);
+                ctx.instance_result()
+            }
+            unexpected => unexpected_args("|Number|", unexpected),
+        }
+    }
+
+    #[koto_method]
+    fn to_base64(&self) -> KValue {
+        encode_base64(&self.0).into()
+    }
+
+    #[koto_method]
+    fn to_hex(&self) -> KValue {
+        encode_hex(&self.0).into()
+    }
+
+    #[koto_method]
+    fn to_string(&self) -> Result<KValue> {
+        match std::str::from_utf8(&self.0) {
+            Ok(result) => Ok(result.into()),
+            Err(_) => runtime_error!("Input failed UTF-8 validation"),
+        }
+    }
+}
+
+impl KotoObject for Bytes {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append("Bytes(");
+        for (i, byte) in self.0.iter().enumerate() {
+            if i > 0 {
+                ctx.append(", ");
+            }
+            ctx.append(byte.to_string());
+        }
+        ctx.append(')');
+        Ok(())
+    }
+
+    fn index(&self, index: &KValue) -> Result<KValue> {
+        match index {
+            KValue::Number(n) => {
+                if *n < 0.0 {
+                    return runtime_error!("Negative indices aren't allowed ('{n}')");
+                }
+                match self.0.get(usize::from(n)) {
+                    Some(byte) => Ok((*byte).into()),
+                    None => {
+                        let size = self.0.len();
+                        runtime_error!("Index out of bounds - index: {n}, size: {size}")
+                    }
+                }
+            }
+            KValue::Range(range) => {
+                let indices = range.indices(self.0.len());
+                Ok(Self::from(self.0[indices].to_vec()).into())
+            }
+            unexpected => unexpected_type("Number or Range", unexpected),
+        }
+    }
+
+    fn size(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+
+    fn add(&self, rhs: &KValue) -> Result<KValue> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>()?;
+                let mut result = Vec::with_capacity(self.0.len() + rhs.0.len());
+                result.extend_from_slice(&self.0);
+                result.extend_from_slice(&rhs.0);
+                Ok(Self::from(result).into())
+            }
+            unexpected => unexpected_type(Self::type_static(), unexpected),
+        }
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => Ok(self.0 == o.cast::<Self>()?.0),
+            _ => Ok(false),
+        }
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+
+    fn is_iterable(&self) -> IsIterable {
+        IsIterable::Iterable
+    }
+
+    fn make_iterator(&self, _vm: &mut KotoVm) -> Result<KIterator> {
+        let iter = self
+            .0
+            .clone()
+            .into_iter()
+            .map(|byte| Output::Value(byte.into()));
+        Ok(KIterator::with_std_iter(iter))
+    }
+}
+
+impl Deref for Bytes {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Bytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Bytes> for KValue {
+    fn from(bytes: Bytes) -> Self {
+        KObject::from(bytes).into()
+    }
+}
+
+/// Collects the output of an iterable value into a byte vector
+///
+/// An error is returned if any of the iterator's values aren't numbers in the range `0..=255`.
+pub(crate) fn collect_bytes(vm: &mut KotoVm, iterable: KValue) -> Result<Vec<u8>> {
+    let iterator = vm.make_iterator(iterable)?;
+    let (size_hint, _) = iterator.size_hint();
+    let mut result = Vec::with_capacity(size_hint);
+
+    for output in iterator.map(collect_pair) {
+        match output {
+            Output::Value(KValue::Number(n)) => result.push(number_to_byte(n)?),
+            Output::Value(unexpected) => return unexpected_type("Number", &unexpected),
+            Output::Error(error) => return Err(error),
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(result)
+}
+
+fn number_to_byte(n: KNumber) -> Result<u8> {
+    match u8::try_from(i64::from(n)) {
+        Ok(byte) => Ok(byte),
+        Err(_) => runtime_error!("'{n}' is out of the valid byte range"),
+    }
+}
+
+/// Encodes the data as a base64 string, using the standard alphabet with padding
+pub fn encode_base64(data: &[u8]) -> String {
+    BASE64.encode(data)
+}
+
+/// Decodes a base64 string, using the standard alphabet with padding
+pub fn decode_base64(s: &str) -> Result<Vec<u8>> {
+    match BASE64.decode(s.as_bytes()) {
+        Ok(result) => Ok(result),
+        Err(error) => runtime_error!("Failed to decode base64 data: {error}"),
+    }
+}
+
+/// Encodes the data as a string of lowercase hex digits
+pub fn encode_hex(data: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut result = String::with_capacity(data.len() * 2);
+    for byte in data {
+        write!(result, "{byte:02x}").ok();
+    }
+    result
+}
+
+/// Decodes a string of hex digits, which must contain two digits per byte
+pub fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return runtime_error!("Hex strings must contain an even number of digits");
+    }
+
+    let mut result = Vec::with_capacity(s.len() / 2);
+    for chunk in s.as_bytes().chunks(2) {
+        let digits = String::from_utf8_lossy(chunk);
+        if !chunk.iter().all(u8::is_ascii_hexdigit) {
+            return runtime_error!("Invalid hex digits: '{digits}'");
+        }
+        // The digits have been validated, so parsing can't fail
+        result.push(u8::from_str_radix(&digits, 16).unwrap());
+    }
+
+    Ok(result)
+}
@@ -1,10 +1,22 @@
 //! The `io` core library module
 
+mod dir;
+mod metadata;
+mod path;
+
+pub use dir::{DirIterator, Glob};
+pub use metadata::Metadata;
+pub use path::{path_from_value, Path};
+
+use super::bytes::Bytes;
 use crate::{derive::*, prelude::*, BufferedFile, Error, Ptr, Result};
+pub(crate) use path::normalize_path;
+
+use path::extend_path;
 use std::{
-    fmt, fs,
+    fmt,
     io::{self, BufRead, Read, Seek, SeekFrom, Write},
-    path::{Path, PathBuf},
+    path::PathBuf,
 };
 
 /// The initializer for the io module
@@ -13,16 +25,27 @@ pub fn make_module() -> KMap {
 
     let result = KMap::with_type("core.io");
 
+    result.add_fn("copy", |ctx| match path_args(ctx.args()) {
+        Some([from, to]) => {
+            check_read(ctx.vm, &from)?;
+            check_write(ctx.vm, &to)?;
+            ctx.vm
+                .file_system()
+                .copy(&from, &to)
+                .map_err(|error| error.with_prefix("io.copy"))?;
+            Ok(Null)
+        }
+        None => unexpected_args("|String, String|, or |Path, Path|", ctx.args()),
+    });
+
     result.add_fn("create", {
-        move |ctx| match ctx.args() {
-            [Str(path)] => {
-                let path = Path::new(path.as_str()).to_path_buf();
-                match fs::File::create(&path) {
-                    Ok(file) => Ok(File::system_file(file, path)),
-                    Err(error) => runtime_error!("Error while creating file: {error}"),
-                }
+        move |ctx| match path_args(ctx.args()) {
+            Some([path]) => {
+                check_write(ctx.vm, &path)?;
+                let file = ctx.vm.file_system().create(&path)?;
+                Ok(File::new(file).into())
             }
-            unexpected => unexpected_args("|String|", unexpected),
+            None => unexpected_args("|String|, or |Path|", ctx.args()),
         }
     });
 
@@ -37,43 +60,79 @@ pub fn make_module() -> KMap {
         unexpected => unexpected_args("||", unexpected),
     });
 
-    result.add_fn("exists", |ctx| match ctx.args() {
-        [Str(path)] => Ok(Bool(fs::canonicalize(path.as_str()).is_ok())),
-        unexpected => unexpected_args("|String|", unexpected),
+    result.add_fn("exists", |ctx| match path_args(ctx.args()) {
+        Some([path]) => {
+            check_read(ctx.vm, &path)?;
+            ctx.vm.file_system().exists(&path).map(Bool)
+        }
+        None => unexpected_args("|String|, or |Path|", ctx.args()),
     });
 
     result.add_fn("extend_path", |ctx| match ctx.args() {
         [Str(path), nodes @ ..] => {
             let mut path = PathBuf::from(path.as_str());
-
-            for node in nodes {
-                match node {
-                    Str(s) => path.push(s.as_str()),
-                    other => {
-                        let mut display_context = DisplayContext::with_vm(ctx.vm);
-                        other.display(&mut display_context)?;
-                        path.push(display_context.result());
-                    }
-                }
-            }
+            extend_path(&mut path, nodes, ctx.vm)?;
             Ok(path.to_string_lossy().to_string().into())
         }
         unexpected => unexpected_args("|String, Any...|", unexpected),
     });
 
+    result.add_fn("glob", |ctx| match ctx.args() {
+        [Str(pattern)] => {
+            let glob = Glob::new(ctx.vm.file_system().clone(), pattern)?;
+            check_read(ctx.vm, glob.root())?;
+            Ok(KIterator::new(glob).into())
+        }
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("make_dir", |ctx| match path_args(ctx.args()) {
+        Some([path]) => {
+            check_write(ctx.vm, &path)?;
+            ctx.vm
+                .file_system()
+                .make_dir(&path)
+                .map_err(|error| error.with_prefix("io.make_dir"))?;
+            Ok(Null)
+        }
+        None => unexpected_args("|String|, or |Path|", ctx.args()),
+    });
+
+    result.add_fn("metadata", |ctx| match path_args(ctx.args()) {
+        Some([path]) => {
+            check_read(ctx.vm, &path)?;
+            let metadata = ctx
+                .vm
+                .file_system()
+                .metadata(&path)
+                .map_err(|error| error.with_prefix("io.metadata"))?;
+            Ok(Metadata::new(path, metadata).into())
+        }
+        None => unexpected_args("|String|, or |Path|", ctx.args()),
+    });
+
     result.add_fn("open", {
-        |ctx| match ctx.args() {
-            [Str(path)] => match fs::canonicalize(path.as_str()) {
-                Ok(path) => match fs::File::open(&path) {
-                    Ok(file) => Ok(File::system_file(file, path)),
-                    Err(error) => runtime_error!("Error while opening path: {error}"),
-                },
-                Err(_) => runtime_error!("Failed to canonicalize path"),
-            },
-            unexpected => unexpected_args("|String|", unexpected),
+        |ctx| match path_args(ctx.args()) {
+            Some([path]) => {
+                check_read(ctx.vm, &path)?;
+                let file = ctx.vm.file_system().open(&path)?;
+                Ok(File::new(file).into())
+            }
+            None => unexpected_args("|String|, or |Path|", ctx.args()),
         }
     });
 
+    result.add_fn("path", |ctx| match ctx.args() {
+        [first, nodes @ ..] => match path_from_value(first) {
+            Some(mut path) => {
+                extend_path(&mut path, nodes, ctx.vm)?;
+                Ok(Path::new(path).into())
+            }
+            None => unexpected_args("|String, Any...|, or |Path, Any...|", ctx.args()),
+        },
+        unexpected => unexpected_args("|String, Any...|, or |Path, Any...|", unexpected),
+    });
+
     result.add_fn("print", |ctx| {
         let result = match ctx.args() {
             [Str(s)] => ctx.vm.stdout().write_line(s.as_str()),
@@ -100,30 +159,101 @@ pub fn make_module() -> KMap {
         result.map(|_| Null)
     });
 
-    result.add_fn("read_to_string", |ctx| match ctx.args() {
-        [Str(path)] => match fs::read_to_string(Path::new(path.as_str())) {
-            Ok(result) => Ok(result.into()),
-            Err(error) => {
-                runtime_error!("io.read_to_string: Unable to read file '{path}': {error}")
+    result.add_fn("read_bytes", |ctx| match path_args(ctx.args()) {
+        Some([path]) => {
+            check_read(ctx.vm, &path)?;
+            match ctx
+                .vm
+                .file_system()
+                .open(&path)
+                .and_then(|file| file.read_to_end())
+            {
+                Ok(result) => Ok(Bytes::from(result).into()),
+                Err(error) => Err(error.with_prefix(&format!(
+                    "io.read_bytes: Unable to read file '{}'",
+                    path.to_string_lossy()
+                ))),
             }
-        },
-        unexpected => unexpected_args("|String|", unexpected),
+        }
+        None => unexpected_args("|String|, or |Path|", ctx.args()),
+    });
+
+    result.add_fn("read_dir", |ctx| match path_args(ctx.args()) {
+        Some([path]) => {
+            check_read(ctx.vm, &path)?;
+            match DirIterator::read_dir(ctx.vm.file_system().clone(), &path) {
+                Ok(iterator) => Ok(KIterator::new(iterator).into()),
+                Err(error) => Err(error.with_prefix("io.read_dir")),
+            }
+        }
+        None => unexpected_args("|String|, or |Path|", ctx.args()),
+    });
+
+    result.add_fn("read_to_string", |ctx| match path_args(ctx.args()) {
+        Some([path]) => {
+            check_read(ctx.vm, &path)?;
+            match ctx
+                .vm
+                .file_system()
+                .open(&path)
+                .and_then(|file| file.read_to_string())
+            {
+                Ok(result) => Ok(result.into()),
+                Err(error) => Err(error.with_prefix(&format!(
+                    "io.read_to_string: Unable to read file '{}'",
+                    path.to_string_lossy()
+                ))),
+            }
+        }
+        None => unexpected_args("|String|, or |Path|", ctx.args()),
+    });
+
+    result.add_fn("remove_dir", |ctx| {
+        let (path, recursive) = match ctx.args() {
+            [path] => (path_from_value(path), false),
+            [path, Bool(recursive)] => (path_from_value(path), *recursive),
+            _ => (None, false),
+        };
+        let Some(path) = path else {
+            return unexpected_args(
+                "|String|, or |Path|, or |String, Bool|, or |Path, Bool|",
+                ctx.args(),
+            );
+        };
+        check_write(ctx.vm, &path)?;
+
+        ctx.vm
+            .file_system()
+            .remove_dir(&path, recursive)
+            .map_err(|error| error.with_prefix("io.remove_dir"))?;
+        Ok(Null)
     });
 
     result.add_fn("remove_file", {
-        |ctx| match ctx.args() {
-            [Str(path)] => {
-                let path = Path::new(path.as_str());
-                match fs::remove_file(path) {
-                    Ok(_) => Ok(KValue::Null),
-                    Err(error) => runtime_error!(
-                        "io.remove_file: Error while removing file '{}': {error}",
-                        path.to_string_lossy(),
-                    ),
-                }
+        |ctx| match path_args(ctx.args()) {
+            Some([path]) => {
+                check_write(ctx.vm, &path)?;
+                ctx.vm
+                    .file_system()
+                    .remove_file(&path)
+                    .map_err(|error| error.with_prefix("io.remove_file"))?;
+                Ok(Null)
             }
-            unexpected => unexpected_args("|String|", unexpected),
+            None => unexpected_args("|String|, or |Path|", ctx.args()),
+        }
+    });
+
+    result.add_fn("rename", |ctx| match path_args(ctx.args()) {
+        Some([from, to]) => {
+            check_write(ctx.vm, &from)?;
+            check_write(ctx.vm, &to)?;
+            ctx.vm
+                .file_system()
+                .rename(&from, &to)
+                .map_err(|error| error.with_prefix("io.rename"))?;
+            Ok(Null)
         }
+        None => unexpected_args("|String, String|, or |Path, Path|", ctx.args()),
     });
 
     result.add_fn("stderr", |ctx| match ctx.args() {
@@ -144,15 +274,61 @@ pub fn make_module() -> KMap {
         unexpected => unexpected_args("||", unexpected),
     });
 
+    result.add_fn("walk_dir", |ctx| match path_args(ctx.args()) {
+        Some([path]) => {
+            check_read(ctx.vm, &path)?;
+            match DirIterator::walk_dir(ctx.vm.file_system().clone(), &path) {
+                Ok(iterator) => Ok(KIterator::new(iterator).into()),
+                Err(error) => Err(error.with_prefix("io.walk_dir")),
+            }
+        }
+        None => unexpected_args("|String|, or |Path|", ctx.args()),
+    });
+
     result
 }
 
+// Returns the paths contained in the arguments if they're all Strings or Paths
+fn path_args<const N: usize>(args: &[KValue]) -> Option<[PathBuf; N]> {
+    if args.len() != N {
+        return None;
+    }
+
+    let paths = args
+        .iter()
+        .map(path_from_value)
+        .collect::<Option<Vec<_>>>()?;
+    paths.try_into().ok()
+}
+
+fn check_read(vm: &KotoVm, path: &std::path::Path) -> Result<()> {
+    vm.settings()
+        .permissions
+        .check_read(path, &**vm.file_system())
+}
+
+fn check_write(vm: &KotoVm, path: &std::path::Path) -> Result<()> {
+    vm.settings()
+        .permissions
+        .check_write(path, &**vm.file_system())
+}
+
 /// The File type used in the io module
 #[derive(Clone, KotoCopy, KotoType)]
 pub struct File(Ptr<dyn KotoFile>);
 
 #[koto_impl(runtime = crate)]
 impl File {
+    /// Initializes a File that wraps the provided [KotoFile]
+    pub fn new(file: Ptr<dyn KotoFile>) -> Self {
+        Self(file)
+    }
+
+    /// Returns a reference to the wrapped [KotoFile]
+    pub fn inner(&self) -> &Ptr<dyn KotoFile> {
+        &self.0
+    }
+
     /// Wraps a file that implements traits typical of a system file in a buffered reader/writer
     pub fn system_file<T>(file: T, path: PathBuf) -> KValue
     where
@@ -183,12 +359,29 @@ impl File {
         self.0.path().map(KValue::from)
     }
 
+    #[koto_method]
+    fn read_bytes(&mut self, args: &[KValue]) -> Result<KValue> {
+        let result = match args {
+            [] => self.0.read_to_end()?,
+            [KValue::Number(n)] if *n >= 0.0 => self.0.read_bytes(n.into())?,
+            unexpected => return unexpected_args("||, or |Number|", unexpected),
+        };
+        Ok(Bytes::from(result).into())
+    }
+
     #[koto_method]
     fn read_line(&mut self) -> Result<KValue> {
         self.0.read_line().map(|result| match result {
             Some(result) => {
                 if !result.is_empty() {
-                    let newline_bytes = if result.ends_with("\r\n") { 2 } else { 1 };
+                    // The final line in the file might not end with a newline
+                    let newline_bytes = if result.ends_with("\r\n") {
+                        2
+                    } else if result.ends_with('\n') {
+                        1
+                    } else {
+                        0
+                    };
                     result[..result.len() - newline_bytes].into()
                 } else {
                     KValue::Null
@@ -231,6 +424,17 @@ impl File {
         }
     }
 
+    #[koto_method]
+    fn write_bytes(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [KValue::Object(o)] if o.is_a::<Bytes>() => {
+                let bytes = o.cast::<Bytes>()?;
+                ctx.instance_mut()?.0.write(&bytes).map(|_| KValue::Null)
+            }
+            unexpected => unexpected_args("|Bytes|", unexpected),
+        }
+    }
+
     #[koto_method]
     fn write_line(ctx: MethodContext<Self>) -> Result<KValue> {
         let mut display_context = DisplayContext::with_vm(ctx.vm);
@@ -260,7 +464,7 @@ impl From<File> for KValue {
     }
 }
 
-struct BufferedSystemFile<T>
+pub(crate) struct BufferedSystemFile<T>
 where
     T: Write + KotoSend + KotoSync,
 {
@@ -326,6 +530,25 @@ where
             .map_err(map_io_err)?;
         Ok(buffer)
     }
+
+    fn read_bytes(&self, count: usize) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut file = self.file.borrow_mut();
+        Read::by_ref(&mut *file)
+            .take(count as u64)
+            .read_to_end(&mut buffer)
+            .map_err(map_io_err)?;
+        Ok(buffer)
+    }
+
+    fn read_to_end(&self) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.file
+            .borrow_mut()
+            .read_to_end(&mut buffer)
+            .map_err(map_io_err)?;
+        Ok(buffer)
+    }
 }
 
 impl<T> KotoWrite for BufferedSystemFile<T>
@@ -0,0 +1,258 @@
+//! Iterators over the contents of directories, used by the `io` module
+
+use crate::{prelude::*, KIteratorOutput as Output, KotoFileSystem, Ptr, Result};
+use glob::{MatchOptions, Pattern};
+use std::path::{Component, Path, PathBuf};
+
+/// A walker that visits the entries of a directory in depth-first order
+///
+/// Entries are visited in sorted order, with the entries of a subdirectory visited directly after
+/// the subdirectory itself. Symlinks to directories are output but not followed.
+#[derive(Clone)]
+struct DirWalker {
+    file_system: Ptr<dyn KotoFileSystem>,
+    // The directory that the walk starts from, its entries are read when the walker is first
+    // advanced.
+    root: Option<PathBuf>,
+    // Entries that are yet to be visited, stored in reverse order so that the next entry can be
+    // popped from the end.
+    pending: Vec<PendingEntry>,
+    max_depth: usize,
+}
+
+#[derive(Clone)]
+struct PendingEntry {
+    path: PathBuf,
+    depth: usize,
+    is_dir: bool,
+}
+
+impl DirWalker {
+    fn new(file_system: Ptr<dyn KotoFileSystem>, path: &Path, max_depth: usize) -> Self {
+        Self {
+            file_system,
+            root: (max_depth > 0).then(|| path.to_path_buf()),
+            pending: Vec::new(),
+            max_depth,
+        }
+    }
+
+    fn push_dir_entries(&mut self, path: &Path, depth: usize) -> Result<()> {
+        let mut entries = self
+            .file_system
+            .read_dir(path)?
+            .into_iter()
+            .map(|entry| PendingEntry {
+                path: entry.path,
+                depth,
+                is_dir: entry.is_dir,
+            })
+            .collect::<Vec<_>>();
+
+        entries.sort_unstable_by(|a, b| b.path.cmp(&a.path));
+        self.pending.extend(entries);
+
+        Ok(())
+    }
+}
+
+impl Iterator for DirWalker {
+    type Item = Result<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(root) = self.root.take() {
+            if let Err(error) = self.push_dir_entries(&root, 1) {
+                return Some(Err(error));
+            }
+        }
+
+        let entry = self.pending.pop()?;
+
+        if entry.is_dir && entry.depth < self.max_depth {
+            if let Err(error) = self.push_dir_entries(&entry.path, entry.depth + 1) {
+                return Some(Err(error));
+            }
+        }
+
+        Some(Ok(entry.path))
+    }
+}
+
+fn path_to_value(path: &Path) -> KValue {
+    path.to_string_lossy().to_string().into()
+}
+
+/// An iterator that outputs the paths of the entries in a directory
+///
+/// Used by `io.read_dir` and `io.walk_dir`.
+#[derive(Clone)]
+pub struct DirIterator(DirWalker);
+
+impl DirIterator {
+    /// Returns an iterator over the entries of the directory at the given path
+    pub fn read_dir(file_system: Ptr<dyn KotoFileSystem>, path: &Path) -> Result<Self> {
+        Self::new(file_system, path, 1)
+    }
+
+    /// Returns an iterator that recursively walks the contents of the directory at the given path
+    pub fn walk_dir(file_system: Ptr<dyn KotoFileSystem>, path: &Path) -> Result<Self> {
+        Self::new(file_system, path, usize::MAX)
+    }
+
+    // The directory's entries are read lazily, but a missing directory is reported immediately
+    fn new(file_system: Ptr<dyn KotoFileSystem>, path: &Path, max_depth: usize) -> Result<Self> {
+        if !file_system.metadata(path)?.is_dir {
+            return runtime_error!("'{}' is not a directory", path.to_string_lossy());
+        }
+        Ok(Self(DirWalker::new(file_system, path, max_depth)))
+    }
+}
+
+impl KotoIterator for DirIterator {
+    fn make_copy(&self) -> Result<KIterator> {
+        Ok(KIterator::new(self.clone()))
+    }
+}
+
+impl Iterator for DirIterator {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|entry| match entry {
+            Ok(path) => Output::Value(path_to_value(&path)),
+            Err(error) => Output::Error(error),
+        })
+    }
+}
+
+/// An iterator that outputs the paths that match a glob pattern
+///
+/// Used by `io.glob`.
+#[derive(Clone)]
+pub struct Glob {
+    file_system: Ptr<dyn KotoFileSystem>,
+    // The literal start of the pattern, from which the walk begins
+    base: PathBuf,
+    // The remainder of the pattern, to be matched against the walked paths
+    pattern: Pattern,
+    // The walker, None if the pattern contains no wildcards
+    walker: Option<DirWalker>,
+}
+
+// Wildcards don't match path separators, so that `*` only matches within a single name
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+impl Glob {
+    /// Returns an iterator over the paths that match the given glob pattern
+    ///
+    /// Relative patterns are matched against paths relative to the current directory.
+    pub fn new(file_system: Ptr<dyn KotoFileSystem>, pattern: &str) -> Result<Self> {
+        let mut base = PathBuf::new();
+        let mut components = Vec::new();
+
+        for component in Path::new(pattern).components() {
+            match component {
+                Component::Normal(name) if !components.is_empty() || is_wildcard(name) => {
+                    components.push(name.to_string_lossy())
+                }
+                _ if !components.is_empty() => {
+                    return runtime_error!("Invalid glob pattern '{pattern}'")
+                }
+                other => base.push(other),
+            }
+        }
+
+        let walker = if components.is_empty() {
+            None
+        } else {
+            let max_depth = if components.iter().any(|component| component == "**") {
+                usize::MAX
+            } else {
+                components.len()
+            };
+            let walk_root = if base.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                base.as_path()
+            };
+            // A missing base directory produces no matches rather than an error
+            let is_dir = file_system
+                .metadata(walk_root)
+                .is_ok_and(|metadata| metadata.is_dir);
+            let max_depth = if is_dir { max_depth } else { 0 };
+            Some(DirWalker::new(file_system.clone(), walk_root, max_depth))
+        };
+
+        let pattern = match Pattern::new(&components.join("/")) {
+            Ok(pattern) => pattern,
+            Err(error) => return runtime_error!("Invalid glob pattern '{pattern}': {error}"),
+        };
+
+        Ok(Self {
+            file_system,
+            base,
+            pattern,
+            walker,
+        })
+    }
+
+    /// Returns the directory from which the search for matching paths begins
+    pub fn root(&self) -> &Path {
+        if self.base.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            &self.base
+        }
+    }
+}
+
+impl KotoIterator for Glob {
+    fn make_copy(&self) -> Result<KIterator> {
+        Ok(KIterator::new(self.clone()))
+    }
+}
+
+impl Iterator for Glob {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(walker) = self.walker.as_mut() else {
+            // The pattern doesn't contain wildcards, so the base path is output if it exists
+            let path = std::mem::take(&mut self.base);
+            let exists =
+                !path.as_os_str().is_empty() && self.file_system.exists(&path).unwrap_or(false);
+            return exists.then(|| Output::Value(path_to_value(&path)));
+        };
+
+        for entry in walker.by_ref() {
+            match entry {
+                Ok(path) => {
+                    if self.base.as_os_str().is_empty() {
+                        // The walk started in the current directory, so the leading `./` is
+                        // removed from the output.
+                        let relative = path.strip_prefix(".").unwrap_or(&path);
+                        if self.pattern.matches_path_with(relative, MATCH_OPTIONS) {
+                            return Some(Output::Value(path_to_value(relative)));
+                        }
+                    } else {
+                        let relative = path.strip_prefix(&self.base).unwrap_or(&path);
+                        if self.pattern.matches_path_with(relative, MATCH_OPTIONS) {
+                            return Some(Output::Value(path_to_value(&path)));
+                        }
+                    }
+                }
+                Err(error) => return Some(Output::Error(error)),
+            }
+        }
+
+        None
+    }
+}
+
+fn is_wildcard(name: &std::ffi::OsStr) -> bool {
+    name.to_string_lossy().contains(['*', '?', '['])
+}
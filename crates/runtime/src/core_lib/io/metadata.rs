@@ -0,0 +1,63 @@
+use crate::{core_lib::os::DateTime, derive::*, prelude::*, FileMetadata, Ptr, Result};
+use std::path::PathBuf;
+
+/// The Metadata type returned by `io.metadata`
+#[derive(Clone, KotoCopy, KotoType)]
+pub struct Metadata {
+    path: PathBuf,
+    metadata: Ptr<FileMetadata>,
+}
+
+#[koto_impl(runtime = crate)]
+impl Metadata {
+    /// Initializes a Metadata object with the metadata for the given path
+    pub fn new(path: PathBuf, metadata: FileMetadata) -> Self {
+        Self {
+            path,
+            metadata: metadata.into(),
+        }
+    }
+
+    #[koto_method]
+    fn is_dir(&self) -> KValue {
+        self.metadata.is_dir.into()
+    }
+
+    #[koto_method]
+    fn is_file(&self) -> KValue {
+        self.metadata.is_file.into()
+    }
+
+    #[koto_method]
+    fn modified(&self) -> Result<KValue> {
+        match self.metadata.modified {
+            Some(time) => Ok(DateTime::with_system_time(time)),
+            None => runtime_error!(
+                "The modified time is unavailable for '{}'",
+                self.path.to_string_lossy()
+            ),
+        }
+    }
+
+    #[koto_method]
+    fn size(&self) -> KValue {
+        self.metadata.size.into()
+    }
+}
+
+impl KotoObject for Metadata {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(format!(
+            "{}({})",
+            Self::type_static(),
+            self.path.to_string_lossy()
+        ));
+        Ok(())
+    }
+}
+
+impl From<Metadata> for KValue {
+    fn from(metadata: Metadata) -> Self {
+        KObject::from(metadata).into()
+    }
+}
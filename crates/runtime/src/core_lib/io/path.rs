@@ -0,0 +1,189 @@
+use crate::{derive::*, prelude::*, Ptr, Result};
+use std::path::{Component, PathBuf};
+
+/// The Path type used in the io module
+#[derive(Clone, KotoCopy, KotoType)]
+pub struct Path(Ptr<PathBuf>);
+
+#[koto_impl(runtime = crate)]
+impl Path {
+    /// Initializes a Path object with the given path
+    pub fn new(path: PathBuf) -> Self {
+        Self(path.into())
+    }
+
+    /// Returns a reference to the object's path
+    pub fn path(&self) -> &std::path::Path {
+        &self.0
+    }
+
+    #[koto_method]
+    fn canonicalize(ctx: MethodContext<Self>) -> Result<KValue> {
+        let path = ctx.instance()?.0.clone();
+        let file_system = ctx.vm.file_system();
+        ctx.vm
+            .settings()
+            .permissions
+            .check_read(&path, &**file_system)?;
+        let result = file_system.canonicalize(&path)?;
+        Ok(Self::new(result).into())
+    }
+
+    #[koto_method]
+    fn extension(&self) -> KValue {
+        optional_str(self.0.extension())
+    }
+
+    #[koto_method]
+    fn file_name(&self) -> KValue {
+        optional_str(self.0.file_name())
+    }
+
+    #[koto_method]
+    fn is_absolute(&self) -> KValue {
+        self.0.is_absolute().into()
+    }
+
+    #[koto_method]
+    fn join(ctx: MethodContext<Self>) -> Result<KValue> {
+        let mut result = ctx.instance()?.0.to_path_buf();
+        extend_path(&mut result, ctx.args, ctx.vm)?;
+        Ok(Self::new(result).into())
+    }
+
+    #[koto_method]
+    fn normalize(&self) -> KValue {
+        let mut result = normalize_path(&self.0);
+        if result.as_os_str().is_empty() {
+            result.push(Component::CurDir);
+        }
+        Self::new(result).into()
+    }
+
+    #[koto_method]
+    fn parent(&self) -> KValue {
+        match self.0.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                Self::new(parent.to_path_buf()).into()
+            }
+            _ => KValue::Null,
+        }
+    }
+
+    #[koto_method]
+    fn relative_to(&self, args: &[KValue]) -> Result<KValue> {
+        match args {
+            [base] => {
+                let Some(base) = path_from_value(base) else {
+                    return unexpected_args("|Path|, or |String|", args);
+                };
+                match self.0.strip_prefix(base) {
+                    Ok(relative) => Ok(Self::new(relative.to_path_buf()).into()),
+                    Err(_) => Ok(KValue::Null),
+                }
+            }
+            unexpected => unexpected_args("|Path|, or |String|", unexpected),
+        }
+    }
+
+    #[koto_method]
+    fn stem(&self) -> KValue {
+        optional_str(self.0.file_stem())
+    }
+}
+
+impl KotoObject for Path {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        // Paths are shown with their type when contained in other values, to distinguish them
+        // from strings.
+        if ctx.is_contained() {
+            ctx.append(format!(
+                "{}({})",
+                Self::type_static(),
+                self.0.to_string_lossy()
+            ));
+        } else {
+            ctx.append(self.0.to_string_lossy().as_ref());
+        }
+        Ok(())
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        match path_from_value(rhs) {
+            Some(rhs) => Ok(*self.0 == rhs),
+            None => Ok(false),
+        }
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+
+    fn equal_rhs(&self, lhs: &KValue) -> Result<bool> {
+        self.equal(lhs)
+    }
+}
+
+impl From<Path> for KValue {
+    fn from(path: Path) -> Self {
+        KObject::from(path).into()
+    }
+}
+
+/// Returns the path contained in a String or Path value
+pub fn path_from_value(value: &KValue) -> Option<PathBuf> {
+    match value {
+        KValue::Str(s) => Some(PathBuf::from(s.as_str())),
+        KValue::Object(o) => o.cast::<Path>().ok().map(|path| PathBuf::clone(&path.0)),
+        _ => None,
+    }
+}
+
+/// Extends a path with the given nodes
+///
+/// Nodes that aren't Strings or Paths are formatted with `@display`.
+pub(crate) fn extend_path(path: &mut PathBuf, nodes: &[KValue], vm: &KotoVm) -> Result<()> {
+    for node in nodes {
+        match path_from_value(node) {
+            Some(node) => path.push(node),
+            None => {
+                let mut display_context = DisplayContext::with_vm(vm);
+                node.display(&mut display_context)?;
+                path.push(display_context.result());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Removes `.` components from the path, and applies `..` components to their preceding components
+///
+/// The filesystem isn't accessed, so symlinks aren't resolved. An empty path is returned if all of
+/// the path's components cancel out.
+pub(crate) fn normalize_path(path: &std::path::Path) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                // The root's parent is the root
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => result.push(component),
+            },
+            _ => result.push(component),
+        }
+    }
+
+    result
+}
+
+fn optional_str(s: Option<&std::ffi::OsStr>) -> KValue {
+    match s {
+        Some(s) => s.to_string_lossy().as_ref().into(),
+        None => KValue::Null,
+    }
+}
@@ -820,6 +820,19 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("to_set", |ctx| {
+        let expected_error = "|Iterable|";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let result = super::set::collect_values(ctx.vm, iterable)?;
+                Ok(KSet::from(result).into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
     result.add_fn("to_string", |ctx| {
         let expected_error = "|Iterable|";
 
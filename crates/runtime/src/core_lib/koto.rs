@@ -23,6 +23,7 @@ pub fn make_module() -> KMap {
             );
             Ok(result.into())
         }
+        [KValue::Set(s)] => Ok(KSet::with_data(s.data().clone()).into()),
         [KValue::Object(o)] => o.try_borrow().map(|o| o.copy().into()),
         [other] => Ok(other.clone()),
         unexpected => unexpected_args("|Any|", unexpected),
@@ -38,6 +39,14 @@ pub fn make_module() -> KMap {
         unexpected => unexpected_args("||", unexpected),
     });
 
+    result.add_fn("freeze", |ctx| match ctx.args() {
+        [value] => {
+            value.freeze();
+            Ok(value.clone())
+        }
+        unexpected => unexpected_args("|Any|", unexpected),
+    });
+
     result.add_fn("hash", |ctx| match ctx.args() {
         [value] => match ValueKey::try_from(value.clone()) {
             Ok(key) => {
@@ -53,6 +62,11 @@ pub fn make_module() -> KMap {
     result.insert("script_dir", KValue::Null);
     result.insert("script_path", KValue::Null);
 
+    result.add_fn("is_frozen", |ctx| match ctx.args() {
+        [value] => Ok(value.is_frozen().into()),
+        unexpected => unexpected_args("|Any|", unexpected),
+    });
+
     result.add_fn("size", |ctx| match ctx.args() {
         [value] => ctx.vm.run_unary_op(UnaryOp::Size, value.clone()),
         unexpected => unexpected_args("|Any|", unexpected),
@@ -16,7 +16,7 @@ pub fn make_module() -> KMap {
 
         match ctx.instance_and_args(is_list, expected_error)? {
             (KValue::List(l), []) => {
-                l.data_mut().clear();
+                l.checked_data_mut()?.clear();
                 Ok(KValue::List(l.clone()))
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
@@ -57,11 +57,11 @@ pub fn make_module() -> KMap {
 
         match ctx.instance_and_args(is_list, expected_error)? {
             (KValue::List(l), [KValue::List(other)]) => {
-                l.data_mut().extend(other.data().iter().cloned());
+                l.checked_data_mut()?.extend(other.data().iter().cloned());
                 Ok(KValue::List(l.clone()))
             }
             (KValue::List(l), [KValue::Tuple(other)]) => {
-                l.data_mut().extend(other.iter().cloned());
+                l.checked_data_mut()?.extend(other.iter().cloned());
                 Ok(KValue::List(l.clone()))
             }
             (KValue::List(l), [iterable]) if iterable.is_iterable() => {
@@ -70,7 +70,7 @@ pub fn make_module() -> KMap {
                 let iterator = ctx.vm.make_iterator(iterable)?;
 
                 {
-                    let mut list_data = l.data_mut();
+                    let mut list_data = l.checked_data_mut()?;
                     let (size_hint, _) = iterator.size_hint();
                     list_data.reserve(size_hint);
 
@@ -94,7 +94,7 @@ pub fn make_module() -> KMap {
 
         match ctx.instance_and_args(is_list, expected_error)? {
             (KValue::List(l), [value]) => {
-                for v in l.data_mut().iter_mut() {
+                for v in l.checked_data_mut()?.iter_mut() {
                     *v = value.clone();
                 }
                 Ok(KValue::List(l.clone()))
@@ -149,7 +149,7 @@ pub fn make_module() -> KMap {
                     return runtime_error!("Index out of bounds");
                 }
 
-                l.data_mut().insert(index, value.clone());
+                l.checked_data_mut()?.insert(index, value.clone());
                 Ok(KValue::List(l.clone()))
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
@@ -181,7 +181,7 @@ pub fn make_module() -> KMap {
         let expected_error = "|List|";
 
         match ctx.instance_and_args(is_list, expected_error)? {
-            (KValue::List(l), []) => match l.data_mut().pop() {
+            (KValue::List(l), []) => match l.checked_data_mut()?.pop() {
                 Some(value) => Ok(value),
                 None => Ok(KValue::Null),
             },
@@ -194,7 +194,7 @@ pub fn make_module() -> KMap {
 
         match ctx.instance_and_args(is_list, expected_error)? {
             (KValue::List(l), [value]) => {
-                l.data_mut().push(value.clone());
+                l.checked_data_mut()?.push(value.clone());
                 Ok(KValue::List(l.clone()))
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
@@ -211,7 +211,7 @@ pub fn make_module() -> KMap {
                     return runtime_error!("Index out of bounds");
                 }
 
-                Ok(l.data_mut().remove(index))
+                Ok(l.checked_data_mut()?.remove(index))
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
         }
@@ -225,11 +225,11 @@ pub fn make_module() -> KMap {
                 runtime_error!("Expected a non-negative size")
             }
             (KValue::List(l), [KValue::Number(n)]) => {
-                l.data_mut().resize(n.into(), KValue::Null);
+                l.checked_data_mut()?.resize(n.into(), KValue::Null);
                 Ok(KValue::List(l.clone()))
             }
             (KValue::List(l), [KValue::Number(n), value]) => {
-                l.data_mut().resize(n.into(), value.clone());
+                l.checked_data_mut()?.resize(n.into(), value.clone());
                 Ok(KValue::List(l.clone()))
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
@@ -251,12 +251,12 @@ pub fn make_module() -> KMap {
                 let f = f.clone();
 
                 match len.cmp(&new_size) {
-                    Ordering::Greater => l.data_mut().truncate(new_size),
+                    Ordering::Greater => l.checked_data_mut()?.truncate(new_size),
                     Ordering::Less => {
-                        l.data_mut().reserve(new_size);
+                        l.checked_data_mut()?.reserve(new_size);
                         for _ in 0..new_size - len {
                             let new_value = ctx.vm.call_function(f.clone(), &[])?;
-                            l.data_mut().push(new_value);
+                            l.checked_data_mut()?.push(new_value);
                         }
                     }
                     Ordering::Equal => {}
@@ -283,7 +283,7 @@ pub fn make_module() -> KMap {
                         match ctx.vm.call_function(f.clone(), value.clone()) {
                             Ok(KValue::Bool(result)) => {
                                 if result {
-                                    l.data_mut()[write_index] = value;
+                                    l.checked_data_mut()?[write_index] = value;
                                     write_index += 1;
                                 }
                             }
@@ -296,7 +296,7 @@ pub fn make_module() -> KMap {
                             Err(error) => return Err(error),
                         }
                     }
-                    l.data_mut().resize(write_index, KValue::Null);
+                    l.checked_data_mut()?.resize(write_index, KValue::Null);
                     l
                 }
                 (KValue::List(l), [value]) => {
@@ -304,7 +304,7 @@ pub fn make_module() -> KMap {
                     let value = value.clone();
 
                     let mut error = None;
-                    l.data_mut().retain(|x| {
+                    l.checked_data_mut()?.retain(|x| {
                         if error.is_some() {
                             return true;
                         }
@@ -346,7 +346,7 @@ pub fn make_module() -> KMap {
 
         match ctx.instance_and_args(is_list, expected_error)? {
             (KValue::List(l), []) => {
-                l.data_mut().reverse();
+                l.checked_data_mut()?.reverse();
                 Ok(KValue::List(l.clone()))
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
@@ -359,7 +359,7 @@ pub fn make_module() -> KMap {
         match ctx.instance_and_args(is_list, expected_error)? {
             (KValue::List(l), []) => {
                 let l = l.clone();
-                let mut data = l.data_mut();
+                let mut data = l.checked_data_mut()?;
                 sort_values(ctx.vm, &mut data)?;
                 Ok(KValue::List(l.clone()))
             }
@@ -369,7 +369,7 @@ pub fn make_module() -> KMap {
                 let sorted = sort_by_key(ctx.vm, l.data().as_ref(), f.clone())?;
 
                 for (target_value, (_key, source_value)) in
-                    l.data_mut().iter_mut().zip(sorted.into_iter())
+                    l.checked_data_mut()?.iter_mut().zip(sorted.into_iter())
                 {
                     *target_value = source_value;
                 }
@@ -385,7 +385,10 @@ pub fn make_module() -> KMap {
 
         match ctx.instance_and_args(is_list, expected_error)? {
             (KValue::List(a), [KValue::List(b)]) => {
-                std::mem::swap(a.data_mut().deref_mut(), b.data_mut().deref_mut());
+                std::mem::swap(
+                    a.checked_data_mut()?.deref_mut(),
+                    b.checked_data_mut()?.deref_mut(),
+                );
                 Ok(KValue::Null)
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
@@ -409,7 +412,7 @@ pub fn make_module() -> KMap {
                 let l = l.clone();
                 let f = f.clone();
 
-                for value in l.data_mut().iter_mut() {
+                for value in l.checked_data_mut()?.iter_mut() {
                     *value = match ctx.vm.call_function(f.clone(), value.clone()) {
                         Ok(result) => result,
                         Err(error) => return Err(error),
@@ -0,0 +1,72 @@
+//! The `log` core library module
+
+use crate::{prelude::*, LogLevel, LogRecord, Result};
+
+/// Initializes the `log` core library module
+pub fn make_module() -> KMap {
+    let result = KMap::with_type("core.log");
+
+    result.add_fn("debug", |ctx| log(ctx, LogLevel::Debug));
+    result.add_fn("error", |ctx| log(ctx, LogLevel::Error));
+    result.add_fn("info", |ctx| log(ctx, LogLevel::Info));
+    result.add_fn("warn", |ctx| log(ctx, LogLevel::Warn));
+
+    result
+}
+
+fn log(ctx: &mut CallContext, level: LogLevel) -> Result<KValue> {
+    let (message, fields) = match ctx.args() {
+        [message] => (message.clone(), None),
+        [message, KValue::Map(fields)] => (message.clone(), Some(fields.clone())),
+        unexpected => return unexpected_args("|Any|, or |Any, Map|", unexpected),
+    };
+
+    let vm = &mut ctx.vm;
+
+    if let Some(sink) = vm.log_sink() {
+        if !sink.is_enabled(level) {
+            return Ok(KValue::Null);
+        }
+    }
+
+    let message = match &message {
+        KValue::Str(s) => s.to_string(),
+        other => vm.value_to_string(other)?,
+    };
+
+    let fields = match fields {
+        Some(fields) => {
+            // The entries are cloned so that the map isn't borrowed while values are displayed
+            let entries = fields
+                .data()
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>();
+
+            entries
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        KValue::Str(s) => s.to_string(),
+                        other => vm.value_to_string(other)?,
+                    };
+                    Ok((key.to_string(), value))
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        None => Vec::new(),
+    };
+
+    let record = LogRecord {
+        level,
+        message,
+        fields,
+    };
+
+    match vm.log_sink() {
+        Some(sink) => sink.log(&record)?,
+        None => vm.stderr().write_line(&record.to_string())?,
+    }
+
+    Ok(KValue::Null)
+}
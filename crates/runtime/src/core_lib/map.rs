@@ -13,7 +13,7 @@ pub fn make_module() -> KMap {
 
         match map_instance_and_args(ctx, expected_error)? {
             (KValue::Map(m), []) => {
-                m.data_mut().clear();
+                m.checked_data_mut()?.clear();
                 Ok(KValue::Map(m.clone()))
             }
             (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
@@ -37,7 +37,7 @@ pub fn make_module() -> KMap {
 
         match map_instance_and_args(ctx, expected_error)? {
             (KValue::Map(m), [KValue::Map(other)]) => {
-                m.data_mut().extend(
+                m.checked_data_mut()?.extend(
                     other
                         .data()
                         .iter()
@@ -51,7 +51,7 @@ pub fn make_module() -> KMap {
                 let iterator = ctx.vm.make_iterator(iterable)?;
 
                 {
-                    let mut map_data = m.data_mut();
+                    let mut map_data = m.checked_data_mut()?;
                     let (size_hint, _) = iterator.size_hint();
                     map_data.reserve(size_hint);
 
@@ -145,7 +145,7 @@ pub fn make_module() -> KMap {
 
         match map_instance_and_args(ctx, expected_error)? {
             (KValue::Map(m), [key]) => match m
-                .data_mut()
+                .checked_data_mut()?
                 .insert(ValueKey::try_from(key.clone())?, KValue::Null)
             {
                 Some(old_value) => Ok(old_value),
@@ -153,7 +153,7 @@ pub fn make_module() -> KMap {
             },
             (KValue::Map(m), [key, value]) => {
                 match m
-                    .data_mut()
+                    .checked_data_mut()?
                     .insert(ValueKey::try_from(key.clone())?, value.clone())
                 {
                     Some(old_value) => Ok(old_value),
@@ -190,7 +190,10 @@ pub fn make_module() -> KMap {
 
         match map_instance_and_args(ctx, expected_error)? {
             (KValue::Map(m), [key]) => {
-                match m.data_mut().shift_remove(&ValueKey::try_from(key.clone())?) {
+                match m
+                    .checked_data_mut()?
+                    .shift_remove(&ValueKey::try_from(key.clone())?)
+                {
                     Some(old_value) => Ok(old_value),
                     None => Ok(KValue::Null),
                 }
@@ -205,7 +208,7 @@ pub fn make_module() -> KMap {
         match map_instance_and_args(ctx, expected_error)? {
             (KValue::Map(m), []) => {
                 let mut error = None;
-                m.data_mut().sort_by(|key_a, _, key_b, _| {
+                m.checked_data_mut()?.sort_by(|key_a, _, key_b, _| {
                     if error.is_some() {
                         return Ordering::Equal;
                     }
@@ -243,40 +246,41 @@ pub fn make_module() -> KMap {
                 };
 
                 let mut cache = ValueMap::with_capacity(m.len());
-                m.data_mut().sort_by(|key_a, value_a, key_b, value_b| {
-                    if error.is_some() {
-                        return Ordering::Equal;
-                    }
+                m.checked_data_mut()?
+                    .sort_by(|key_a, value_a, key_b, value_b| {
+                        if error.is_some() {
+                            return Ordering::Equal;
+                        }
 
-                    let value_a = match cache.get(key_a) {
-                        Some(value) => value.clone(),
-                        None => match get_sort_key(ctx.vm, &mut cache, key_a, value_a) {
-                            Ok(val) => val,
-                            Err(e) => {
-                                error.get_or_insert(Err(e));
-                                KValue::Null
-                            }
-                        },
-                    };
-                    let value_b = match cache.get(key_b) {
-                        Some(value) => value.clone(),
-                        None => match get_sort_key(ctx.vm, &mut cache, key_b, value_b) {
-                            Ok(val) => val,
+                        let value_a = match cache.get(key_a) {
+                            Some(value) => value.clone(),
+                            None => match get_sort_key(ctx.vm, &mut cache, key_a, value_a) {
+                                Ok(val) => val,
+                                Err(e) => {
+                                    error.get_or_insert(Err(e));
+                                    KValue::Null
+                                }
+                            },
+                        };
+                        let value_b = match cache.get(key_b) {
+                            Some(value) => value.clone(),
+                            None => match get_sort_key(ctx.vm, &mut cache, key_b, value_b) {
+                                Ok(val) => val,
+                                Err(e) => {
+                                    error.get_or_insert(Err(e));
+                                    KValue::Null
+                                }
+                            },
+                        };
+
+                        match compare_values(ctx.vm, &value_a, &value_b) {
+                            Ok(ordering) => ordering,
                             Err(e) => {
                                 error.get_or_insert(Err(e));
-                                KValue::Null
+                                Ordering::Equal
                             }
-                        },
-                    };
-
-                    match compare_values(ctx.vm, &value_a, &value_b) {
-                        Ok(ordering) => ordering,
-                        Err(e) => {
-                            error.get_or_insert(Err(e));
-                            Ordering::Equal
                         }
-                    }
-                });
+                    });
 
                 if let Some(error) = error {
                     error
@@ -346,12 +350,12 @@ fn do_map_update(
     vm: &mut KotoVm,
 ) -> Result<KValue> {
     if !map.data().contains_key(&key) {
-        map.data_mut().insert(key.clone(), default);
+        map.checked_data_mut()?.insert(key.clone(), default);
     }
     let value = map.get(&key).unwrap();
     match vm.call_function(f, value) {
         Ok(new_value) => {
-            map.data_mut().insert(key, new_value.clone());
+            map.checked_data_mut()?.insert(key, new_value.clone());
             Ok(new_value)
         }
         Err(error) => Err(error),
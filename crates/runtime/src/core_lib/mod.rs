@@ -1,13 +1,16 @@
 //! The core library for the Koto language
 
+pub mod bytes;
 pub mod io;
 pub mod iterator;
 pub mod koto;
 pub mod list;
+pub mod log;
 pub mod map;
 pub mod number;
 pub mod os;
 pub mod range;
+pub mod set;
 pub mod string;
 pub mod test;
 pub mod tuple;
@@ -18,14 +21,17 @@ use crate::KMap;
 #[derive(Clone)]
 #[allow(missing_docs)]
 pub struct CoreLib {
+    pub bytes: KMap,
     pub io: KMap,
     pub iterator: KMap,
     pub koto: KMap,
     pub list: KMap,
+    pub log: KMap,
     pub map: KMap,
     pub os: KMap,
     pub number: KMap,
     pub range: KMap,
+    pub set: KMap,
     pub string: KMap,
     pub test: KMap,
     pub tuple: KMap,
@@ -35,14 +41,17 @@ impl CoreLib {
     /// The core lib items made available in each Koto script
     pub fn prelude(&self) -> KMap {
         let result = KMap::default();
+        result.insert("bytes", self.bytes.clone());
         result.insert("io", self.io.clone());
         result.insert("iterator", self.iterator.clone());
         result.insert("koto", self.koto.clone());
         result.insert("list", self.list.clone());
+        result.insert("log", self.log.clone());
         result.insert("map", self.map.clone());
         result.insert("os", self.os.clone());
         result.insert("number", self.number.clone());
         result.insert("range", self.range.clone());
+        result.insert("set", self.set.clone());
         result.insert("string", self.string.clone());
         result.insert("test", self.test.clone());
         result.insert("tuple", self.tuple.clone());
@@ -69,14 +78,17 @@ impl CoreLib {
 impl Default for CoreLib {
     fn default() -> Self {
         Self {
+            bytes: bytes::make_module(),
             io: io::make_module(),
             iterator: iterator::make_module(),
             koto: koto::make_module(),
             list: list::make_module(),
+            log: log::make_module(),
             map: map::make_module(),
             os: os::make_module(),
             number: number::make_module(),
             range: range::make_module(),
+            set: set::make_module(),
             string: string::make_module(),
             test: test::make_module(),
             tuple: tuple::make_module(),
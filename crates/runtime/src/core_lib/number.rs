@@ -1,5 +1,11 @@
 //! The `number` core library module
 
+mod big_int;
+mod decimal;
+
+pub use big_int::BigInt;
+pub use decimal::Decimal;
+
 use crate::prelude::*;
 
 /// Initializes the `number` core library module
@@ -76,6 +82,24 @@ pub fn make_module() -> KMap {
         };
     }
 
+    macro_rules! number_pair_fn {
+        ($name:ident, |$a:ident, $b:ident| $body:expr) => {
+            result.add_fn(stringify!($name), |ctx| {
+                let expected_error = "|Number, Number|";
+
+                match ctx.instance_and_args(is_number, expected_error)? {
+                    (Number($a), [Number($b)]) => {
+                        let ($a, $b) = (*$a, *$b);
+                        Ok($body)
+                    }
+                    (instance, args) => {
+                        unexpected_args_after_instance(expected_error, instance, args)
+                    }
+                }
+            })
+        };
+    }
+
     number_fn!(abs);
     number_f64_fn!(acos);
     number_f64_fn!(acosh);
@@ -94,8 +118,30 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("big", |ctx| match ctx.args() {
+        [KValue::Str(s)] => BigInt::parse(s).map(KValue::from),
+        [value] => match BigInt::try_from_value(value)? {
+            Some(result) => Ok(result.into()),
+            None => unexpected_args("|Number|, or |String|", ctx.args()),
+        },
+        unexpected => unexpected_args("|Number|, or |String|", unexpected),
+    });
+
     number_fn!(ceil);
 
+    number_pair_fn!(checked_add, |a, b| a
+        .checked_add(b)
+        .map_or(KValue::Null, Number));
+    number_pair_fn!(checked_mul, |a, b| a
+        .checked_mul(b)
+        .map_or(KValue::Null, Number));
+    number_pair_fn!(checked_pow, |a, b| a
+        .checked_pow(b)
+        .map_or(KValue::Null, Number));
+    number_pair_fn!(checked_sub, |a, b| a
+        .checked_sub(b)
+        .map_or(KValue::Null, Number));
+
     result.add_fn("clamp", |ctx| {
         let expected_error = "|Number, Number, Number|";
 
@@ -107,6 +153,16 @@ pub fn make_module() -> KMap {
 
     number_f64_fn!(cos);
     number_f64_fn!(cosh);
+
+    result.add_fn("decimal", |ctx| match ctx.args() {
+        [KValue::Str(s)] => Decimal::parse(s).map(KValue::from),
+        [value] => match Decimal::try_from_value(value)? {
+            Some(result) => Ok(result.into()),
+            None => unexpected_args("|Number|, or |String|", ctx.args()),
+        },
+        unexpected => unexpected_args("|Number|, or |String|", unexpected),
+    });
+
     number_f64_fn!("degrees", to_degrees);
 
     result.insert("e", std::f64::consts::E);
@@ -192,6 +248,11 @@ pub fn make_module() -> KMap {
     number_f64_fn!(recip);
     number_fn!(round);
 
+    number_pair_fn!(saturating_add, |a, b| Number(a.saturating_add(b)));
+    number_pair_fn!(saturating_mul, |a, b| Number(a.saturating_mul(b)));
+    number_pair_fn!(saturating_pow, |a, b| Number(a.saturating_pow(b)));
+    number_pair_fn!(saturating_sub, |a, b| Number(a.saturating_sub(b)));
+
     bitwise_fn_positive_arg!(shift_left, <<);
     bitwise_fn_positive_arg!(shift_right, >>);
 
@@ -212,6 +273,12 @@ pub fn make_module() -> KMap {
         }
     });
 
+    // Integer arithmetic wraps by default, see the overflow checks in KotoVmSettings
+    number_pair_fn!(wrapping_add, |a, b| Number(a + b));
+    number_pair_fn!(wrapping_mul, |a, b| Number(a * b));
+    number_pair_fn!(wrapping_pow, |a, b| Number(a.pow(b)));
+    number_pair_fn!(wrapping_sub, |a, b| Number(a - b));
+
     bitwise_fn!(xor, ^);
 
     result
@@ -0,0 +1,453 @@
+use super::decimal::Decimal;
+use crate::{derive::*, prelude::*, BinaryOp, ErrorKind, Result};
+use num_bigint::BigInt as Inner;
+use num_traits::{FromPrimitive, Signed, ToPrimitive, Zero};
+use std::{cmp::Ordering, fmt, ops::Deref, str::FromStr};
+
+/// The BigInt type used in the number module
+///
+/// BigInts are arbitrary-precision integers, allowing integer arithmetic without overflow.
+#[derive(Clone, KotoCopy, KotoType)]
+pub struct BigInt(Inner);
+
+#[koto_impl(runtime = crate)]
+impl BigInt {
+    #[koto_method]
+    fn abs(&self) -> KValue {
+        Self(self.0.abs()).into()
+    }
+
+    #[koto_method]
+    fn to_number(&self) -> KValue {
+        match self.0.to_i64() {
+            Some(n) => n.into(),
+            None => self.to_f64().into(),
+        }
+    }
+}
+
+impl BigInt {
+    /// Parses an integer from a string, e.g. `"12345678901234567890"`
+    pub fn parse(s: &str) -> Result<Self> {
+        match Inner::from_str(s) {
+            Ok(n) => Ok(Self(n)),
+            Err(_) => runtime_error!("Failed to parse '{s}' as a BigInt"),
+        }
+    }
+
+    /// Converts a value into a big integer
+    ///
+    /// Decimals are truncated, and an error is returned for numbers with a fractional part.
+    /// `Ok(None)` is returned if the value isn't a number.
+    pub fn try_from_value(value: &KValue) -> Result<Option<Self>> {
+        let result = match value {
+            KValue::Number(KNumber::I64(n)) => Inner::from(*n),
+            KValue::Number(KNumber::F64(n)) => match Inner::from_f64(*n) {
+                Some(result) if n.fract() == 0.0 => result,
+                _ => return runtime_error!("Expected an integer, found '{n}'"),
+            },
+            KValue::Object(o) if o.is_a::<Self>() => o.cast::<Self>()?.0.clone(),
+            KValue::Object(o) if o.is_a::<Decimal>() => {
+                let n = o.cast::<Decimal>()?.trunc();
+                // Decimals have at most 96 bits of precision, so they always fit in an i128
+                Inner::from(n.to_i128().unwrap_or_default())
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(Self(result)))
+    }
+
+    /// Returns the integer as an `i64`, if it's within the `i64` range
+    pub fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    /// Returns the nearest `f64` to the integer
+    ///
+    /// Infinity is returned if the integer is outside of the `f64` range.
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Converts the integer into a [Decimal]
+    ///
+    /// An error is returned if the integer has more digits than a decimal can represent.
+    pub fn to_decimal(&self) -> Result<Decimal> {
+        let result = self
+            .0
+            .to_i128()
+            .and_then(|n| rust_decimal::Decimal::try_from_i128_with_scale(n, 0).ok());
+        match result {
+            Some(result) => Ok(result.into()),
+            None => runtime_error!("'{}' is too large to be converted to a Decimal", self.0),
+        }
+    }
+
+    /// Performs a binary arithmetic operation with another numeric value
+    ///
+    /// Operations with integers produce BigInts, while operations with decimals or numbers with
+    /// fractional parts produce Decimals. Division with `/` produces a Number.
+    ///
+    /// Bitwise operations and shifts are only supported with integers.
+    ///
+    /// If `swapped` is true then the integer is on the RHS of the operation.
+    fn binary_op(&self, other: &KValue, op: BinaryOp, swapped: bool) -> Result<KValue> {
+        match Operand::from_value(other)? {
+            Some(Operand::Int(other)) => {
+                let (a, b) = if swapped {
+                    (&other, &self.0)
+                } else {
+                    (&self.0, &other)
+                };
+                if matches!(op, BinaryOp::Divide) {
+                    if b.is_zero() {
+                        return runtime_error!("Division by zero");
+                    }
+                    let (a, b) = (a.to_f64(), b.to_f64());
+                    Ok((a.unwrap_or(f64::NAN) / b.unwrap_or(f64::NAN)).into())
+                } else {
+                    int_op(a, b, op).map(|result| Self(result).into())
+                }
+            }
+            Some(Operand::Decimal(_)) if is_bitwise_op(op) => {
+                runtime_error!("Expected an integer for '{op}' with a BigInt, found a Decimal")
+            }
+            Some(Operand::Decimal(other)) => {
+                let this = self.to_decimal()?;
+                let result = if swapped {
+                    other.arithmetic_op(&this, op)
+                } else {
+                    this.arithmetic_op(&other, op)
+                };
+                result.map(KValue::from)
+            }
+            None => {
+                let (lhs, rhs) = if swapped {
+                    (other.clone(), self.clone().into())
+                } else {
+                    (self.clone().into(), other.clone())
+                };
+                runtime_error!(ErrorKind::InvalidBinaryOp { lhs, rhs, op })
+            }
+        }
+    }
+
+    fn assign_op(&mut self, rhs: &KValue, op: BinaryOp) -> Result<()> {
+        match Operand::from_value(rhs)? {
+            Some(Operand::Int(rhs)) => {
+                self.0 = int_op(&self.0, &rhs, op)?;
+                Ok(())
+            }
+            Some(Operand::Decimal(_)) => {
+                runtime_error!("Expected an integer for '{op}' with a BigInt, found a Decimal")
+            }
+            None => unexpected_type("a Number, BigInt, or Decimal", rhs),
+        }
+    }
+
+    fn compare(&self, other: &KValue) -> Result<Option<Ordering>> {
+        let result = match Operand::from_value(other)? {
+            Some(Operand::Int(other)) => Some(self.0.cmp(&other)),
+            Some(Operand::Decimal(other)) => Some((*self.to_decimal()?).cmp(&*other)),
+            None => None,
+        };
+        Ok(result)
+    }
+
+    fn compare_or_error(&self, other: &KValue) -> Result<Ordering> {
+        match self.compare(other)? {
+            Some(ordering) => Ok(ordering),
+            None => unexpected_type("a Number, BigInt, or Decimal", other),
+        }
+    }
+}
+
+impl KotoObject for BigInt {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(self.0.to_string());
+        Ok(())
+    }
+
+    fn negate(&self, _vm: &mut KotoVm) -> Result<KValue> {
+        Ok(Self(-&self.0).into())
+    }
+
+    fn add(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Add, false)
+    }
+
+    fn subtract(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Subtract, false)
+    }
+
+    fn multiply(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Multiply, false)
+    }
+
+    fn divide(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Divide, false)
+    }
+
+    fn integer_divide(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::IntegerDivide, false)
+    }
+
+    fn remainder(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Remainder, false)
+    }
+
+    fn power(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Power, false)
+    }
+
+    fn add_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Add, true)
+    }
+
+    fn subtract_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Subtract, true)
+    }
+
+    fn multiply_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Multiply, true)
+    }
+
+    fn divide_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Divide, true)
+    }
+
+    fn integer_divide_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::IntegerDivide, true)
+    }
+
+    fn remainder_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Remainder, true)
+    }
+
+    fn power_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Power, true)
+    }
+
+    fn bitwise_and(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::BitwiseAnd, false)
+    }
+
+    fn bitwise_or(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::BitwiseOr, false)
+    }
+
+    fn bitwise_xor(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::BitwiseXor, false)
+    }
+
+    fn shift_left(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::ShiftLeft, false)
+    }
+
+    fn shift_right(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::ShiftRight, false)
+    }
+
+    fn bitwise_and_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::BitwiseAnd, true)
+    }
+
+    fn bitwise_or_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::BitwiseOr, true)
+    }
+
+    fn bitwise_xor_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::BitwiseXor, true)
+    }
+
+    fn shift_left_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::ShiftLeft, true)
+    }
+
+    fn shift_right_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::ShiftRight, true)
+    }
+
+    fn add_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Add)
+    }
+
+    fn subtract_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Subtract)
+    }
+
+    fn multiply_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Multiply)
+    }
+
+    fn remainder_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Remainder)
+    }
+
+    fn less(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_lt())
+    }
+
+    fn less_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_le())
+    }
+
+    fn greater(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_gt())
+    }
+
+    fn greater_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_ge())
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)? == Some(Ordering::Equal))
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+
+    fn equal_rhs(&self, lhs: &KValue) -> Result<bool> {
+        self.equal(lhs)
+    }
+}
+
+// A numeric operand in an operation with a BigInt
+enum Operand {
+    Int(Inner),
+    Decimal(Decimal),
+}
+
+impl Operand {
+    // Numbers with fractional parts are converted into Decimals
+    fn from_value(value: &KValue) -> Result<Option<Self>> {
+        let result = match value {
+            KValue::Number(KNumber::I64(n)) => Self::Int(Inner::from(*n)),
+            KValue::Number(KNumber::F64(n)) if n.fract() == 0.0 => match Inner::from_f64(*n) {
+                Some(n) => Self::Int(n),
+                None => return runtime_error!("Unable to convert '{n}' to a BigInt"),
+            },
+            KValue::Object(o) if o.is_a::<BigInt>() => Self::Int(o.cast::<BigInt>()?.0.clone()),
+            _ => match Decimal::try_from_value(value)? {
+                Some(n) => Self::Decimal(n),
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(result))
+    }
+}
+
+// The maximum size in bits of the result of a `**` or `<<` operation
+//
+// Without a limit, large exponents would cause the runtime to hang while using all available
+// memory, e.g. `number.big(10) ** 4000000000`.
+const MAX_RESULT_BITS: u64 = 1 << 20;
+
+fn is_bitwise_op(op: BinaryOp) -> bool {
+    use BinaryOp::*;
+    matches!(
+        op,
+        BitwiseAnd | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight
+    )
+}
+
+// Performs an arithmetic or bitwise operation on two integers, excluding `/` which produces a
+// Number
+fn int_op(a: &Inner, b: &Inner, op: BinaryOp) -> Result<Inner> {
+    use BinaryOp::*;
+
+    let result = match op {
+        Add => a + b,
+        Subtract => a - b,
+        Multiply => a * b,
+        IntegerDivide | Remainder if b.is_zero() => return runtime_error!("Division by zero"),
+        IntegerDivide => {
+            // Round towards negative infinity, matching the behaviour of `//` with Numbers
+            let quotient = a / b;
+            if (a % b).is_zero() || a.is_negative() == b.is_negative() {
+                quotient
+            } else {
+                quotient - 1
+            }
+        }
+        Remainder => a % b,
+        Power => match u32::try_from(b) {
+            Ok(exponent) => {
+                // The result has at least (bits - 1) * exponent bits,
+                // which is zero for 0, 1, and -1.
+                let result_bits = a.bits().saturating_sub(1) * u64::from(exponent);
+                if result_bits > MAX_RESULT_BITS {
+                    return runtime_error!(
+                        "The result of raising a BigInt to the power of '{exponent}' \
+                         exceeds the maximum size of {MAX_RESULT_BITS} bits"
+                    );
+                }
+                a.pow(exponent)
+            }
+            Err(_) => {
+                return runtime_error!(
+                    "BigInt exponents must be non-negative integers, found '{b}'"
+                )
+            }
+        },
+        BitwiseAnd => a & b,
+        BitwiseOr => a | b,
+        BitwiseXor => a ^ b,
+        ShiftLeft | ShiftRight if b.is_negative() => {
+            return runtime_error!("Expected a non-negative shift amount, found '{b}'")
+        }
+        ShiftLeft => {
+            let shift = b.to_u64().unwrap_or(u64::MAX);
+            if !a.is_zero() && a.bits().saturating_add(shift) > MAX_RESULT_BITS {
+                return runtime_error!(
+                    "The result of shifting a BigInt left by '{b}' \
+                     exceeds the maximum size of {MAX_RESULT_BITS} bits"
+                );
+            }
+            a << shift
+        }
+        ShiftRight => {
+            let shift = b.to_u64().unwrap_or(u64::MAX);
+            // Shifting by at least the number of bits fills the result with the sign bit
+            if shift >= a.bits() {
+                if a.is_negative() {
+                    Inner::from(-1)
+                } else {
+                    Inner::zero()
+                }
+            } else {
+                a >> shift
+            }
+        }
+        _ => return runtime_error!("Unsupported operation for BigInt: '{op}'"),
+    };
+
+    Ok(result)
+}
+
+impl Deref for BigInt {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Inner> for BigInt {
+    fn from(n: Inner) -> Self {
+        Self(n)
+    }
+}
+
+impl From<BigInt> for KValue {
+    fn from(n: BigInt) -> Self {
+        KObject::from(n).into()
+    }
+}
@@ -0,0 +1,293 @@
+use super::big_int::BigInt;
+use crate::{derive::*, prelude::*, BinaryOp, ErrorKind, Result};
+use rust_decimal::{prelude::ToPrimitive, Decimal as Inner, MathematicalOps, RoundingStrategy};
+use std::{cmp::Ordering, fmt, ops::Deref, str::FromStr};
+
+/// The Decimal type used in the number module
+///
+/// Decimals represent base-10 numbers with up to 28 significant digits exactly,
+/// avoiding the rounding errors that are introduced with floating-point numbers.
+#[derive(Clone, KotoCopy, KotoType)]
+pub struct Decimal(Inner);
+
+#[koto_impl(runtime = crate)]
+impl Decimal {
+    #[koto_method]
+    fn abs(&self) -> KValue {
+        Self(self.0.abs()).into()
+    }
+
+    #[koto_method]
+    fn ceil(&self) -> KValue {
+        Self(self.0.ceil()).into()
+    }
+
+    #[koto_method]
+    fn floor(&self) -> KValue {
+        Self(self.0.floor()).into()
+    }
+
+    #[koto_method]
+    fn round(&self, args: &[KValue]) -> Result<KValue> {
+        let decimal_places = match args {
+            [] => 0,
+            [KValue::Number(n)] if *n >= 0.0 => u32::try_from(i64::from(n)).unwrap_or(u32::MAX),
+            unexpected => return unexpected_args("||, or |Number|", unexpected),
+        };
+        let result = self
+            .0
+            .round_dp_with_strategy(decimal_places, RoundingStrategy::MidpointAwayFromZero);
+        Ok(Self(result).into())
+    }
+
+    #[koto_method]
+    fn to_number(&self) -> KValue {
+        match self.0.is_integer().then(|| self.0.to_i64()).flatten() {
+            Some(n) => n.into(),
+            None => self.0.to_f64().unwrap_or(f64::NAN).into(),
+        }
+    }
+}
+
+impl Decimal {
+    /// Parses a decimal number from a string, e.g. `"123.45"`
+    pub fn parse(s: &str) -> Result<Self> {
+        match Inner::from_str(s).or_else(|_| Inner::from_scientific(s)) {
+            Ok(n) => Ok(Self(n)),
+            Err(_) => runtime_error!("Failed to parse '{s}' as a Decimal"),
+        }
+    }
+
+    /// Converts a value into a decimal number
+    ///
+    /// `Ok(None)` is returned if the value isn't a number.
+    pub fn try_from_value(value: &KValue) -> Result<Option<Self>> {
+        let result = match value {
+            KValue::Number(KNumber::I64(n)) => Inner::from(*n),
+            KValue::Number(KNumber::F64(n)) => match Inner::try_from(*n) {
+                Ok(n) => n,
+                Err(_) => return runtime_error!("Unable to convert '{n}' to a Decimal"),
+            },
+            KValue::Object(o) if o.is_a::<Self>() => o.cast::<Self>()?.0,
+            KValue::Object(o) if o.is_a::<BigInt>() => o.cast::<BigInt>()?.to_decimal()?.0,
+            _ => return Ok(None),
+        };
+        Ok(Some(Self(result)))
+    }
+
+    /// Performs a binary arithmetic operation with another numeric value
+    ///
+    /// If `swapped` is true then the decimal is on the RHS of the operation.
+    fn binary_op(&self, other: &KValue, op: BinaryOp, swapped: bool) -> Result<Self> {
+        let Some(other) = Self::try_from_value(other)? else {
+            let (lhs, rhs) = if swapped {
+                (other.clone(), self.clone().into())
+            } else {
+                (self.clone().into(), other.clone())
+            };
+            return runtime_error!(ErrorKind::InvalidBinaryOp { lhs, rhs, op });
+        };
+
+        if swapped {
+            other.arithmetic_op(self, op)
+        } else {
+            self.arithmetic_op(&other, op)
+        }
+    }
+
+    pub(super) fn arithmetic_op(&self, rhs: &Self, op: BinaryOp) -> Result<Self> {
+        use BinaryOp::*;
+
+        let (a, b) = (self.0, rhs.0);
+        let result = match op {
+            Add => a.checked_add(b),
+            Subtract => a.checked_sub(b),
+            Multiply => a.checked_mul(b),
+            Divide | IntegerDivide | Remainder if b.is_zero() => {
+                return runtime_error!("Division by zero")
+            }
+            // Division can introduce trailing zeros, e.g. `10 / 4` results in `2.50`
+            Divide => a.checked_div(b).map(|n| n.normalize()),
+            IntegerDivide => a.checked_div(b).map(|n| n.floor()),
+            Remainder => a.checked_rem(b),
+            Power => match b.is_integer().then(|| b.to_i64()).flatten() {
+                Some(exponent) => a.checked_powi(exponent),
+                None => return runtime_error!("Decimal exponents must be integers, found '{b}'"),
+            },
+            _ => return runtime_error!("Unsupported operation for Decimal: '{op}'"),
+        };
+
+        match result {
+            Some(result) => Ok(Self(result)),
+            None => runtime_error!("Decimal overflow in operation '{op}'"),
+        }
+    }
+
+    fn assign_op(&mut self, rhs: &KValue, op: BinaryOp) -> Result<()> {
+        *self = self.binary_op(rhs, op, false)?;
+        Ok(())
+    }
+
+    fn compare(&self, other: &KValue) -> Result<Option<Ordering>> {
+        Ok(Self::try_from_value(other)?.map(|other| self.0.cmp(&other.0)))
+    }
+
+    fn compare_or_error(&self, other: &KValue) -> Result<Ordering> {
+        match self.compare(other)? {
+            Some(ordering) => Ok(ordering),
+            None => unexpected_type("a Number, BigInt, or Decimal", other),
+        }
+    }
+}
+
+impl KotoObject for Decimal {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(self.0.to_string());
+        Ok(())
+    }
+
+    fn negate(&self, _vm: &mut KotoVm) -> Result<KValue> {
+        Ok(Self(-self.0).into())
+    }
+
+    fn add(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Add, false).map(KValue::from)
+    }
+
+    fn subtract(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Subtract, false)
+            .map(KValue::from)
+    }
+
+    fn multiply(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Multiply, false)
+            .map(KValue::from)
+    }
+
+    fn divide(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Divide, false)
+            .map(KValue::from)
+    }
+
+    fn integer_divide(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::IntegerDivide, false)
+            .map(KValue::from)
+    }
+
+    fn remainder(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Remainder, false)
+            .map(KValue::from)
+    }
+
+    fn power(&self, rhs: &KValue) -> Result<KValue> {
+        self.binary_op(rhs, BinaryOp::Power, false)
+            .map(KValue::from)
+    }
+
+    fn add_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Add, true).map(KValue::from)
+    }
+
+    fn subtract_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Subtract, true)
+            .map(KValue::from)
+    }
+
+    fn multiply_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Multiply, true)
+            .map(KValue::from)
+    }
+
+    fn divide_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Divide, true)
+            .map(KValue::from)
+    }
+
+    fn integer_divide_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::IntegerDivide, true)
+            .map(KValue::from)
+    }
+
+    fn remainder_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Remainder, true)
+            .map(KValue::from)
+    }
+
+    fn power_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.binary_op(lhs, BinaryOp::Power, true).map(KValue::from)
+    }
+
+    fn add_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Add)
+    }
+
+    fn subtract_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Subtract)
+    }
+
+    fn multiply_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Multiply)
+    }
+
+    fn divide_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Divide)
+    }
+
+    fn remainder_assign(&mut self, rhs: &KValue) -> Result<()> {
+        self.assign_op(rhs, BinaryOp::Remainder)
+    }
+
+    fn less(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_lt())
+    }
+
+    fn less_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_le())
+    }
+
+    fn greater(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_gt())
+    }
+
+    fn greater_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare_or_error(rhs)?.is_ge())
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)? == Some(Ordering::Equal))
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+
+    fn equal_rhs(&self, lhs: &KValue) -> Result<bool> {
+        self.equal(lhs)
+    }
+}
+
+impl Deref for Decimal {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Inner> for Decimal {
+    fn from(n: Inner) -> Self {
+        Self(n)
+    }
+}
+
+impl From<Decimal> for KValue {
+    fn from(n: Decimal) -> Self {
+        KObject::from(n).into()
+    }
+}
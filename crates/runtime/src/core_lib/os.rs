@@ -1,20 +1,127 @@
 //! The `os` core library module
 
+mod command;
+mod date_time;
+mod duration;
+
+pub use command::{Child, Command, CommandOutput};
+pub use date_time::DateTime;
+pub use duration::Duration;
+
 use crate::{derive::*, prelude::*, Result};
-use chrono::prelude::*;
 use instant::Instant;
 
 /// Initializes the `os` core library module
 pub fn make_module() -> KMap {
-    use KValue::Number;
+    use KValue::{Number, Str};
 
     let result = KMap::with_type("core.os");
 
+    result.add_fn("command", |ctx| match ctx.args() {
+        [Str(program)] => {
+            ctx.vm.settings().permissions.check_process_spawning()?;
+            Ok(Command::new(program.clone()).into())
+        }
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("duration", |ctx| match ctx.args() {
+        [Number(seconds)] => Duration::from_seconds(seconds.into()).map(KValue::from),
+        unexpected => unexpected_args("|Number|", unexpected),
+    });
+
+    result.add_fn("env", |ctx| match ctx.args() {
+        [] => {
+            ctx.vm.settings().permissions.check_env_access()?;
+            let vars = ctx.vm.os().env_vars()?;
+            let result = KMap::with_capacity(vars.len());
+            for (key, value) in vars {
+                result.insert(key.as_str(), value);
+            }
+            Ok(result.into())
+        }
+        [Str(key)] => {
+            ctx.vm.settings().permissions.check_env_access()?;
+            match ctx.vm.os().env_var(key)? {
+                Some(value) => Ok(value.into()),
+                None => Ok(KValue::Null),
+            }
+        }
+        unexpected => unexpected_args("||, or |String|", unexpected),
+    });
+
+    result.add_fn("exit", |ctx| {
+        let code = match ctx.args() {
+            [] => 0,
+            [Number(n)] if n.is_f64() && f64::from(n).fract() != 0.0 => {
+                return runtime_error!("Expected an integer exit code, found {n}");
+            }
+            // Exit codes are limited to the range that's supported by all platforms
+            [Number(n)] => match u8::try_from(i64::from(n)) {
+                Ok(code) => i32::from(code),
+                Err(_) => return runtime_error!("Exit code out of range (0-255): {n}"),
+            },
+            unexpected => return unexpected_args("||, or |Number|", unexpected),
+        };
+        ctx.vm.os().exit(code)?;
+        Ok(KValue::Null)
+    });
+
+    result.add_fn("hostname", |ctx| match ctx.args() {
+        [] => ctx.vm.os().hostname().map(KValue::from),
+        unexpected => unexpected_args("||", unexpected),
+    });
+
     result.add_fn("name", |ctx| match ctx.args() {
         [] => Ok(std::env::consts::OS.into()),
         unexpected => unexpected_args("||", unexpected),
     });
 
+    result.add_fn("parse_time", |ctx| match ctx.args() {
+        [Str(input)] => DateTime::parse(input, None),
+        [Str(input), Str(format)] => DateTime::parse(input, Some(format)),
+        unexpected => unexpected_args("|String|, or |String, String|", unexpected),
+    });
+
+    result.add_fn("pid", |ctx| match ctx.args() {
+        [] => ctx.vm.os().process_id().map(KValue::from),
+        unexpected => unexpected_args("||", unexpected),
+    });
+
+    result.add_fn("remove_env", |ctx| match ctx.args() {
+        [Str(key)] => {
+            ctx.vm.settings().permissions.check_env_access()?;
+            ctx.vm.os().remove_env_var(key).map(|_| KValue::Null)
+        }
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("run", |ctx| match ctx.args() {
+        [Str(program), args @ ..] => {
+            ctx.vm.settings().permissions.check_process_spawning()?;
+            let mut command = Command::new(program.clone());
+            command.add_args(command::collect_args(args)?);
+            command.output().map(KValue::from)
+        }
+        unexpected => unexpected_args("|String, Any...|", unexpected),
+    });
+
+    result.add_fn("set_env", |ctx| match ctx.args() {
+        [Str(key), Str(value)] => {
+            ctx.vm.settings().permissions.check_env_access()?;
+            ctx.vm.os().set_env_var(key, value).map(|_| KValue::Null)
+        }
+        unexpected => unexpected_args("|String, String|", unexpected),
+    });
+
+    result.add_fn("sleep", |ctx| match ctx.args() {
+        [Number(seconds)] => match std::time::Duration::try_from_secs_f64(seconds.into()) {
+            Ok(duration) => ctx.vm.os().sleep(duration).map(|_| KValue::Null),
+            Err(_) => runtime_error!("Invalid sleep duration: {seconds}"),
+        },
+        unexpected => unexpected_args("|Number|", unexpected),
+    });
+
     result.add_fn("start_timer", |ctx| match ctx.args() {
         [] => Ok(Timer::now()),
         unexpected => unexpected_args("||", unexpected),
@@ -32,99 +139,6 @@ pub fn make_module() -> KMap {
     result
 }
 
-/// The underlying data type returned by `os.time()`
-#[derive(Clone, Debug, KotoCopy, KotoType)]
-pub struct DateTime(chrono::DateTime<FixedOffset>);
-
-#[koto_impl(runtime = crate)]
-impl DateTime {
-    fn with_chrono_datetime(time: chrono::DateTime<FixedOffset>) -> KValue {
-        KObject::from(Self(time)).into()
-    }
-
-    fn now() -> KValue {
-        Self::with_chrono_datetime(Local::now().fixed_offset())
-    }
-
-    fn from_seconds(seconds: f64, maybe_offset: Option<i64>) -> Result<KValue> {
-        let seconds_i64 = seconds as i64;
-        let sub_nanos = (seconds.fract() * 1.0e9) as u32;
-        match chrono::DateTime::from_timestamp(seconds_i64, sub_nanos) {
-            Some(utc) => {
-                let offset = match maybe_offset {
-                    Some(offset) => match FixedOffset::east_opt(offset as i32) {
-                        Some(offset) => offset,
-                        None => return runtime_error!("time offset is out of range: {offset}"),
-                    },
-                    None => *Local::now().offset(),
-                };
-                let local = utc.with_timezone(&offset);
-                Ok(Self::with_chrono_datetime(local))
-            }
-            None => runtime_error!("timestamp in seconds is out of range: {seconds}"),
-        }
-    }
-
-    #[koto_method]
-    fn day(&self) -> KValue {
-        self.0.day().into()
-    }
-
-    #[koto_method]
-    fn hour(&self) -> KValue {
-        self.0.hour().into()
-    }
-
-    #[koto_method]
-    fn minute(&self) -> KValue {
-        self.0.minute().into()
-    }
-
-    #[koto_method]
-    fn month(&self) -> KValue {
-        self.0.month().into()
-    }
-
-    #[koto_method]
-    fn second(&self) -> KValue {
-        self.0.second().into()
-    }
-
-    #[koto_method]
-    fn nanosecond(&self) -> KValue {
-        self.0.nanosecond().into()
-    }
-
-    #[koto_method]
-    fn timestamp(&self) -> KValue {
-        let seconds = self.0.timestamp() as f64;
-        let sub_nanos = self.0.timestamp_subsec_nanos();
-        (seconds + sub_nanos as f64 / 1.0e9).into()
-    }
-
-    #[koto_method]
-    fn timezone_offset(&self) -> KValue {
-        self.0.offset().local_minus_utc().into()
-    }
-
-    #[koto_method]
-    fn timezone_string(&self) -> KValue {
-        self.0.format("%z").to_string().into()
-    }
-
-    #[koto_method]
-    fn year(&self) -> KValue {
-        self.0.year().into()
-    }
-}
-
-impl KotoObject for DateTime {
-    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
-        ctx.append(self.0.format("%F %T").to_string());
-        Ok(())
-    }
-}
-
 /// The underlying data type returned by `os.start_timer()`
 #[derive(Clone, Debug, KotoCopy, KotoType)]
 pub struct Timer(Instant);
@@ -0,0 +1,636 @@
+use crate::{
+    core_lib::{bytes::Bytes, io::map_io_err, io::File},
+    derive::*,
+    prelude::*,
+    Ptr, PtrMut, Result,
+};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    process::{self, ChildStdin, ExitStatus, Stdio},
+};
+
+/// The Command type returned by `os.command`
+///
+/// Commands are used to configure and then spawn a child process.
+#[derive(Clone, KotoCopy, KotoType)]
+pub struct Command {
+    program: KString,
+    args: Vec<KString>,
+    // Environment variables that should be set (or removed if the value is None)
+    env: Vec<(KString, Option<KString>)>,
+    clear_env: bool,
+    current_dir: Option<KString>,
+    stdin: Option<StdioMode>,
+    stdout: Option<StdioMode>,
+    stderr: Option<StdioMode>,
+}
+
+#[koto_impl(runtime = crate)]
+impl Command {
+    /// Initializes a command that will run the provided program
+    pub fn new(program: KString) -> Self {
+        Self {
+            program,
+            args: Vec::new(),
+            env: Vec::new(),
+            clear_env: false,
+            current_dir: None,
+            stdin: None,
+            stdout: None,
+            stderr: None,
+        }
+    }
+
+    /// Adds arguments that should be passed to the program
+    pub fn add_args(&mut self, args: impl IntoIterator<Item = KString>) {
+        self.args.extend(args);
+    }
+
+    #[koto_method]
+    fn args(ctx: MethodContext<Self>) -> Result<KValue> {
+        let args = collect_args(ctx.args)?;
+        ctx.instance_mut()?.add_args(args);
+        ctx.instance_result()
+    }
+
+    #[koto_method]
+    fn current_dir(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [KValue::Str(path)] => {
+                ctx.instance_mut()?.current_dir = Some(path.clone());
+                ctx.instance_result()
+            }
+            unexpected => unexpected_args("|String|", unexpected),
+        }
+    }
+
+    #[koto_method]
+    fn env(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [KValue::Str(key), KValue::Str(value)] => {
+                ctx.instance_mut()?
+                    .env
+                    .push((key.clone(), Some(value.clone())));
+                ctx.instance_result()
+            }
+            unexpected => unexpected_args("|String, String|", unexpected),
+        }
+    }
+
+    #[koto_method]
+    fn env_clear(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [] => {
+                let mut this = ctx.instance_mut()?;
+                this.env.clear();
+                this.clear_env = true;
+            }
+            unexpected => return unexpected_args("||", unexpected),
+        }
+        ctx.instance_result()
+    }
+
+    #[koto_method]
+    fn env_remove(ctx: MethodContext<Self>) -> Result<KValue> {
+        match ctx.args {
+            [KValue::Str(key)] => {
+                ctx.instance_mut()?.env.push((key.clone(), None));
+                ctx.instance_result()
+            }
+            unexpected => unexpected_args("|String|", unexpected),
+        }
+    }
+
+    #[koto_method]
+    fn stdin(ctx: MethodContext<Self>) -> Result<KValue> {
+        let mode = StdioMode::from_args(ctx.args)?;
+        ctx.instance_mut()?.stdin = Some(mode);
+        ctx.instance_result()
+    }
+
+    #[koto_method]
+    fn stdout(ctx: MethodContext<Self>) -> Result<KValue> {
+        let mode = StdioMode::from_args(ctx.args)?;
+        ctx.instance_mut()?.stdout = Some(mode);
+        ctx.instance_result()
+    }
+
+    #[koto_method]
+    fn stderr(ctx: MethodContext<Self>) -> Result<KValue> {
+        let mode = StdioMode::from_args(ctx.args)?;
+        ctx.instance_mut()?.stderr = Some(mode);
+        ctx.instance_result()
+    }
+
+    #[koto_method]
+    fn spawn(&self) -> Result<KValue> {
+        match self.make_process_command().spawn() {
+            Ok(child) => Ok(Child::new(child).into()),
+            Err(error) => runtime_error!("Failed to spawn '{}': {error}", self.program),
+        }
+    }
+
+    #[koto_method]
+    fn wait_for_exit(&self) -> Result<KValue> {
+        match self.make_process_command().status() {
+            Ok(status) => Ok(exit_code(status)),
+            Err(error) => runtime_error!("Failed to run '{}': {error}", self.program),
+        }
+    }
+
+    #[koto_method]
+    fn wait_for_output(&self) -> Result<KValue> {
+        self.output().map(KValue::from)
+    }
+
+    /// Runs the command, waits for it to finish, and then returns its output
+    ///
+    /// Unless otherwise configured, the command's stdout and stderr will be captured,
+    /// and stdin will be closed.
+    pub fn output(&self) -> Result<CommandOutput> {
+        match self.make_process_command().output() {
+            Ok(output) => Ok(output.into()),
+            Err(error) => runtime_error!("Failed to run '{}': {error}", self.program),
+        }
+    }
+
+    fn make_process_command(&self) -> process::Command {
+        let mut command = process::Command::new(self.program.as_str());
+
+        command.args(self.args.iter().map(KString::as_str));
+
+        if self.clear_env {
+            command.env_clear();
+        }
+        for (key, value) in self.env.iter() {
+            match value {
+                Some(value) => command.env(key.as_str(), value.as_str()),
+                None => command.env_remove(key.as_str()),
+            };
+        }
+
+        if let Some(dir) = &self.current_dir {
+            command.current_dir(dir.as_str());
+        }
+
+        if let Some(mode) = self.stdin {
+            command.stdin(mode);
+        }
+        if let Some(mode) = self.stdout {
+            command.stdout(mode);
+        }
+        if let Some(mode) = self.stderr {
+            command.stderr(mode);
+        }
+
+        command
+    }
+}
+
+impl KotoObject for Command {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(format!("{}({})", Self::type_static(), self.program));
+        Ok(())
+    }
+}
+
+impl From<Command> for KValue {
+    fn from(command: Command) -> Self {
+        KObject::from(command).into()
+    }
+}
+
+/// The Child type returned by `Command.spawn`
+///
+/// A child is a running (or exited) process, with its standard streams available as files when
+/// they've been configured to be piped.
+#[derive(Clone, KotoCopy, KotoType)]
+pub struct Child(PtrMut<ChildProcess>);
+
+struct ChildProcess {
+    id: u32,
+    // The process is taken when waiting for its output
+    process: Option<process::Child>,
+    status: Option<ExitStatus>,
+    stdin: Option<KValue>,
+    // A shared handle to the stdin pipe, allowing it to be closed before waiting for the process
+    stdin_pipe: Option<PtrMut<Option<ChildStdin>>>,
+    stdout: Option<KValue>,
+    stderr: Option<KValue>,
+}
+
+#[koto_impl(runtime = crate)]
+impl Child {
+    fn new(process: process::Child) -> Self {
+        Self(make_ptr_mut!(ChildProcess {
+            id: process.id(),
+            process: Some(process),
+            status: None,
+            stdin: None,
+            stdin_pipe: None,
+            stdout: None,
+            stderr: None,
+        }))
+    }
+
+    #[koto_method]
+    fn id(&self) -> KValue {
+        self.0.borrow().id.into()
+    }
+
+    #[koto_method]
+    fn has_exited(&self) -> Result<KValue> {
+        let mut child = self.0.borrow_mut();
+        if child.status.is_some() {
+            return Ok(true.into());
+        }
+        let Some(process) = child.process.as_mut() else {
+            return Ok(true.into());
+        };
+        match process.try_wait() {
+            Ok(status) => {
+                let result = status.is_some();
+                child.status = status;
+                Ok(result.into())
+            }
+            Err(error) => runtime_error!("Failed to get the child's status: {error}"),
+        }
+    }
+
+    #[koto_method]
+    fn kill(&self) -> Result<KValue> {
+        let mut child = self.0.borrow_mut();
+        match child.process.as_mut().map(|process| process.kill()) {
+            Some(Err(error)) => runtime_error!("Failed to kill the child process: {error}"),
+            _ => Ok(KValue::Null),
+        }
+    }
+
+    #[koto_method]
+    fn stdin(&self) -> KValue {
+        let mut child = self.0.borrow_mut();
+        if let Some(stdin) = &child.stdin {
+            return stdin.clone();
+        }
+        let Some(pipe) = child
+            .process
+            .as_mut()
+            .and_then(|process| process.stdin.take())
+        else {
+            return KValue::Null;
+        };
+        let pipe = make_ptr_mut!(Some(pipe));
+        let file = File::new(make_ptr!(ChildStdinFile(pipe.clone()))).into();
+        child.stdin = Some(file);
+        child.stdin_pipe = Some(pipe);
+        child.stdin.clone().unwrap_or_default()
+    }
+
+    #[koto_method]
+    fn stdout(&self) -> KValue {
+        let mut child = self.0.borrow_mut();
+        if let Some(stdout) = &child.stdout {
+            return stdout.clone();
+        }
+        let Some(pipe) = child
+            .process
+            .as_mut()
+            .and_then(|process| process.stdout.take())
+        else {
+            return KValue::Null;
+        };
+        let file: KValue = File::new(make_ptr!(ChildOutputFile::new(pipe, "stdout"))).into();
+        child.stdout = Some(file.clone());
+        file
+    }
+
+    #[koto_method]
+    fn stderr(&self) -> KValue {
+        let mut child = self.0.borrow_mut();
+        if let Some(stderr) = &child.stderr {
+            return stderr.clone();
+        }
+        let Some(pipe) = child
+            .process
+            .as_mut()
+            .and_then(|process| process.stderr.take())
+        else {
+            return KValue::Null;
+        };
+        let file: KValue = File::new(make_ptr!(ChildOutputFile::new(pipe, "stderr"))).into();
+        child.stderr = Some(file.clone());
+        file
+    }
+
+    #[koto_method]
+    fn wait_for_exit(&self) -> Result<KValue> {
+        let mut child = self.0.borrow_mut();
+        child.close_stdin();
+        if let Some(status) = child.status {
+            return Ok(exit_code(status));
+        }
+        let Some(process) = child.process.as_mut() else {
+            return Ok(KValue::Null);
+        };
+        match process.wait() {
+            Ok(status) => {
+                child.status = Some(status);
+                Ok(exit_code(status))
+            }
+            Err(error) => runtime_error!("Failed to wait for the child process: {error}"),
+        }
+    }
+
+    #[koto_method]
+    fn wait_for_output(&self) -> Result<KValue> {
+        let mut child = self.0.borrow_mut();
+        child.close_stdin();
+        let Some(process) = child.process.take() else {
+            return runtime_error!("The child's output has already been retrieved");
+        };
+        match process.wait_with_output() {
+            Ok(output) => {
+                child.status = Some(output.status);
+                Ok(CommandOutput::from(output).into())
+            }
+            Err(error) => runtime_error!("Failed to wait for the child process: {error}"),
+        }
+    }
+}
+
+impl ChildProcess {
+    // Closes the child's stdin so that it doesn't block while waiting for more input
+    fn close_stdin(&mut self) {
+        if let Some(pipe) = &self.stdin_pipe {
+            pipe.borrow_mut().take();
+        }
+        if let Some(process) = self.process.as_mut() {
+            process.stdin.take();
+        }
+    }
+}
+
+impl KotoObject for Child {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(format!("{}({})", Self::type_static(), self.0.borrow().id));
+        Ok(())
+    }
+}
+
+impl From<Child> for KValue {
+    fn from(child: Child) -> Self {
+        KObject::from(child).into()
+    }
+}
+
+/// The CommandOutput type returned by `Command.wait_for_output` and `os.run`
+#[derive(Clone, KotoCopy, KotoType)]
+pub struct CommandOutput {
+    status: ExitStatus,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+
+#[koto_impl(runtime = crate)]
+impl CommandOutput {
+    #[koto_method]
+    fn exit_code(&self) -> KValue {
+        exit_code(self.status)
+    }
+
+    #[koto_method]
+    fn success(&self) -> KValue {
+        self.status.success().into()
+    }
+
+    #[koto_method]
+    fn stdout(&self) -> KValue {
+        bytes_to_string(&self.stdout)
+    }
+
+    #[koto_method]
+    fn stdout_bytes(&self) -> KValue {
+        Bytes::from(self.stdout.clone()).into()
+    }
+
+    #[koto_method]
+    fn stderr(&self) -> KValue {
+        bytes_to_string(&self.stderr)
+    }
+
+    #[koto_method]
+    fn stderr_bytes(&self) -> KValue {
+        Bytes::from(self.stderr.clone()).into()
+    }
+}
+
+impl KotoObject for CommandOutput {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(format!("{}({})", Self::type_static(), self.status));
+        Ok(())
+    }
+}
+
+impl From<process::Output> for CommandOutput {
+    fn from(output: process::Output) -> Self {
+        Self {
+            status: output.status,
+            stdout: output.stdout,
+            stderr: output.stderr,
+        }
+    }
+}
+
+impl From<CommandOutput> for KValue {
+    fn from(output: CommandOutput) -> Self {
+        KObject::from(output).into()
+    }
+}
+
+/// Collects command arguments from Strings, or from Lists or Tuples containing Strings
+pub fn collect_args(args: &[KValue]) -> Result<Vec<KString>> {
+    let mut result = Vec::with_capacity(args.len());
+
+    for arg in args {
+        match arg {
+            KValue::Str(s) => result.push(s.clone()),
+            KValue::List(l) => collect_nested_args(l.data().iter(), &mut result)?,
+            KValue::Tuple(t) => collect_nested_args(t.iter(), &mut result)?,
+            unexpected => return unexpected_type("a String, List, or Tuple", unexpected),
+        }
+    }
+
+    Ok(result)
+}
+
+fn collect_nested_args<'a>(
+    args: impl Iterator<Item = &'a KValue>,
+    result: &mut Vec<KString>,
+) -> Result<()> {
+    for arg in args {
+        match arg {
+            KValue::Str(s) => result.push(s.clone()),
+            unexpected => return unexpected_type("a String", unexpected),
+        }
+    }
+    Ok(())
+}
+
+// Returns the process's exit code, or null if the process was terminated by a signal
+fn exit_code(status: ExitStatus) -> KValue {
+    match status.code() {
+        Some(code) => code.into(),
+        None => KValue::Null,
+    }
+}
+
+// Output that isn't valid UTF-8 results in null, see the `_bytes` functions for binary output
+fn bytes_to_string(bytes: &[u8]) -> KValue {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s.into(),
+        Err(_) => KValue::Null,
+    }
+}
+
+// The configuration options for a command's standard streams
+#[derive(Clone, Copy)]
+enum StdioMode {
+    Inherit,
+    Piped,
+    Null,
+}
+
+impl StdioMode {
+    fn from_args(args: &[KValue]) -> Result<Self> {
+        match args {
+            [KValue::Str(mode)] => match mode.as_str() {
+                "inherit" => Ok(Self::Inherit),
+                "piped" => Ok(Self::Piped),
+                "null" => Ok(Self::Null),
+                other => runtime_error!(
+                    "Expected 'inherit', 'piped', or 'null' as the stream mode, found '{other}'"
+                ),
+            },
+            unexpected => unexpected_args("|String|", unexpected),
+        }
+    }
+}
+
+impl From<StdioMode> for Stdio {
+    fn from(mode: StdioMode) -> Self {
+        match mode {
+            StdioMode::Inherit => Stdio::inherit(),
+            StdioMode::Piped => Stdio::piped(),
+            StdioMode::Null => Stdio::null(),
+        }
+    }
+}
+
+// The file returned by `Child.stdin`
+struct ChildStdinFile(PtrMut<Option<ChildStdin>>);
+
+impl ChildStdinFile {
+    fn with_pipe<T>(&self, f: impl FnOnce(&mut ChildStdin) -> std::io::Result<T>) -> Result<T> {
+        match self.0.borrow_mut().as_mut() {
+            Some(pipe) => f(pipe).map_err(map_io_err),
+            None => runtime_error!("The child's stdin has been closed"),
+        }
+    }
+}
+
+impl KotoFile for ChildStdinFile {
+    fn id(&self) -> KString {
+        "stdin".into()
+    }
+}
+
+impl KotoRead for ChildStdinFile {}
+
+impl KotoWrite for ChildStdinFile {
+    fn write(&self, bytes: &[u8]) -> Result<()> {
+        self.with_pipe(|pipe| pipe.write_all(bytes))
+    }
+
+    fn write_line(&self, text: &str) -> Result<()> {
+        self.with_pipe(|pipe| {
+            pipe.write_all(text.as_bytes())?;
+            pipe.write_all(b"\n")
+        })
+    }
+
+    fn flush(&self) -> Result<()> {
+        self.with_pipe(|pipe| pipe.flush())
+    }
+}
+
+// The file returned by `Child.stdout` and `Child.stderr`
+struct ChildOutputFile<T: Read> {
+    reader: KCell<BufReader<T>>,
+    id: &'static str,
+}
+
+impl<T: Read> ChildOutputFile<T> {
+    fn new(pipe: T, id: &'static str) -> Self {
+        Self {
+            reader: BufReader::new(pipe).into(),
+            id,
+        }
+    }
+}
+
+impl<T> KotoFile for ChildOutputFile<T>
+where
+    T: Read + KotoSend + KotoSync,
+{
+    fn id(&self) -> KString {
+        self.id.into()
+    }
+}
+
+impl<T> KotoRead for ChildOutputFile<T>
+where
+    T: Read,
+{
+    fn read_line(&self) -> Result<Option<String>> {
+        let mut buffer = String::new();
+        match self
+            .reader
+            .borrow_mut()
+            .read_line(&mut buffer)
+            .map_err(map_io_err)?
+        {
+            0 => Ok(None),
+            _ => Ok(Some(buffer)),
+        }
+    }
+
+    fn read_to_string(&self) -> Result<String> {
+        let mut buffer = String::new();
+        self.reader
+            .borrow_mut()
+            .read_to_string(&mut buffer)
+            .map_err(map_io_err)?;
+        Ok(buffer)
+    }
+
+    fn read_bytes(&self, count: usize) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut reader = self.reader.borrow_mut();
+        Read::by_ref(&mut *reader)
+            .take(count as u64)
+            .read_to_end(&mut buffer)
+            .map_err(map_io_err)?;
+        Ok(buffer)
+    }
+
+    fn read_to_end(&self) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.reader
+            .borrow_mut()
+            .read_to_end(&mut buffer)
+            .map_err(map_io_err)?;
+        Ok(buffer)
+    }
+}
+
+impl<T: Read> KotoWrite for ChildOutputFile<T> {}
@@ -0,0 +1,258 @@
+use super::Duration;
+use crate::{derive::*, prelude::*, Result};
+use chrono::{format::ParseErrorKind, prelude::*, TimeDelta};
+use std::{cmp::Ordering, fmt::Write, time::SystemTime};
+
+/// The DateTime type returned by `os.time` and `os.parse_time`
+#[derive(Clone, Debug, KotoCopy, KotoType)]
+pub struct DateTime(chrono::DateTime<FixedOffset>);
+
+#[koto_impl(runtime = crate)]
+impl DateTime {
+    fn with_chrono_datetime(time: chrono::DateTime<FixedOffset>) -> KValue {
+        KObject::from(Self(time)).into()
+    }
+
+    /// Returns a DateTime set to the current time in the local timezone
+    pub fn now() -> KValue {
+        Self::with_chrono_datetime(Local::now().fixed_offset())
+    }
+
+    /// Returns a DateTime in the local timezone that corresponds to the given [SystemTime]
+    pub fn with_system_time(time: SystemTime) -> KValue {
+        Self::with_chrono_datetime(chrono::DateTime::<Local>::from(time).fixed_offset())
+    }
+
+    /// Returns a DateTime set to the given timestamp in seconds
+    ///
+    /// If an offset in seconds isn't provided then the local timezone will be used.
+    pub fn from_seconds(seconds: f64, maybe_offset: Option<i64>) -> Result<KValue> {
+        let seconds_i64 = seconds as i64;
+        let sub_nanos = (seconds.fract() * 1.0e9) as u32;
+        match chrono::DateTime::from_timestamp(seconds_i64, sub_nanos) {
+            Some(utc) => {
+                let offset = match maybe_offset {
+                    Some(offset) => match FixedOffset::east_opt(offset as i32) {
+                        Some(offset) => offset,
+                        None => return runtime_error!("time offset is out of range: {offset}"),
+                    },
+                    None => *Local::now().offset(),
+                };
+                let local = utc.with_timezone(&offset);
+                Ok(Self::with_chrono_datetime(local))
+            }
+            None => runtime_error!("timestamp in seconds is out of range: {seconds}"),
+        }
+    }
+
+    /// Parses a DateTime from the given string
+    ///
+    /// If a format isn't provided then the input is expected to be an RFC 3339 timestamp.
+    ///
+    /// If the format doesn't include a timezone offset then the local timezone will be used,
+    /// and if the format doesn't include a time then the time will be set to midnight.
+    pub fn parse(input: &str, maybe_format: Option<&str>) -> Result<KValue> {
+        let Some(format) = maybe_format else {
+            return match chrono::DateTime::parse_from_rfc3339(input) {
+                Ok(result) => Ok(Self::with_chrono_datetime(result)),
+                Err(error) => runtime_error!("failed to parse '{input}': {error}"),
+            };
+        };
+
+        // Parsing is attempted with decreasing levels of detail, with the first error that isn't
+        // caused by missing information being reported.
+        let error = match chrono::DateTime::parse_from_str(input, format) {
+            Ok(result) => return Ok(Self::with_chrono_datetime(result)),
+            Err(error) if error.kind() != ParseErrorKind::NotEnough => error,
+            Err(_) => match NaiveDateTime::parse_from_str(input, format) {
+                Ok(result) => return Self::from_local_datetime(result),
+                Err(error) if error.kind() != ParseErrorKind::NotEnough => error,
+                Err(_) => match NaiveDate::parse_from_str(input, format) {
+                    Ok(result) => return Self::from_local_datetime(result.into()),
+                    Err(error) => error,
+                },
+            },
+        };
+
+        runtime_error!("failed to parse '{input}' with format '{format}': {error}")
+    }
+
+    fn from_local_datetime(time: NaiveDateTime) -> Result<KValue> {
+        match Local.from_local_datetime(&time).earliest() {
+            Some(result) => Ok(Self::with_chrono_datetime(result.fixed_offset())),
+            None => runtime_error!("'{time}' doesn't exist in the local timezone"),
+        }
+    }
+
+    #[koto_method]
+    fn day(&self) -> KValue {
+        self.0.day().into()
+    }
+
+    #[koto_method]
+    fn format(&self, args: &[KValue]) -> Result<KValue> {
+        match args {
+            [KValue::Str(format)] => {
+                let mut result = String::new();
+                match write!(result, "{}", self.0.format(format)) {
+                    Ok(_) => Ok(result.into()),
+                    Err(_) => runtime_error!("invalid format string: '{format}'"),
+                }
+            }
+            unexpected => unexpected_args("|String|", unexpected),
+        }
+    }
+
+    #[koto_method]
+    fn hour(&self) -> KValue {
+        self.0.hour().into()
+    }
+
+    #[koto_method]
+    fn minute(&self) -> KValue {
+        self.0.minute().into()
+    }
+
+    #[koto_method]
+    fn month(&self) -> KValue {
+        self.0.month().into()
+    }
+
+    #[koto_method]
+    fn second(&self) -> KValue {
+        self.0.second().into()
+    }
+
+    #[koto_method]
+    fn nanosecond(&self) -> KValue {
+        self.0.nanosecond().into()
+    }
+
+    #[koto_method]
+    fn timestamp(&self) -> KValue {
+        let seconds = self.0.timestamp() as f64;
+        let sub_nanos = self.0.timestamp_subsec_nanos();
+        (seconds + sub_nanos as f64 / 1.0e9).into()
+    }
+
+    #[koto_method]
+    fn timezone_offset(&self) -> KValue {
+        self.0.offset().local_minus_utc().into()
+    }
+
+    #[koto_method]
+    fn timezone_string(&self) -> KValue {
+        self.0.format("%z").to_string().into()
+    }
+
+    #[koto_method]
+    fn to_local(&self) -> KValue {
+        Self::with_chrono_datetime(self.0.with_timezone(&Local).fixed_offset())
+    }
+
+    #[koto_method]
+    fn to_utc(&self) -> KValue {
+        Self::with_chrono_datetime(self.0.to_utc().fixed_offset())
+    }
+
+    #[koto_method]
+    fn year(&self) -> KValue {
+        self.0.year().into()
+    }
+
+    /// Returns the result of adding a duration to the DateTime
+    pub(super) fn add_duration(&self, duration: TimeDelta) -> Result<KValue> {
+        match self.0.checked_add_signed(duration) {
+            Some(result) => Ok(Self::with_chrono_datetime(result)),
+            None => runtime_error!("the resulting DateTime is out of range"),
+        }
+    }
+
+    fn compare(&self, rhs: &KValue) -> Result<Ordering> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>().unwrap();
+                Ok(self.0.cmp(&rhs.0))
+            }
+            unexpected => unexpected_type(Self::type_static(), unexpected),
+        }
+    }
+}
+
+impl KotoObject for DateTime {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(self.0.format("%F %T").to_string());
+        Ok(())
+    }
+
+    fn format(&self, format_string: &str) -> Result<Option<String>> {
+        // Format strings containing `%` specifiers are used as `strftime`-style formats,
+        // otherwise the standard formatting options are applied
+        if !format_string.contains('%') {
+            return Ok(None);
+        }
+
+        let mut result = String::new();
+        match write!(result, "{}", self.0.format(format_string)) {
+            Ok(_) => Ok(Some(result)),
+            Err(_) => runtime_error!("invalid format string: '{format_string}'"),
+        }
+    }
+
+    fn add(&self, rhs: &KValue) -> Result<KValue> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Duration>() => {
+                let rhs = o.cast::<Duration>().unwrap();
+                self.add_duration(rhs.inner())
+            }
+            unexpected => unexpected_type(Duration::type_static(), unexpected),
+        }
+    }
+
+    fn subtract(&self, rhs: &KValue) -> Result<KValue> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Duration>() => {
+                let rhs = o.cast::<Duration>().unwrap();
+                self.add_duration(-rhs.inner())
+            }
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>().unwrap();
+                Ok(Duration::from(self.0.signed_duration_since(rhs.0)).into())
+            }
+            unexpected => unexpected_type(
+                &format!("{}, or {}", Self::type_static(), Duration::type_static()),
+                unexpected,
+            ),
+        }
+    }
+
+    fn less(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_lt())
+    }
+
+    fn less_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_le())
+    }
+
+    fn greater(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_gt())
+    }
+
+    fn greater_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_ge())
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>().unwrap();
+                Ok(self.0 == rhs.0)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+}
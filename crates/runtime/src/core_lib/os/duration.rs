@@ -0,0 +1,178 @@
+use super::DateTime;
+use crate::{derive::*, prelude::*, Result};
+use chrono::TimeDelta;
+use std::cmp::Ordering;
+
+/// The Duration type returned by `os.duration`, and by subtracting DateTimes
+#[derive(Clone, Copy, Debug, KotoCopy, KotoType)]
+pub struct Duration(TimeDelta);
+
+#[koto_impl(runtime = crate)]
+impl Duration {
+    /// Returns a Duration with the given number of seconds
+    pub fn from_seconds(seconds: f64) -> Result<Self> {
+        if !seconds.is_finite() {
+            return runtime_error!("invalid number of seconds for a Duration: {seconds}");
+        }
+
+        let whole = seconds.floor();
+        let nanos = ((seconds - whole) * 1.0e9).round() as u32;
+        // Rounding might have produced a full second
+        let (whole, nanos) = if nanos >= 1_000_000_000 {
+            (whole + 1.0, 0)
+        } else {
+            (whole, nanos)
+        };
+
+        match TimeDelta::new(whole as i64, nanos) {
+            Some(result) if whole.abs() < i64::MAX as f64 => Ok(Self(result)),
+            _ => runtime_error!("the number of seconds is out of range for a Duration: {seconds}"),
+        }
+    }
+
+    /// Returns the inner [TimeDelta]
+    pub fn inner(&self) -> TimeDelta {
+        self.0
+    }
+
+    fn as_seconds(&self) -> f64 {
+        self.0.num_seconds() as f64 + self.0.subsec_nanos() as f64 / 1.0e9
+    }
+
+    #[koto_method]
+    fn days(&self) -> KValue {
+        (self.as_seconds() / 86400.0).into()
+    }
+
+    #[koto_method]
+    fn hours(&self) -> KValue {
+        (self.as_seconds() / 3600.0).into()
+    }
+
+    #[koto_method]
+    fn minutes(&self) -> KValue {
+        (self.as_seconds() / 60.0).into()
+    }
+
+    #[koto_method]
+    fn seconds(&self) -> KValue {
+        self.as_seconds().into()
+    }
+
+    fn scale(&self, rhs: &KValue, op: fn(f64, f64) -> f64) -> Result<KValue> {
+        match rhs {
+            KValue::Number(n) => {
+                Self::from_seconds(op(self.as_seconds(), n.into())).map(KValue::from)
+            }
+            unexpected => unexpected_type("Number", unexpected),
+        }
+    }
+
+    fn compare(&self, rhs: &KValue) -> Result<Ordering> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>().unwrap();
+                Ok(self.0.cmp(&rhs.0))
+            }
+            unexpected => unexpected_type(Self::type_static(), unexpected),
+        }
+    }
+}
+
+impl KotoObject for Duration {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(format!("{}({}s)", Self::type_static(), self.as_seconds()));
+        Ok(())
+    }
+
+    fn negate(&self, _vm: &mut KotoVm) -> Result<KValue> {
+        Ok(Self(-self.0).into())
+    }
+
+    fn add(&self, rhs: &KValue) -> Result<KValue> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>().unwrap();
+                match self.0.checked_add(&rhs.0) {
+                    Some(result) => Ok(Self(result).into()),
+                    None => runtime_error!("the resulting Duration is out of range"),
+                }
+            }
+            KValue::Object(o) if o.is_a::<DateTime>() => {
+                let rhs = o.cast::<DateTime>().unwrap();
+                rhs.add_duration(self.0)
+            }
+            unexpected => unexpected_type(
+                &format!("{}, or {}", Self::type_static(), DateTime::type_static()),
+                unexpected,
+            ),
+        }
+    }
+
+    fn subtract(&self, rhs: &KValue) -> Result<KValue> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>().unwrap();
+                match self.0.checked_sub(&rhs.0) {
+                    Some(result) => Ok(Self(result).into()),
+                    None => runtime_error!("the resulting Duration is out of range"),
+                }
+            }
+            unexpected => unexpected_type(Self::type_static(), unexpected),
+        }
+    }
+
+    fn multiply(&self, rhs: &KValue) -> Result<KValue> {
+        self.scale(rhs, |seconds, n| seconds * n)
+    }
+
+    fn divide(&self, rhs: &KValue) -> Result<KValue> {
+        self.scale(rhs, |seconds, n| seconds / n)
+    }
+
+    fn multiply_rhs(&self, lhs: &KValue) -> Result<KValue> {
+        self.scale(lhs, |seconds, n| n * seconds)
+    }
+
+    fn less(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_lt())
+    }
+
+    fn less_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_le())
+    }
+
+    fn greater(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_gt())
+    }
+
+    fn greater_or_equal(&self, rhs: &KValue) -> Result<bool> {
+        Ok(self.compare(rhs)?.is_ge())
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        match rhs {
+            KValue::Object(o) if o.is_a::<Self>() => {
+                let rhs = o.cast::<Self>().unwrap();
+                Ok(self.0 == rhs.0)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+}
+
+impl From<TimeDelta> for Duration {
+    fn from(duration: TimeDelta) -> Self {
+        Self(duration)
+    }
+}
+
+impl From<Duration> for KValue {
+    fn from(duration: Duration) -> Self {
+        KObject::from(duration).into()
+    }
+}
@@ -0,0 +1,211 @@
+//! The `set` core library module
+
+use super::iterator::collect_pair;
+use crate::{prelude::*, KIteratorOutput as Output, Result};
+
+/// Initializes the `set` core library module
+pub fn make_module() -> KMap {
+    let result = KMap::with_type("core.set");
+
+    result.add_fn("clear", |ctx| {
+        let expected_error = "|Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(s), []) => {
+                s.checked_data_mut()?.clear();
+                Ok(KValue::Set(s.clone()))
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("contains", |ctx| {
+        let expected_error = "|Set, Any|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(s), [value]) => {
+                let result = value.is_hashable() && s.contains(&ValueKey::try_from(value.clone())?);
+                Ok(result.into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("difference", |ctx| {
+        let expected_error = "|Set, Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(a), [KValue::Set(b)]) => {
+                let result = a
+                    .data()
+                    .difference(&b.data())
+                    .cloned()
+                    .collect::<ValueSet>();
+                Ok(KSet::from(result).into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("extend", |ctx| {
+        let expected_error = "|Set, Iterable|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(s), [iterable]) if iterable.is_iterable() => {
+                let s = s.clone();
+                let iterable = iterable.clone();
+                let values = collect_values(ctx.vm, iterable)?;
+                s.checked_data_mut()?.extend(values.iter().cloned());
+                Ok(KValue::Set(s))
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("insert", |ctx| {
+        let expected_error = "|Set, Any|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(s), [value]) => {
+                let key = ValueKey::try_from(value.clone())?;
+                let result = s.checked_data_mut()?.insert(key);
+                Ok(result.into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("intersection", |ctx| {
+        let expected_error = "|Set, Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(a), [KValue::Set(b)]) => {
+                let result = a
+                    .data()
+                    .intersection(&b.data())
+                    .cloned()
+                    .collect::<ValueSet>();
+                Ok(KSet::from(result).into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("is_disjoint", |ctx| {
+        let expected_error = "|Set, Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(a), [KValue::Set(b)]) => Ok(a.data().is_disjoint(&b.data()).into()),
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("is_empty", |ctx| {
+        let expected_error = "|Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(s), []) => Ok(s.is_empty().into()),
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("is_subset", |ctx| {
+        let expected_error = "|Set, Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(a), [KValue::Set(b)]) => Ok(a.data().is_subset(&b.data()).into()),
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("is_superset", |ctx| {
+        let expected_error = "|Set, Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(a), [KValue::Set(b)]) => Ok(a.data().is_superset(&b.data()).into()),
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("new", |ctx| {
+        let expected_error = "||, or |Iterable|";
+
+        match ctx.args() {
+            [] => Ok(KSet::new().into()),
+            [iterable] if iterable.is_iterable() => {
+                let iterable = iterable.clone();
+                Ok(KSet::from(collect_values(ctx.vm, iterable)?).into())
+            }
+            unexpected => unexpected_args(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("remove", |ctx| {
+        let expected_error = "|Set, Any|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(s), [value]) => {
+                let key = ValueKey::try_from(value.clone())?;
+                let result = s.checked_data_mut()?.shift_remove(&key);
+                Ok(result.into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("symmetric_difference", |ctx| {
+        let expected_error = "|Set, Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(a), [KValue::Set(b)]) => {
+                let result = a
+                    .data()
+                    .symmetric_difference(&b.data())
+                    .cloned()
+                    .collect::<ValueSet>();
+                Ok(KSet::from(result).into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result.add_fn("union", |ctx| {
+        let expected_error = "|Set, Set|";
+
+        match ctx.instance_and_args(is_set, expected_error)? {
+            (KValue::Set(a), [KValue::Set(b)]) => {
+                let result = a.data().union(&b.data()).cloned().collect::<ValueSet>();
+                Ok(KSet::from(result).into())
+            }
+            (instance, args) => unexpected_args_after_instance(expected_error, instance, args),
+        }
+    });
+
+    result
+}
+
+/// Collects the output of an iterable value into a [ValueSet]
+///
+/// Key/value pairs are collected as tuples, and an error is returned if any of the iterator's
+/// values aren't hashable.
+pub(crate) fn collect_values(vm: &mut KotoVm, iterable: KValue) -> Result<ValueSet> {
+    let iterator = vm.make_iterator(iterable)?;
+    let (size_hint, _) = iterator.size_hint();
+    let mut result = ValueSet::with_capacity(size_hint);
+
+    for output in iterator.map(collect_pair) {
+        match output {
+            Output::Value(value) => {
+                result.insert(ValueKey::try_from(value)?);
+            }
+            Output::Error(error) => return Err(error),
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(result)
+}
+
+fn is_set(value: &KValue) -> bool {
+    matches!(value, KValue::Set(_))
+}
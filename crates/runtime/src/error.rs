@@ -23,6 +23,14 @@ pub enum ErrorKind {
     },
     #[error("Execution timed out (the limit of {} seconds was reached)", .0.as_secs_f64())]
     Timeout(Duration),
+    /// Returned when a script calls `os.exit`, containing the exit code
+    ///
+    /// The error can't be caught by scripts.
+    #[error("The script exited with code {0}")]
+    Exit(i32),
+    /// Returned when an operation isn't allowed by the runtime's [permissions](crate::KotoPermissions)
+    #[error("Permission denied: {0} is not allowed")]
+    PermissionDenied(String),
     #[error("Unable to borrow an object that is already mutably borrowed")]
     UnableToBorrowObject,
     #[error(
@@ -29,6 +29,18 @@ pub trait KotoRead {
     fn read_to_string(&self) -> Result<String> {
         runtime_error!("unsupported for this file type")
     }
+
+    /// Returns up to `count` bytes from the file's current position
+    ///
+    /// An empty result indicates that the end of the file has been reached.
+    fn read_bytes(&self, _count: usize) -> Result<Vec<u8>> {
+        runtime_error!("unsupported for this file type")
+    }
+
+    /// Returns the contents of the file from the current position as bytes
+    fn read_to_end(&self) -> Result<Vec<u8>> {
+        runtime_error!("unsupported for this file type")
+    }
 }
 
 /// A trait that defines the write operations of a [KotoFile]
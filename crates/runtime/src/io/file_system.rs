@@ -0,0 +1,247 @@
+use crate::{core_lib::io::BufferedSystemFile, prelude::*, Ptr, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// A trait that defines the filesystem operations that are available via the `io` module
+///
+/// [DefaultFileSystem] is used by default, which accesses the host's filesystem.
+/// [MemoryFileSystem](crate::MemoryFileSystem) stores files in memory, and a custom implementation
+/// can be provided in [KotoVmSettings] to redirect file access elsewhere, e.g. to an archive.
+pub trait KotoFileSystem: KotoSend + KotoSync {
+    /// Opens the file at the given path for reading
+    fn open(&self, _path: &Path) -> Result<Ptr<dyn KotoFile>> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Creates a file at the given path for writing
+    ///
+    /// If the file already exists then it should be truncated.
+    fn create(&self, _path: &Path) -> Result<Ptr<dyn KotoFile>> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Returns true if a file or directory exists at the given path
+    fn exists(&self, _path: &Path) -> Result<bool> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Removes the file at the given path
+    fn remove_file(&self, _path: &Path) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Returns the entries of the directory at the given path
+    ///
+    /// The entries can be returned in any order.
+    fn read_dir(&self, _path: &Path) -> Result<Vec<DirEntry>> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Returns the metadata of the file or directory at the given path
+    fn metadata(&self, _path: &Path) -> Result<FileMetadata> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Creates a directory at the given path, along with any missing parent directories
+    fn make_dir(&self, _path: &Path) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Removes the directory at the given path
+    ///
+    /// If `recursive` is false then the directory must be empty.
+    fn remove_dir(&self, _path: &Path, _recursive: bool) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Copies the contents of the file at `from` to `to`, replacing any existing file
+    fn copy(&self, _from: &Path, _to: &Path) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Moves the file or directory at `from` to `to`
+    fn rename(&self, _from: &Path, _to: &Path) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Returns the canonical form of the given path, which must exist
+    ///
+    /// This is used by `Path.canonicalize`, and when checking paths against the runtime's
+    /// [permissions](crate::KotoPermissions).
+    fn canonicalize(&self, _path: &Path) -> Result<PathBuf> {
+        runtime_error!("unsupported by this runtime")
+    }
+}
+
+/// An entry in a directory, as returned by [KotoFileSystem::read_dir]
+#[derive(Clone, Debug)]
+pub struct DirEntry {
+    /// The entry's path, made by joining the directory's path with the entry's name
+    pub path: PathBuf,
+    /// True if the entry is a directory, symlinks to directories should be reported as false
+    pub is_dir: bool,
+}
+
+/// The metadata of a file or directory, as returned by [KotoFileSystem::metadata]
+#[derive(Clone, Debug, Default)]
+pub struct FileMetadata {
+    /// True if the path refers to a directory
+    pub is_dir: bool,
+    /// True if the path refers to a file
+    pub is_file: bool,
+    /// The size of the file in bytes
+    pub size: u64,
+    /// The time when the file was last modified, if available
+    pub modified: Option<SystemTime>,
+}
+
+/// The default implementation of [KotoFileSystem], which uses the host's filesystem
+#[derive(Default)]
+pub struct DefaultFileSystem {}
+
+impl KotoFileSystem for DefaultFileSystem {
+    fn open(&self, path: &Path) -> Result<Ptr<dyn KotoFile>> {
+        match fs::canonicalize(path) {
+            Ok(path) => match fs::File::open(&path) {
+                Ok(file) => Ok(make_ptr!(BufferedSystemFile::new(file, path))),
+                Err(error) => runtime_error!("Error while opening path: {error}"),
+            },
+            Err(_) => runtime_error!("Failed to canonicalize path"),
+        }
+    }
+
+    fn create(&self, path: &Path) -> Result<Ptr<dyn KotoFile>> {
+        match fs::File::create(path) {
+            Ok(file) => Ok(make_ptr!(BufferedSystemFile::new(file, path.to_path_buf()))),
+            Err(error) => runtime_error!("Error while creating file: {error}"),
+        }
+    }
+
+    fn exists(&self, path: &Path) -> Result<bool> {
+        Ok(fs::canonicalize(path).is_ok())
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<()> {
+        match fs::remove_file(path) {
+            Ok(_) => Ok(()),
+            Err(error) => runtime_error!(
+                "Error while removing file '{}': {error}",
+                path.to_string_lossy(),
+            ),
+        }
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<DirEntry>> {
+        let read_dir_error = |error| {
+            runtime_error!(
+                "Error while reading directory '{}': {error}",
+                path.to_string_lossy()
+            )
+        };
+
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(error) => return read_dir_error(error),
+        };
+
+        let mut result = Vec::new();
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(error) => return read_dir_error(error),
+            };
+            match entry.file_type() {
+                Ok(file_type) => result.push(DirEntry {
+                    path: entry.path(),
+                    is_dir: file_type.is_dir(),
+                }),
+                Err(error) => return read_dir_error(error),
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+        match fs::metadata(path) {
+            Ok(metadata) => Ok(FileMetadata {
+                is_dir: metadata.is_dir(),
+                is_file: metadata.is_file(),
+                size: metadata.len(),
+                modified: metadata.modified().ok(),
+            }),
+            Err(error) => runtime_error!(
+                "Unable to get metadata for '{}': {error}",
+                path.to_string_lossy()
+            ),
+        }
+    }
+
+    fn make_dir(&self, path: &Path) -> Result<()> {
+        match fs::create_dir_all(path) {
+            Ok(_) => Ok(()),
+            Err(error) => runtime_error!(
+                "Error while creating directory '{}': {error}",
+                path.to_string_lossy()
+            ),
+        }
+    }
+
+    fn remove_dir(&self, path: &Path, recursive: bool) -> Result<()> {
+        let result = if recursive {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_dir(path)
+        };
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => runtime_error!(
+                "Error while removing directory '{}': {error}",
+                path.to_string_lossy()
+            ),
+        }
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        match fs::copy(from, to) {
+            Ok(_) => Ok(()),
+            Err(error) => runtime_error!(
+                "Error while copying '{}' to '{}': {error}",
+                from.to_string_lossy(),
+                to.to_string_lossy()
+            ),
+        }
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        match fs::rename(from, to) {
+            Ok(_) => Ok(()),
+            Err(error) => runtime_error!(
+                "Error while renaming '{}' to '{}': {error}",
+                from.to_string_lossy(),
+                to.to_string_lossy()
+            ),
+        }
+    }
+
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        // An empty path refers to the current directory
+        let path = if path.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            path
+        };
+
+        match dunce::canonicalize(path) {
+            Ok(result) => Ok(result),
+            Err(error) => runtime_error!(
+                "Unable to canonicalize '{}': {error}",
+                path.to_string_lossy()
+            ),
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{
+    core_lib::io::normalize_path as normalize, prelude::*, DirEntry, FileMetadata, KotoFileSystem,
+    Ptr, PtrMut, Result,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+/// An implementation of [KotoFileSystem] that stores files in memory
+///
+/// The files are shared between clones of the filesystem, so a host can keep a clone to prepare
+/// files before running a script, and to inspect the files that were written by the script.
+///
+/// A directory exists if it contains at least one file, or if it has been created with
+/// `io.make_dir`.
+#[derive(Clone)]
+pub struct MemoryFileSystem {
+    files: PtrMut<BTreeMap<PathBuf, PtrMut<Vec<u8>>>>,
+    // Directories that have been created explicitly
+    dirs: PtrMut<BTreeSet<PathBuf>>,
+}
+
+impl MemoryFileSystem {
+    /// Initializes an empty filesystem
+    pub fn new() -> Self {
+        Self {
+            files: BTreeMap::new().into(),
+            dirs: BTreeSet::new().into(),
+        }
+    }
+
+    /// Adds a file with the given contents, replacing any existing file at the path
+    pub fn insert_file(&self, path: impl AsRef<Path>, contents: impl Into<Vec<u8>>) {
+        self.files
+            .borrow_mut()
+            .insert(normalize(path.as_ref()), contents.into().into());
+    }
+
+    /// Returns a copy of the contents of the file at the given path, if it exists
+    pub fn file_contents(&self, path: impl AsRef<Path>) -> Option<Vec<u8>> {
+        self.files
+            .borrow()
+            .get(&normalize(path.as_ref()))
+            .map(|contents| contents.borrow().clone())
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.files.borrow().contains_key(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.dirs.borrow().iter().any(|dir| dir.starts_with(path))
+            || self
+                .files
+                .borrow()
+                .keys()
+                .any(|file| file != path && file.starts_with(path))
+    }
+}
+
+impl KotoFileSystem for MemoryFileSystem {
+    fn open(&self, path: &Path) -> Result<Ptr<dyn KotoFile>> {
+        let path = normalize(path);
+        match self.files.borrow().get(&path) {
+            Some(contents) => Ok(make_ptr!(MemoryFile::new(path, contents.clone()))),
+            None => not_found(&path),
+        }
+    }
+
+    fn create(&self, path: &Path) -> Result<Ptr<dyn KotoFile>> {
+        let path = normalize(path);
+        if self.is_dir(&path) {
+            return runtime_error!("'{}' is a directory", path.to_string_lossy());
+        }
+
+        let contents = self
+            .files
+            .borrow_mut()
+            .entry(path.clone())
+            .or_insert_with(|| Vec::new().into())
+            .clone();
+        contents.borrow_mut().clear();
+
+        Ok(make_ptr!(MemoryFile::new(path, contents)))
+    }
+
+    fn exists(&self, path: &Path) -> Result<bool> {
+        let path = normalize(path);
+        Ok(self.is_file(&path) || self.is_dir(&path))
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<()> {
+        let path = normalize(path);
+        match self.files.borrow_mut().remove(&path) {
+            Some(_) => Ok(()),
+            None => not_found(&path),
+        }
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<DirEntry>> {
+        let path = normalize(path);
+        if !self.is_dir(&path) {
+            return runtime_error!("Directory not found: '{}'", path.to_string_lossy());
+        }
+
+        // Returns the path of the directory's entry that contains the given path
+        let entry_path = |contained: &PathBuf| {
+            let relative = contained.strip_prefix(&path).ok()?;
+            let entry = relative.components().next()?;
+            Some(path.join(entry))
+        };
+
+        let mut entries = BTreeMap::new();
+        for dir in self.dirs.borrow().iter() {
+            if let Some(entry) = entry_path(dir) {
+                entries.insert(entry, true);
+            }
+        }
+        for file in self.files.borrow().keys() {
+            if let Some(entry) = entry_path(file) {
+                // A file that's nested in a subdirectory implies that the entry is a directory
+                let is_dir = entry != *file;
+                *entries.entry(entry).or_default() |= is_dir;
+            }
+        }
+
+        Ok(entries
+            .into_iter()
+            .map(|(path, is_dir)| DirEntry { path, is_dir })
+            .collect())
+    }
+
+    fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+        let path = normalize(path);
+        if let Some(contents) = self.files.borrow().get(&path) {
+            Ok(FileMetadata {
+                is_file: true,
+                size: contents.borrow().len() as u64,
+                ..Default::default()
+            })
+        } else if self.is_dir(&path) {
+            Ok(FileMetadata {
+                is_dir: true,
+                ..Default::default()
+            })
+        } else {
+            not_found(&path)
+        }
+    }
+
+    fn make_dir(&self, path: &Path) -> Result<()> {
+        let path = normalize(path);
+        if let Some(file) = path.ancestors().find(|ancestor| self.is_file(ancestor)) {
+            return runtime_error!("'{}' is a file", file.to_string_lossy());
+        }
+
+        self.dirs.borrow_mut().insert(path);
+        Ok(())
+    }
+
+    fn remove_dir(&self, path: &Path, recursive: bool) -> Result<()> {
+        let path = normalize(path);
+        if !self.is_dir(&path) {
+            return runtime_error!("Directory not found: '{}'", path.to_string_lossy());
+        }
+
+        let contained = |entry: &PathBuf| entry != &path && entry.starts_with(&path);
+        if recursive {
+            self.files.borrow_mut().retain(|file, _| !contained(file));
+            self.dirs.borrow_mut().retain(|dir| !dir.starts_with(&path));
+        } else if self.files.borrow().keys().any(contained)
+            || self.dirs.borrow().iter().any(contained)
+        {
+            return runtime_error!("Directory not empty: '{}'", path.to_string_lossy());
+        } else {
+            self.dirs.borrow_mut().remove(&path);
+        }
+
+        Ok(())
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        let from = normalize(from);
+        let contents = match self.files.borrow().get(&from) {
+            Some(contents) => contents.borrow().clone(),
+            None => return not_found(&from),
+        };
+
+        let file = self.create(to)?;
+        file.write(&contents)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let from = normalize(from);
+        let to = normalize(to);
+
+        if self.is_dir(&to) {
+            return runtime_error!("'{}' is a directory", to.to_string_lossy());
+        }
+
+        let removed_file = self.files.borrow_mut().remove(&from);
+        if let Some(contents) = removed_file {
+            self.files.borrow_mut().insert(to, contents);
+            return Ok(());
+        }
+
+        if !self.is_dir(&from) {
+            return not_found(&from);
+        }
+        if to.starts_with(&from) {
+            return runtime_error!("Unable to move '{}' into itself", from.to_string_lossy());
+        }
+
+        // Returns the new location of an entry that's being moved
+        let moved = |entry: &Path| to.join(entry.strip_prefix(&from).unwrap_or(entry));
+
+        let mut files = self.files.borrow_mut();
+        let moved_files = files
+            .keys()
+            .filter(|file| file.starts_with(&from))
+            .cloned()
+            .collect::<Vec<_>>();
+        for file in moved_files {
+            if let Some(contents) = files.remove(&file) {
+                files.insert(moved(&file), contents);
+            }
+        }
+
+        let mut dirs = self.dirs.borrow_mut();
+        let moved_dirs = dirs
+            .iter()
+            .filter(|dir| dir.starts_with(&from))
+            .cloned()
+            .collect::<Vec<_>>();
+        for dir in moved_dirs {
+            dirs.remove(&dir);
+            dirs.insert(moved(&dir));
+        }
+
+        Ok(())
+    }
+
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        let path = normalize(path);
+        // An empty path refers to the filesystem's root
+        if path.as_os_str().is_empty() || self.is_file(&path) || self.is_dir(&path) {
+            Ok(path)
+        } else {
+            not_found(&path)
+        }
+    }
+}
+
+impl Default for MemoryFileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn not_found<T>(path: &Path) -> Result<T> {
+    runtime_error!("File not found: '{}'", path.to_string_lossy())
+}
+
+// A file that has been opened in a [MemoryFileSystem]
+struct MemoryFile {
+    path: PathBuf,
+    contents: PtrMut<Vec<u8>>,
+    position: KCell<usize>,
+}
+
+impl MemoryFile {
+    fn new(path: PathBuf, contents: PtrMut<Vec<u8>>) -> Self {
+        Self {
+            path,
+            contents,
+            position: 0.into(),
+        }
+    }
+
+    // Reads up to `count` bytes from the current position, or to the end of the file if no
+    // count is provided
+    fn read(&self, count: Option<usize>) -> Vec<u8> {
+        let contents = self.contents.borrow();
+        let mut position = self.position.borrow_mut();
+
+        let start = (*position).min(contents.len());
+        let end = match count {
+            Some(count) => start.saturating_add(count).min(contents.len()),
+            None => contents.len(),
+        };
+        *position = end;
+
+        contents[start..end].to_vec()
+    }
+}
+
+impl KotoFile for MemoryFile {
+    fn id(&self) -> KString {
+        self.path.to_string_lossy().to_string().into()
+    }
+
+    fn path(&self) -> Result<KString> {
+        Ok(self.id())
+    }
+
+    fn seek(&self, position: u64) -> Result<()> {
+        // Seeking past the end of the file isn't supported, which prevents scripts from growing
+        // the file to an arbitrary size with a subsequent write.
+        let len = self.contents.borrow().len();
+        match usize::try_from(position) {
+            Ok(position) if position <= len => {
+                *self.position.borrow_mut() = position;
+                Ok(())
+            }
+            _ => {
+                runtime_error!("Seek position {position} is past the end of the file ({len} bytes)")
+            }
+        }
+    }
+}
+
+impl KotoRead for MemoryFile {
+    fn read_line(&self) -> Result<Option<String>> {
+        let count = {
+            let contents = self.contents.borrow();
+            let start = (*self.position.borrow()).min(contents.len());
+            match contents[start..].iter().position(|byte| *byte == b'\n') {
+                Some(newline) => newline + 1,
+                None => contents.len() - start,
+            }
+        };
+
+        if count == 0 {
+            return Ok(None);
+        }
+
+        match String::from_utf8(self.read(Some(count))) {
+            Ok(line) => Ok(Some(line)),
+            Err(_) => runtime_error!("Failed to read line: the file contains invalid UTF-8"),
+        }
+    }
+
+    fn read_to_string(&self) -> Result<String> {
+        match String::from_utf8(self.read(None)) {
+            Ok(result) => Ok(result),
+            Err(_) => runtime_error!("Failed to read file: the file contains invalid UTF-8"),
+        }
+    }
+
+    fn read_bytes(&self, count: usize) -> Result<Vec<u8>> {
+        Ok(self.read(Some(count)))
+    }
+
+    fn read_to_end(&self) -> Result<Vec<u8>> {
+        Ok(self.read(None))
+    }
+}
+
+impl KotoWrite for MemoryFile {
+    fn write(&self, bytes: &[u8]) -> Result<()> {
+        let mut contents = self.contents.borrow_mut();
+        let mut position = self.position.borrow_mut();
+
+        let Some(end) = position.checked_add(bytes.len()) else {
+            return runtime_error!("The file's size limit has been exceeded");
+        };
+        if end > contents.len() {
+            contents.resize(end, 0);
+        }
+        contents[*position..end].copy_from_slice(bytes);
+        *position = end;
+
+        Ok(())
+    }
+
+    fn write_line(&self, text: &str) -> Result<()> {
+        self.write(text.as_bytes())?;
+        self.write(b"\n")
+    }
+
+    fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
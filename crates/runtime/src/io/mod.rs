@@ -1,9 +1,15 @@
 mod buffered_file;
 mod file;
+mod file_system;
+mod memory_file_system;
+mod os;
 mod stdio;
 
 pub use self::{
     buffered_file::BufferedFile,
     file::{KotoFile, KotoRead, KotoWrite},
+    file_system::{DefaultFileSystem, DirEntry, FileMetadata, KotoFileSystem},
+    memory_file_system::MemoryFileSystem,
+    os::{DefaultOs, KotoOs},
     stdio::{DefaultStderr, DefaultStdin, DefaultStdout},
 };
@@ -0,0 +1,119 @@
+use crate::{prelude::*, ErrorKind, Result};
+use std::time::Duration;
+
+/// A trait that defines the operating system interactions that are available via the `os` module
+///
+/// [DefaultOs] is used by default, which interacts with the host's operating system.
+/// A custom implementation can be provided in [KotoVmSettings] to intercept or sandbox scripts.
+pub trait KotoOs: KotoSend + KotoSync {
+    /// Returns the value of an environment variable, or None if it isn't set
+    fn env_var(&self, _key: &str) -> Result<Option<String>> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Returns all of the environment variables as key/value pairs
+    fn env_vars(&self) -> Result<Vec<(String, String)>> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Sets the value of an environment variable
+    fn set_env_var(&self, _key: &str, _value: &str) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Removes an environment variable
+    fn remove_env_var(&self, _key: &str) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Called when a script calls `os.exit`
+    ///
+    /// By default an [ErrorKind::Exit] error is returned, which can't be caught by scripts, and
+    /// which unwinds the runtime's call stack so that it can be handled by the host.
+    fn exit(&self, code: i32) -> Result<()> {
+        runtime_error!(ErrorKind::Exit(code))
+    }
+
+    /// Returns the id of the current process
+    fn process_id(&self) -> Result<u32> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Returns the name of the host machine
+    fn hostname(&self) -> Result<String> {
+        runtime_error!("unsupported by this runtime")
+    }
+
+    /// Blocks the current thread for the given duration
+    fn sleep(&self, _duration: Duration) -> Result<()> {
+        runtime_error!("unsupported by this runtime")
+    }
+}
+
+/// The default implementation of [KotoOs], which uses the host's operating system
+#[derive(Default)]
+pub struct DefaultOs {}
+
+impl KotoOs for DefaultOs {
+    fn env_var(&self, key: &str) -> Result<Option<String>> {
+        Ok(std::env::var_os(key).map(|value| value.to_string_lossy().to_string()))
+    }
+
+    fn env_vars(&self) -> Result<Vec<(String, String)>> {
+        Ok(std::env::vars_os()
+            .map(|(key, value)| {
+                (
+                    key.to_string_lossy().to_string(),
+                    value.to_string_lossy().to_string(),
+                )
+            })
+            .collect())
+    }
+
+    fn set_env_var(&self, key: &str, value: &str) -> Result<()> {
+        check_env_var_key(key)?;
+        if value.contains('\0') {
+            return runtime_error!("Environment variable values can't contain null characters");
+        }
+        std::env::set_var(key, value);
+        Ok(())
+    }
+
+    fn remove_env_var(&self, key: &str) -> Result<()> {
+        check_env_var_key(key)?;
+        std::env::remove_var(key);
+        Ok(())
+    }
+
+    fn process_id(&self) -> Result<u32> {
+        Ok(std::process::id())
+    }
+
+    fn hostname(&self) -> Result<String> {
+        hostname()
+    }
+
+    fn sleep(&self, duration: Duration) -> Result<()> {
+        std::thread::sleep(duration);
+        Ok(())
+    }
+}
+
+// std::env::set_var and remove_var panic with invalid keys, so they're checked up front
+fn check_env_var_key(key: &str) -> Result<()> {
+    if key.is_empty() || key.contains(['=', '\0']) {
+        runtime_error!("Invalid environment variable name '{key}'")
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn hostname() -> Result<String> {
+    Ok(gethostname::gethostname().to_string_lossy().to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn hostname() -> Result<String> {
+    runtime_error!("unsupported on this platform")
+}
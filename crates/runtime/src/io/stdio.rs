@@ -27,6 +27,25 @@ impl KotoRead for DefaultStdin {
             .map_err(map_io_err)?;
         Ok(result)
     }
+
+    fn read_bytes(&self, count: usize) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        io::stdin()
+            .lock()
+            .take(count as u64)
+            .read_to_end(&mut result)
+            .map_err(map_io_err)?;
+        Ok(result)
+    }
+
+    fn read_to_end(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        io::stdin()
+            .lock()
+            .read_to_end(&mut result)
+            .map_err(map_io_err)?;
+        Ok(result)
+    }
 }
 
 /// The default stdout used in Koto
@@ -5,12 +5,15 @@
 mod display_context;
 mod error;
 mod io;
+mod log_sink;
+mod permissions;
 mod types;
 mod vm;
 
 pub mod core_lib;
 pub mod prelude;
 mod send_sync;
+mod type_hint;
 
 pub use crate::{
     display_context::DisplayContext,
@@ -18,13 +21,19 @@ pub use crate::{
         unexpected_args, unexpected_args_after_instance, unexpected_type, Error, ErrorFrame,
         ErrorKind, Result,
     },
-    io::{BufferedFile, DefaultStderr, DefaultStdin, DefaultStdout, KotoFile, KotoRead, KotoWrite},
+    io::{
+        BufferedFile, DefaultFileSystem, DefaultOs, DefaultStderr, DefaultStdin, DefaultStdout,
+        DirEntry, FileMetadata, KotoFile, KotoFileSystem, KotoOs, KotoRead, KotoWrite,
+        MemoryFileSystem,
+    },
+    log_sink::{KotoLogSink, LogLevel, LogRecord},
+    permissions::{KotoPermissions, PathAccess},
     send_sync::{KotoSend, KotoSync},
     types::{
         BinaryOp, CallContext, IsIterable, KCaptureFunction, KFunction, KIterator, KIteratorOutput,
-        KList, KMap, KNativeFunction, KNumber, KObject, KRange, KString, KTuple, KValue, KotoCopy,
-        KotoEntries, KotoField, KotoFunction, KotoHasher, KotoIterator, KotoObject, KotoType,
-        MetaKey, MetaMap, MethodContext, UnaryOp, ValueKey, ValueMap, ValueVec,
+        KList, KMap, KNativeFunction, KNumber, KObject, KRange, KSet, KString, KTuple, KValue,
+        KotoCopy, KotoEntries, KotoField, KotoFunction, KotoHasher, KotoIterator, KotoObject,
+        KotoType, MetaKey, MetaMap, MethodContext, UnaryOp, ValueKey, ValueMap, ValueSet, ValueVec,
     },
     vm::{CallArgs, KotoVm, KotoVmSettings, ModuleImportedCallback, ReturnOrYield},
 };
@@ -0,0 +1,87 @@
+use crate::{KotoSend, KotoSync, Result};
+use std::fmt;
+
+/// A trait that receives the messages that are logged with the `log` module
+///
+/// If no sink is provided in [KotoVmSettings](crate::KotoVmSettings) then messages are written to
+/// the runtime's stderr. A custom sink allows hosts to route script logs into their own logging
+/// infrastructure.
+pub trait KotoLogSink: KotoSend + KotoSync {
+    /// Called when a script logs a message
+    fn log(&self, record: &LogRecord) -> Result<()>;
+
+    /// Returns true if messages at the given level should be logged
+    ///
+    /// Records are only prepared and passed to [KotoLogSink::log] for enabled levels.
+    fn is_enabled(&self, _level: LogLevel) -> bool {
+        true
+    }
+}
+
+/// The level of a logged message
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Returns the level's name as used in the `log` module
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A message that has been logged by a script
+#[derive(Clone, Debug)]
+pub struct LogRecord {
+    /// The message's level
+    pub level: LogLevel,
+    /// The message, rendered as a string
+    pub message: String,
+    /// Key/value fields that were provided with the message, with the values rendered as strings
+    pub fields: Vec<(String, String)>,
+}
+
+impl fmt::Display for LogRecord {
+    /// Formats the record as a single line, with fields written as `key=value` pairs
+    ///
+    /// Messages containing newlines or other control characters are quoted, as are field values
+    /// containing whitespace, quotes, or `=`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.message.chars().any(char::is_control) {
+            write!(f, "[{}] {:?}", self.level, self.message)?;
+        } else {
+            write!(f, "[{}] {}", self.level, self.message)?;
+        }
+
+        for (key, value) in self.fields.iter() {
+            let needs_quotes = value.is_empty()
+                || value
+                    .chars()
+                    .any(|c| c.is_whitespace() || c == '"' || c == '=');
+
+            if needs_quotes {
+                write!(f, " {key}={value:?}")?;
+            } else {
+                write!(f, " {key}={value}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
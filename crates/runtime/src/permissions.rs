@@ -0,0 +1,158 @@
+use crate::{ErrorKind, KotoFileSystem, Result};
+use std::path::{Path, PathBuf};
+
+/// The permissions that are granted to scripts for accessing the host system
+///
+/// The permissions are consulted by the `io` and `os` core library modules, with a
+/// [PermissionDenied](ErrorKind::PermissionDenied) error being thrown when an operation isn't
+/// allowed.
+///
+/// By default all permissions are granted, [KotoPermissions::none] can be used as a starting point
+/// for granting limited permissions to untrusted scripts.
+#[derive(Clone, Debug)]
+pub struct KotoPermissions {
+    /// The paths that scripts are allowed to read from
+    pub read: PathAccess,
+    /// The paths that scripts are allowed to write to, create, or remove
+    pub write: PathAccess,
+    /// Whether or not scripts are allowed to spawn processes with `os.command` and `os.run`
+    pub allow_process_spawning: bool,
+    /// Whether or not scripts are allowed to access environment variables
+    pub allow_env_access: bool,
+}
+
+impl KotoPermissions {
+    /// Returns permissions that allow full access to the host system
+    pub fn all() -> Self {
+        Self {
+            read: PathAccess::Any,
+            write: PathAccess::Any,
+            allow_process_spawning: true,
+            allow_env_access: true,
+        }
+    }
+
+    /// Returns permissions that deny any access to the host system
+    pub fn none() -> Self {
+        Self {
+            read: PathAccess::none(),
+            write: PathAccess::none(),
+            allow_process_spawning: false,
+            allow_env_access: false,
+        }
+    }
+
+    /// Returns an error if reading from the given path isn't allowed
+    ///
+    /// The path is resolved using the given filesystem.
+    pub fn check_read(&self, path: &Path, file_system: &dyn KotoFileSystem) -> Result<()> {
+        if self.read.allows(path, file_system) {
+            Ok(())
+        } else {
+            permission_denied(format!("reading from '{}'", path.to_string_lossy()))
+        }
+    }
+
+    /// Returns an error if writing to the given path isn't allowed
+    ///
+    /// The path is resolved using the given filesystem.
+    pub fn check_write(&self, path: &Path, file_system: &dyn KotoFileSystem) -> Result<()> {
+        if self.write.allows(path, file_system) {
+            Ok(())
+        } else {
+            permission_denied(format!("writing to '{}'", path.to_string_lossy()))
+        }
+    }
+
+    /// Returns an error if spawning processes isn't allowed
+    pub fn check_process_spawning(&self) -> Result<()> {
+        if self.allow_process_spawning {
+            Ok(())
+        } else {
+            permission_denied("spawning processes".into())
+        }
+    }
+
+    /// Returns an error if accessing environment variables isn't allowed
+    pub fn check_env_access(&self) -> Result<()> {
+        if self.allow_env_access {
+            Ok(())
+        } else {
+            permission_denied("accessing environment variables".into())
+        }
+    }
+}
+
+impl Default for KotoPermissions {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Defines the paths that can be accessed by scripts
+#[derive(Clone, Debug)]
+pub enum PathAccess {
+    /// Any path can be accessed
+    Any,
+    /// Only paths contained in the given directories can be accessed
+    ///
+    /// Paths are resolved before being checked, so symlinks and `..` components can't be used to
+    /// reach paths outside of the directories.
+    Within(Vec<PathBuf>),
+}
+
+impl PathAccess {
+    /// Returns a [PathAccess] that doesn't allow access to any paths
+    pub fn none() -> Self {
+        Self::Within(Vec::new())
+    }
+
+    /// Returns true if the given path can be accessed
+    ///
+    /// The path and the allowed directories are resolved using the given filesystem.
+    pub fn allows(&self, path: &Path, file_system: &dyn KotoFileSystem) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Within(roots) => {
+                let Some(path) = resolve_path(path, file_system) else {
+                    return false;
+                };
+                roots
+                    .iter()
+                    .any(|root| match resolve_path(root, file_system) {
+                        Some(root) => path.starts_with(root),
+                        None => false,
+                    })
+            }
+        }
+    }
+}
+
+// Returns the canonical form of the path
+//
+// The path might not exist yet (e.g. when creating a file), so the path's nearest existing
+// ancestor is canonicalized and the remaining components are appended to the result.
+fn resolve_path(path: &Path, file_system: &dyn KotoFileSystem) -> Option<PathBuf> {
+    let mut existing = path;
+    let mut remaining = Vec::new();
+    loop {
+        if let Ok(mut result) = file_system.canonicalize(existing) {
+            for component in remaining.iter().rev() {
+                match component {
+                    // Parent components can't be applied reliably to paths that don't exist
+                    std::path::Component::ParentDir => return None,
+                    std::path::Component::CurDir => {}
+                    other => result.push(other),
+                }
+            }
+            return Some(result);
+        }
+
+        remaining.extend(existing.components().next_back());
+        existing = existing.parent()?;
+    }
+}
+
+fn permission_denied(operation: String) -> Result<()> {
+    Err(ErrorKind::PermissionDenied(operation).into())
+}
@@ -4,8 +4,9 @@
 pub use crate::{
     make_ptr, make_ptr_mut, runtime_error, unexpected_args, unexpected_args_after_instance,
     unexpected_type, BinaryOp, CallArgs, CallContext, DisplayContext, IsIterable, KCell, KIterator,
-    KIteratorOutput, KList, KMap, KNativeFunction, KNumber, KObject, KRange, KString, KTuple,
-    KValue, KotoCopy, KotoEntries, KotoField, KotoFile, KotoFunction, KotoHasher, KotoIterator,
-    KotoObject, KotoRead, KotoSend, KotoSync, KotoType, KotoVm, KotoVmSettings, KotoWrite, MetaKey,
-    MetaMap, MethodContext, UnaryOp, ValueKey, ValueMap, ValueVec,
+    KIteratorOutput, KList, KMap, KNativeFunction, KNumber, KObject, KRange, KSet, KString, KTuple,
+    KValue, KotoCopy, KotoEntries, KotoField, KotoFile, KotoFileSystem, KotoFunction, KotoHasher,
+    KotoIterator, KotoLogSink, KotoObject, KotoOs, KotoPermissions, KotoRead, KotoSend, KotoSync,
+    KotoType, KotoVm, KotoVmSettings, KotoWrite, MetaKey, MetaMap, MethodContext, UnaryOp,
+    ValueKey, ValueMap, ValueSet, ValueVec,
 };
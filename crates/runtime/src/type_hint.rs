@@ -0,0 +1,219 @@
+/// A type hint that has been parsed from its string representation
+///
+/// Type hints are stored by the compiler as strings in the chunk's constant pool,
+/// e.g. `Map<String, List<Number>>?` or `Number | String`. Hints that refer to a single type are
+/// checked directly, while hints with nested, optional, or union types are parsed into a
+/// `TypeHint` before checking. Parsed hints are cached by the runtime, so each hint is only
+/// parsed once.
+#[derive(Debug, PartialEq)]
+pub(crate) enum TypeHint {
+    /// A single type, with optional nested types
+    Type {
+        name: String,
+        /// The index of the name among the distinct names in the hint, in order of appearance
+        ///
+        /// The index is used to find the value that was bound to the name when the hint was
+        /// checked, e.g. an interface.
+        binding: usize,
+        nested: Vec<TypeHint>,
+        optional: bool,
+    },
+    /// A union of types
+    Union(Vec<TypeHint>),
+}
+
+impl TypeHint {
+    /// Returns true if the hint is a single type without nested or optional types
+    pub fn is_simple(hint: &str) -> bool {
+        !hint.contains(['<', '?', '|'])
+    }
+
+    /// Parses the type hint from a string
+    ///
+    /// None is returned if the string isn't a valid type hint.
+    pub fn parse(hint: &str) -> Option<Self> {
+        let mut parser = TypeHintParser {
+            input: hint,
+            position: 0,
+            names: Vec::new(),
+        };
+        let result = parser.parse_union()?;
+        parser.skip_whitespace();
+        (parser.position == hint.len()).then_some(result)
+    }
+}
+
+struct TypeHintParser<'a> {
+    input: &'a str,
+    position: usize,
+    // The distinct names that have been encountered so far
+    names: Vec<&'a str>,
+}
+
+impl<'a> TypeHintParser<'a> {
+    fn parse_union(&mut self) -> Option<TypeHint> {
+        let first = self.parse_type()?;
+
+        if self.peek() != Some('|') {
+            return Some(first);
+        }
+
+        let mut types = vec![first];
+        while self.peek() == Some('|') {
+            self.advance(); // |
+            types.push(self.parse_type()?);
+        }
+
+        Some(TypeHint::Union(types))
+    }
+
+    fn parse_type(&mut self) -> Option<TypeHint> {
+        let name = self.parse_name()?;
+        let binding = match self.names.iter().position(|existing| *existing == name) {
+            Some(binding) => binding,
+            None => {
+                self.names.push(name);
+                self.names.len() - 1
+            }
+        };
+
+        let mut nested = Vec::new();
+        if self.peek() == Some('<') {
+            self.advance(); // <
+            loop {
+                nested.push(self.parse_union()?);
+                match self.peek()? {
+                    ',' => self.advance(),
+                    '>' => {
+                        self.advance();
+                        break;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+
+        let optional = if self.peek() == Some('?') {
+            self.advance();
+            true
+        } else {
+            false
+        };
+
+        Some(TypeHint::Type {
+            name: name.to_string(),
+            binding,
+            nested,
+            optional,
+        })
+    }
+
+    fn parse_name(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let end = self.input[start..]
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | ',' | '|' | '?'))
+            .map_or(self.input.len(), |offset| start + offset);
+
+        if end > start {
+            self.position = end;
+            Some(&self.input[start..end])
+        } else {
+            None
+        }
+    }
+
+    // Returns the next non-whitespace character without consuming it
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.position..].chars().next()
+    }
+
+    // Consumes the next character, which is expected to be a single-byte symbol
+    fn advance(&mut self) {
+        self.position += 1;
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = &self.input[self.position..];
+        self.position += remaining.len() - remaining.trim_start().len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simple(name: &str, binding: usize) -> TypeHint {
+        TypeHint::Type {
+            name: name.into(),
+            binding,
+            nested: Vec::new(),
+            optional: false,
+        }
+    }
+
+    #[test]
+    fn nested_and_optional() {
+        assert_eq!(
+            TypeHint::parse("Map<String, List<Number>>?"),
+            Some(TypeHint::Type {
+                name: "Map".into(),
+                binding: 0,
+                nested: vec![
+                    simple("String", 1),
+                    TypeHint::Type {
+                        name: "List".into(),
+                        binding: 2,
+                        nested: vec![simple("Number", 3)],
+                        optional: false,
+                    }
+                ],
+                optional: true,
+            })
+        );
+    }
+
+    #[test]
+    fn union_with_nested_union() {
+        assert_eq!(
+            TypeHint::parse("List<Number | String> | Null"),
+            Some(TypeHint::Union(vec![
+                TypeHint::Type {
+                    name: "List".into(),
+                    binding: 0,
+                    nested: vec![TypeHint::Union(vec![
+                        simple("Number", 1),
+                        simple("String", 2)
+                    ])],
+                    optional: false,
+                },
+                simple("Null", 3),
+            ]))
+        );
+    }
+
+    #[test]
+    fn repeated_names_share_a_binding() {
+        assert_eq!(
+            TypeHint::parse("Map<Foo, Foo> | Foo"),
+            Some(TypeHint::Union(vec![
+                TypeHint::Type {
+                    name: "Map".into(),
+                    binding: 0,
+                    nested: vec![simple("Foo", 1), simple("Foo", 1)],
+                    optional: false,
+                },
+                simple("Foo", 1),
+            ]))
+        );
+    }
+
+    #[test]
+    fn invalid_hints() {
+        assert_eq!(TypeHint::parse("List<Number"), None);
+        assert_eq!(TypeHint::parse("Number |"), None);
+        assert_eq!(TypeHint::parse("<Number>"), None);
+    }
+}
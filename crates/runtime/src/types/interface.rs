@@ -0,0 +1,67 @@
+use crate::{prelude::*, Ptr, Result};
+use koto_derive::{KotoCopy, KotoType};
+
+/// An interface, made by `interface` declarations
+///
+/// An interface declares a set of keys and meta keys that values need to provide to conform to
+/// the interface. An interface can be used as a type hint via any name that it's assigned to,
+/// following the same scoping rules as other values.
+#[derive(Clone, KotoCopy, KotoType)]
+#[koto(type_name = "Interface")]
+pub(crate) struct Interface(Ptr<InterfaceDefinition>);
+
+struct InterfaceDefinition {
+    name: KString,
+    keys: Vec<ValueKey>,
+    meta_keys: Vec<MetaKey>,
+}
+
+impl Interface {
+    /// Makes an interface with the given required keys and meta keys
+    pub fn new(name: KString, keys: Vec<ValueKey>, meta_keys: Vec<MetaKey>) -> Self {
+        Self(Ptr::from(InterfaceDefinition {
+            name,
+            keys,
+            meta_keys,
+        }))
+    }
+
+    /// The keys that conforming values need to provide
+    pub fn keys(&self) -> &[ValueKey] {
+        &self.0.keys
+    }
+
+    /// The meta keys that conforming values need to provide
+    pub fn meta_keys(&self) -> &[MetaKey] {
+        &self.0.meta_keys
+    }
+}
+
+impl KotoEntries for Interface {}
+
+impl KotoObject for Interface {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(self.0.name.clone());
+        Ok(())
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        let result = match rhs {
+            KValue::Object(o) => o
+                .cast::<Self>()
+                .is_ok_and(|other| Ptr::ptr_eq(&self.0, &other.0)),
+            _ => false,
+        };
+        Ok(result)
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+}
+
+impl From<Interface> for KValue {
+    fn from(interface: Interface) -> Self {
+        KObject::from(interface).into()
+    }
+}
@@ -110,6 +110,11 @@ impl KIterator {
         Self::new(MapIterator::new(map))
     }
 
+    /// Creates a new KIterator from a Set
+    pub fn with_set(set: KSet) -> Self {
+        Self::new(SetIterator::new(set))
+    }
+
     /// Creates a new KIterator from a String
     pub fn with_string(s: KString) -> Self {
         Self::new(StringIterator::new(s))
@@ -401,6 +406,69 @@ impl Iterator for MapIterator {
     }
 }
 
+#[derive(Clone)]
+struct SetIterator {
+    data: KSet,
+    index: usize,
+    end: usize,
+}
+
+impl SetIterator {
+    fn new(data: KSet) -> Self {
+        let end = data.len();
+        Self {
+            data,
+            index: 0,
+            end,
+        }
+    }
+
+    fn get_output(&self, index: usize) -> Option<KIteratorOutput> {
+        self.data
+            .data()
+            .get_index(index)
+            .map(|key| KIteratorOutput::Value(key.value().clone()))
+    }
+}
+
+impl KotoIterator for SetIterator {
+    fn make_copy(&self) -> Result<KIterator> {
+        Ok(KIterator::new(self.clone()))
+    }
+
+    fn is_bidirectional(&self) -> bool {
+        true
+    }
+
+    fn next_back(&mut self) -> Option<KIteratorOutput> {
+        if self.end > self.index {
+            self.end -= 1;
+            self.get_output(self.end)
+        } else {
+            None
+        }
+    }
+}
+
+impl Iterator for SetIterator {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end > self.index {
+            let result = self.get_output(self.index);
+            self.index += 1;
+            result
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end.saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
 #[derive(Clone)]
 struct MetaIterator {
     vm: KotoVm,
@@ -5,22 +5,35 @@ pub type ValueVec = smallvec::SmallVec<[KValue; 4]>;
 
 /// The List type used by the Koto runtime
 #[derive(Clone, Default)]
-pub struct KList(PtrMut<ValueVec>);
+pub struct KList(PtrMut<ListData>);
+
+// The list's entries, along with a flag that's set when the list has been frozen
+#[derive(Default)]
+struct ListData {
+    values: ValueVec,
+    frozen: bool,
+}
 
 impl KList {
     /// Creates an empty list with the given capacity
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(ValueVec::with_capacity(capacity).into())
+        Self::with_data(ValueVec::with_capacity(capacity))
     }
 
     /// Creates a list containing the provided data
-    pub fn with_data(data: ValueVec) -> Self {
-        Self(data.into())
+    pub fn with_data(values: ValueVec) -> Self {
+        Self(
+            ListData {
+                values,
+                frozen: false,
+            }
+            .into(),
+        )
     }
 
     /// Creates a list containing the provided slice of [Values](crate::KValue)
     pub fn from_slice(data: &[KValue]) -> Self {
-        Self(data.iter().cloned().collect::<ValueVec>().into())
+        Self::with_data(data.iter().cloned().collect())
     }
 
     /// Returns the number of entries of the list
@@ -35,12 +48,44 @@ impl KList {
 
     /// Returns a reference to the list's entries
     pub fn data(&self) -> Borrow<ValueVec> {
-        self.0.borrow()
+        Borrow::map(self.0.borrow(), |data| &data.values)
     }
 
     /// Returns a mutable reference to the list's entries
+    ///
+    /// Note that this doesn't check if the list has been frozen,
+    /// see [KList::checked_data_mut].
     pub fn data_mut(&self) -> BorrowMut<ValueVec> {
-        self.0.borrow_mut()
+        BorrowMut::map(self.0.borrow_mut(), |data| &mut data.values)
+    }
+
+    /// Returns a mutable reference to the list's entries, or an error if the list is frozen
+    pub fn checked_data_mut(&self) -> Result<BorrowMut<'_, ValueVec>> {
+        if self.is_frozen() {
+            runtime_error!("Unable to modify a frozen List")
+        } else {
+            Ok(self.data_mut())
+        }
+    }
+
+    /// Returns true if the list has been frozen
+    pub fn is_frozen(&self) -> bool {
+        self.0.borrow().frozen
+    }
+
+    /// Freezes the list, preventing further modifications
+    ///
+    /// Any lists or maps contained in the list will also be frozen.
+    pub fn freeze(&self) {
+        if self.is_frozen() {
+            return;
+        }
+
+        self.0.borrow_mut().frozen = true;
+
+        for value in self.data().iter() {
+            value.freeze();
+        }
     }
 
     /// Renders the list to the provided display context
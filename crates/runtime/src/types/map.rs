@@ -61,10 +61,17 @@ impl FromIterator<(ValueKey, KValue)> for ValueMap {
 /// The core hash map value type used in Koto, containing a [ValueMap] and a [MetaMap]
 #[derive(Clone, Default)]
 pub struct KMap {
-    data: PtrMut<ValueMap>,
+    data: PtrMut<MapData>,
     meta: Option<PtrMut<MetaMap>>,
 }
 
+// The map's data, along with a flag that's set when the map has been frozen
+#[derive(Default)]
+struct MapData {
+    values: ValueMap,
+    frozen: bool,
+}
+
 impl KMap {
     /// Creates an empty KMap
     pub fn new() -> Self {
@@ -91,7 +98,11 @@ impl KMap {
     /// Creates a KMap initialized with the provided data and meta map
     pub fn with_contents(data: ValueMap, meta: Option<MetaMap>) -> Self {
         Self {
-            data: data.into(),
+            data: MapData {
+                values: data,
+                frozen: false,
+            }
+            .into(),
             meta: meta.map(PtrMut::from),
         }
     }
@@ -106,12 +117,43 @@ impl KMap {
 
     /// Provides a reference to the data map
     pub fn data(&self) -> Borrow<ValueMap> {
-        self.data.borrow()
+        Borrow::map(self.data.borrow(), |data| &data.values)
     }
 
     /// Provides a mutable reference to the data map
+    ///
+    /// Note that this doesn't check if the map has been frozen, see [KMap::checked_data_mut].
     pub fn data_mut(&self) -> BorrowMut<ValueMap> {
-        self.data.borrow_mut()
+        BorrowMut::map(self.data.borrow_mut(), |data| &mut data.values)
+    }
+
+    /// Provides a mutable reference to the data map, or an error if the map is frozen
+    pub fn checked_data_mut(&self) -> Result<BorrowMut<'_, ValueMap>> {
+        if self.is_frozen() {
+            runtime_error!("Unable to modify a frozen Map")
+        } else {
+            Ok(self.data_mut())
+        }
+    }
+
+    /// Returns true if the map has been frozen
+    pub fn is_frozen(&self) -> bool {
+        self.data.borrow().frozen
+    }
+
+    /// Freezes the map, preventing further modifications to its data
+    ///
+    /// Any lists or maps contained in the map's data will also be frozen.
+    pub fn freeze(&self) {
+        if self.is_frozen() {
+            return;
+        }
+
+        self.data.borrow_mut().frozen = true;
+
+        for value in self.data().values() {
+            value.freeze();
+        }
     }
 
     /// Provides a reference to the KMap's meta map
@@ -140,7 +182,7 @@ impl KMap {
     where
         K: Hash + Equivalent<ValueKey> + ?Sized,
     {
-        self.data.borrow().get(key).cloned()
+        self.data().get(key).cloned()
     }
 
     /// Returns a clone of the meta value corresponding to the given key
@@ -58,6 +58,11 @@ pub enum MetaKey {
     ///
     /// Defines the behaviour when performing a function call on the value.
     Call,
+    /// `@format`
+    ///
+    /// Defines how the value should be rendered in an interpolated string with format options,
+    /// with the format string passed as an argument.
+    Format,
     /// A named key
     ///
     /// e.g. `@meta my_named_key`
@@ -132,8 +137,22 @@ pub enum BinaryOp {
     Multiply,
     /// `@/`
     Divide,
+    /// `@//`
+    IntegerDivide,
     /// `@%`
     Remainder,
+    /// `@**`
+    Power,
+    /// `@&`
+    BitwiseAnd,
+    /// `@|`
+    BitwiseOr,
+    /// `@^`
+    BitwiseXor,
+    /// `@<<`
+    ShiftLeft,
+    /// `@>>`
+    ShiftRight,
     /// `@+=`
     AddAssign,
     /// `@-=`
@@ -172,7 +191,14 @@ impl fmt::Display for BinaryOp {
                 Subtract => "-",
                 Multiply => "*",
                 Divide => "/",
+                IntegerDivide => "//",
                 Remainder => "%",
+                Power => "**",
+                BitwiseAnd => "&",
+                BitwiseOr => "|",
+                BitwiseXor => "^",
+                ShiftLeft => "<<",
+                ShiftRight => ">>",
                 AddAssign => "+=",
                 SubtractAssign => "-=",
                 MultiplyAssign => "*=",
@@ -219,7 +245,14 @@ pub fn meta_id_to_key(id: MetaKeyId, name: Option<KString>) -> Result<MetaKey> {
         MetaKeyId::Subtract => MetaKey::BinaryOp(Subtract),
         MetaKeyId::Multiply => MetaKey::BinaryOp(Multiply),
         MetaKeyId::Divide => MetaKey::BinaryOp(Divide),
+        MetaKeyId::IntegerDivide => MetaKey::BinaryOp(IntegerDivide),
         MetaKeyId::Remainder => MetaKey::BinaryOp(Remainder),
+        MetaKeyId::Power => MetaKey::BinaryOp(Power),
+        MetaKeyId::BitwiseAnd => MetaKey::BinaryOp(BitwiseAnd),
+        MetaKeyId::BitwiseOr => MetaKey::BinaryOp(BitwiseOr),
+        MetaKeyId::BitwiseXor => MetaKey::BinaryOp(BitwiseXor),
+        MetaKeyId::ShiftLeft => MetaKey::BinaryOp(ShiftLeft),
+        MetaKeyId::ShiftRight => MetaKey::BinaryOp(ShiftRight),
         MetaKeyId::AddAssign => MetaKey::BinaryOp(AddAssign),
         MetaKeyId::SubtractAssign => MetaKey::BinaryOp(SubtractAssign),
         MetaKeyId::MultiplyAssign => MetaKey::BinaryOp(MultiplyAssign),
@@ -239,6 +272,7 @@ pub fn meta_id_to_key(id: MetaKeyId, name: Option<KString>) -> Result<MetaKey> {
         MetaKeyId::Display => MetaKey::UnaryOp(Display),
         MetaKeyId::Size => MetaKey::UnaryOp(Size),
         MetaKeyId::Call => MetaKey::Call,
+        MetaKeyId::Format => MetaKey::Format,
         MetaKeyId::Named => {
             MetaKey::Named(name.ok_or_else(|| Error::from("Missing name for named meta entry"))?)
         }
@@ -1,6 +1,7 @@
 //! The core types used in the Koto runtime
 
 mod function;
+mod interface;
 mod iterator;
 mod list;
 mod map;
@@ -9,6 +10,8 @@ mod native_function;
 mod number;
 mod object;
 mod range;
+mod record;
+mod set;
 mod string;
 mod tuple;
 pub mod value;
@@ -26,8 +29,14 @@ pub use self::{
         IsIterable, KObject, KotoCopy, KotoEntries, KotoField, KotoObject, KotoType, MethodContext,
     },
     range::KRange,
+    set::{KSet, ValueSet},
     string::KString,
     tuple::KTuple,
     value::KValue,
     value_key::ValueKey,
 };
+
+pub(crate) use self::{
+    interface::Interface,
+    record::{RecordConstructor, RecordField},
+};
@@ -92,8 +92,8 @@ impl KNumber {
 
     /// Returns the result of raising self to the power of `other`
     ///
-    /// If both inputs are i64s then the result will also be an i64,
-    /// otherwise the result will be an f64.
+    /// If both inputs are i64s and the exponent isn't negative then the result will also be an
+    /// i64 (wrapping on overflow), otherwise the result will be an f64.
     #[must_use]
     pub fn pow(self, other: Self) -> Self {
         use KNumber::*;
@@ -102,7 +102,87 @@ impl KNumber {
             (F64(a), F64(b)) => F64(a.powf(b)),
             (F64(a), I64(b)) => F64(a.powf(b as f64)),
             (I64(a), F64(b)) => F64((a as f64).powf(b)),
-            (I64(a), I64(b)) => I64(a.pow(b as u32)),
+            (I64(a), I64(b)) if b < 0 => F64((a as f64).powf(b as f64)),
+            (I64(a), I64(b)) => I64(wrapping_pow(a, b as u64)),
+        }
+    }
+
+    /// Returns the result of raising self to the power of `other`,
+    /// or `None` if integer overflow occurred
+    ///
+    /// See [KNumber::pow].
+    pub fn checked_pow(self, other: Self) -> Option<Self> {
+        use KNumber::*;
+
+        match (self, other) {
+            (I64(a), I64(b)) if b >= 0 => match u32::try_from(b) {
+                Ok(b) => a.checked_pow(b).map(I64),
+                // Only -1, 0, and 1 can be raised to such large powers without overflowing
+                Err(_) if (-1..=1).contains(&a) => Some(I64(wrapping_pow(a, b as u64))),
+                Err(_) => None,
+            },
+            _ => Some(self.pow(other)),
+        }
+    }
+
+    /// Returns the result of raising self to the power of `other`,
+    /// saturating at the `i64` bounds if integer overflow occurred
+    ///
+    /// See [KNumber::pow].
+    #[must_use]
+    pub fn saturating_pow(self, other: Self) -> Self {
+        use KNumber::*;
+
+        match (self, other) {
+            (I64(a), I64(b)) if b >= 0 => match u32::try_from(b) {
+                Ok(b) => I64(a.saturating_pow(b)),
+                Err(_) if (-1..=1).contains(&a) => I64(wrapping_pow(a, b as u64)),
+                // The sign of the saturated result depends on the exponent's parity
+                Err(_) => I64(a.saturating_pow(if b % 2 == 0 { u32::MAX - 1 } else { u32::MAX })),
+            },
+            _ => self.pow(other),
+        }
+    }
+
+    /// Returns the negated number, or `None` if integer overflow occurred
+    pub fn checked_neg(self) -> Option<Self> {
+        match self {
+            Self::F64(n) => Some(Self::F64(-n)),
+            Self::I64(n) => n.checked_neg().map(Self::I64),
+        }
+    }
+
+    /// Returns the result of dividing self by `other`, rounded down to the nearest integer
+    ///
+    /// If both inputs are i64s then the result will also be an i64,
+    /// otherwise the result will be an f64.
+    ///
+    /// Integer division by zero produces an f64 result (infinity or NaN) rather than panicking.
+    #[must_use]
+    pub fn div_floor(self, other: Self) -> Self {
+        use KNumber::*;
+
+        match (self, other) {
+            (F64(a), F64(b)) => F64((a / b).floor()),
+            (F64(a), I64(b)) => F64((a / b as f64).floor()),
+            (I64(a), F64(b)) => F64((a as f64 / b).floor()),
+            (I64(a), I64(0)) => F64((a as f64 / 0.0).floor()),
+            (I64(a), I64(b)) => {
+                let result = a.wrapping_div(b);
+                if a.wrapping_rem(b) != 0 && ((a < 0) != (b < 0)) {
+                    I64(result - 1)
+                } else {
+                    I64(result)
+                }
+            }
+        }
+    }
+
+    /// Returns the result of [KNumber::div_floor], or `None` if integer overflow occurred
+    pub fn checked_div_floor(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (Self::I64(i64::MIN), Self::I64(-1)) => None,
+            _ => Some(self.div_floor(other)),
         }
     }
 
@@ -196,7 +276,7 @@ impl ops::Neg for KNumber {
 
         match self {
             F64(n) => F64(-n),
-            I64(n) => I64(-n),
+            I64(n) => I64(n.wrapping_neg()),
         }
     }
 }
@@ -209,7 +289,7 @@ impl ops::Neg for &KNumber {
 
         match *self {
             F64(n) => F64(-n),
-            I64(n) => I64(-n),
+            I64(n) => I64(n.wrapping_neg()),
         }
     }
 }
@@ -341,8 +421,10 @@ macro_rules! number_traits_int {
 number_traits_float!(f32, f64);
 number_traits_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
 
+// Integer arithmetic wraps on overflow,
+// see the checked_* functions for operations that detect overflow.
 macro_rules! number_op {
-    ($trait:ident, $fn:ident, $op:tt) => {
+    ($trait:ident, $fn:ident, $op:tt, $wrapping_fn:ident) => {
         impl ops::$trait for KNumber {
             type Output = KNumber;
 
@@ -353,7 +435,7 @@ macro_rules! number_op {
                     (F64(a), F64(b)) => F64(a $op b),
                     (F64(a), I64(b)) => F64(a $op b as f64),
                     (I64(a), F64(b)) => F64(a as f64 $op b),
-                    (I64(a), I64(b)) => I64(a $op b),
+                    (I64(a), I64(b)) => I64(a.$wrapping_fn(b)),
                 }
             }
         }
@@ -368,17 +450,47 @@ macro_rules! number_op {
                     (F64(a), F64(b)) => F64(a $op b),
                     (F64(a), I64(b)) => F64(a $op b as f64),
                     (I64(a), F64(b)) => F64(a as f64 $op b),
-                    (I64(a), I64(b)) => I64(a $op b),
+                    (I64(a), I64(b)) => I64(a.$wrapping_fn(b)),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! overflow_ops {
+    ($op:tt, $op_name:literal, $checked_fn:ident, $saturating_fn:ident) => {
+        impl KNumber {
+            #[doc = concat!("Returns the result of ", $op_name, ", or `None` if integer overflow occurred")]
+            pub fn $checked_fn(self, other: Self) -> Option<Self> {
+                match (self, other) {
+                    (Self::I64(a), Self::I64(b)) => a.$checked_fn(b).map(Self::I64),
+                    _ => Some(self $op other),
+                }
+            }
+
+            #[doc = concat!(
+                "Returns the result of ", $op_name,
+                ", saturating at the `i64` bounds if integer overflow occurred"
+            )]
+            #[must_use]
+            pub fn $saturating_fn(self, other: Self) -> Self {
+                match (self, other) {
+                    (Self::I64(a), Self::I64(b)) => Self::I64(a.$saturating_fn(b)),
+                    _ => self $op other,
                 }
             }
         }
     };
 }
 
-number_op!(Add, add, +);
-number_op!(Sub, sub, -);
-number_op!(Mul, mul, *);
-number_op!(Rem, rem, %);
+overflow_ops!(+, "addition", checked_add, saturating_add);
+overflow_ops!(-, "subtraction", checked_sub, saturating_sub);
+overflow_ops!(*, "multiplication", checked_mul, saturating_mul);
+
+number_op!(Add, add, +, wrapping_add);
+number_op!(Sub, sub, -, wrapping_sub);
+number_op!(Mul, mul, *, wrapping_mul);
+number_op!(Rem, rem, %, wrapping_rem);
 
 impl ops::Div for KNumber {
     type Output = KNumber;
@@ -410,10 +522,76 @@ impl ops::Div for &KNumber {
     }
 }
 
+// Raises `base` to the power of `exponent`, wrapping on overflow
+//
+// `i64::wrapping_pow` only accepts `u32` exponents, so larger exponents are handled here by
+// exponentiation by squaring, which produces the same wrapped result.
+fn wrapping_pow(base: i64, exponent: u64) -> i64 {
+    if let Ok(exponent) = u32::try_from(exponent) {
+        return base.wrapping_pow(exponent);
+    }
+
+    let mut result: i64 = 1;
+    let mut base = base;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.wrapping_mul(base);
+        }
+        base = base.wrapping_mul(base);
+        exponent >>= 1;
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn div_floor() {
+        assert_eq!(KNumber::from(7).div_floor(2.into()), KNumber::from(3));
+        assert_eq!(KNumber::from(-7).div_floor(2.into()), KNumber::from(-4));
+        assert_eq!(KNumber::from(7).div_floor((-2).into()), KNumber::from(-4));
+        assert_eq!(KNumber::from(-8).div_floor(2.into()), KNumber::from(-4));
+        assert!(KNumber::from(7.5).div_floor(2.into()).is_f64());
+        assert_eq!(KNumber::from(7.5).div_floor(2.into()), KNumber::from(3));
+        assert!(KNumber::from(1).div_floor(0.into()).is_f64());
+    }
+
+    #[test]
+    fn integer_overflow() {
+        let max = KNumber::from(i64::MAX);
+        let min = KNumber::from(i64::MIN);
+        assert_eq!(max + 1.into(), min);
+        assert_eq!(max.checked_add(1.into()), None);
+        assert_eq!(max.saturating_add(1.into()), max);
+        assert_eq!(min.checked_sub(1.into()), None);
+        assert_eq!(min.saturating_mul(2.into()), min);
+        assert_eq!(KNumber::from(2).checked_pow(63.into()), None);
+        assert_eq!(KNumber::from(2).saturating_pow(100.into()), max);
+        assert_eq!(KNumber::from(2).pow((1_i64 << 32).into()), KNumber::from(0));
+        assert_eq!(
+            KNumber::from(-1).pow(((1_i64 << 32) + 1).into()),
+            KNumber::from(-1)
+        );
+        assert_eq!(
+            KNumber::from(3).pow(((1_i64 << 32) + 1).into()),
+            KNumber::from(
+                3_i64
+                    .wrapping_pow(1 << 16)
+                    .wrapping_pow(1 << 16)
+                    .wrapping_mul(3)
+            )
+        );
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.checked_div_floor((-1).into()), None);
+        assert_eq!(
+            max.checked_add(1.5.into()),
+            Some(KNumber::from(i64::MAX as f64 + 1.5))
+        );
+    }
+
     #[test]
     fn from_out_of_range_saturates() {
         assert_eq!(u8::from(KNumber::from(1000)), u8::MAX);
@@ -123,6 +123,18 @@ pub trait KotoObject: KotoType + KotoCopy + KotoEntries + KotoSend + KotoSync +
         Ok(())
     }
 
+    /// Called when the object is rendered in an interpolated string with formatting options,
+    /// e.g. `'{x:>10}'`, or `'{x:%Y-%m-%d}'`
+    ///
+    /// The format string is provided as it appears in the source, allowing objects to interpret
+    /// their own formatting options.
+    ///
+    /// By default, `None` is returned, and the standard formatting options are applied to the
+    /// object's display string, with an error thrown for custom format strings.
+    fn format(&self, _format_string: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
     /// Called for indexing operations, e.g. `x[0]`
     ///
     /// See also: [KotoObject::size]
@@ -185,11 +197,109 @@ pub trait KotoObject: KotoType + KotoCopy + KotoEntries + KotoSend + KotoSync +
         unimplemented_error("@/", self.type_string())
     }
 
+    /// The `//` integer division operator
+    fn integer_divide(&self, _rhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@//", self.type_string())
+    }
+
     /// The `%` remainder operator
     fn remainder(&self, _rhs: &KValue) -> Result<KValue> {
         unimplemented_error("@%", self.type_string())
     }
 
+    /// The `**` power operator
+    fn power(&self, _rhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@**", self.type_string())
+    }
+
+    /// The `&` bitwise and operator
+    fn bitwise_and(&self, _rhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@&", self.type_string())
+    }
+
+    /// The `|` bitwise or operator
+    fn bitwise_or(&self, _rhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@|", self.type_string())
+    }
+
+    /// The `^` bitwise xor operator
+    fn bitwise_xor(&self, _rhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@^", self.type_string())
+    }
+
+    /// The `<<` left shift operator
+    fn shift_left(&self, _rhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@<<", self.type_string())
+    }
+
+    /// The `>>` right shift operator
+    fn shift_right(&self, _rhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@>>", self.type_string())
+    }
+
+    /// The `+` addition operator, when the object is on the RHS of the operation
+    ///
+    /// The runtime calls the RHS operator functions when the LHS isn't an object,
+    /// e.g. `1 + x`. For compound assignments like `y += x`, the result is assigned to the LHS.
+    fn add_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@+ (RHS)", self.type_string())
+    }
+
+    /// The `-` subtraction operator, when the object is on the RHS of the operation
+    fn subtract_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@- (RHS)", self.type_string())
+    }
+
+    /// The `*` multiplication operator, when the object is on the RHS of the operation
+    fn multiply_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@* (RHS)", self.type_string())
+    }
+
+    /// The `/` division operator, when the object is on the RHS of the operation
+    fn divide_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@/ (RHS)", self.type_string())
+    }
+
+    /// The `//` integer division operator, when the object is on the RHS of the operation
+    fn integer_divide_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@// (RHS)", self.type_string())
+    }
+
+    /// The `%` remainder operator, when the object is on the RHS of the operation
+    fn remainder_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@% (RHS)", self.type_string())
+    }
+
+    /// The `**` power operator, when the object is on the RHS of the operation
+    fn power_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@** (RHS)", self.type_string())
+    }
+
+    /// The `&` bitwise AND operator, when the object is on the RHS of the operation
+    fn bitwise_and_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@& (RHS)", self.type_string())
+    }
+
+    /// The `|` bitwise OR operator, when the object is on the RHS of the operation
+    fn bitwise_or_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@| (RHS)", self.type_string())
+    }
+
+    /// The `^` bitwise XOR operator, when the object is on the RHS of the operation
+    fn bitwise_xor_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@^ (RHS)", self.type_string())
+    }
+
+    /// The `<<` left shift operator, when the object is on the RHS of the operation
+    fn shift_left_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@<< (RHS)", self.type_string())
+    }
+
+    /// The `>>` right shift operator, when the object is on the RHS of the operation
+    fn shift_right_rhs(&self, _lhs: &KValue) -> Result<KValue> {
+        unimplemented_error("@>> (RHS)", self.type_string())
+    }
+
     /// The `+=` in-place addition operator
     fn add_assign(&mut self, _rhs: &KValue) -> Result<()> {
         unimplemented_error("@+=", self.type_string())
@@ -245,6 +355,17 @@ pub trait KotoObject: KotoType + KotoCopy + KotoEntries + KotoSend + KotoSync +
         unimplemented_error("@!=", self.type_string())
     }
 
+    /// The `==` equality operator, when the object is on the RHS of the operation
+    ///
+    /// This is called when the LHS isn't an object, with `!=` producing the inverted result.
+    /// Objects are considered to be unequal to non-object values by default.
+    ///
+    /// Comparison operations (e.g. `<`) with the object on the RHS are swapped, so that
+    /// `1 < x` calls [`KotoObject::greater`] with `1` as the argument.
+    fn equal_rhs(&self, _lhs: &KValue) -> Result<bool> {
+        Ok(false)
+    }
+
     /// Declares to the runtime whether or not the object is iterable
     ///
     /// The `Iterable` type hint defers to this function,
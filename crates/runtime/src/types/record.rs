@@ -0,0 +1,233 @@
+use crate::{prelude::*, Ptr, PtrMut, Result};
+use koto_derive::{KotoCopy, KotoType};
+
+/// The constructor for a record or enum variant, made by `record` and `enum` declarations
+///
+/// Calling the constructor makes a [KMap] containing the record's fields.
+///
+/// All of the records made by a constructor share the same [MetaMap], which defines the record's
+/// `@type`, along with `@display`, `@==`, and `@!=` implementations.
+#[derive(Clone, KotoCopy, KotoType)]
+#[koto(type_name = "Constructor")]
+pub(crate) struct RecordConstructor(Ptr<RecordDefinition>);
+
+struct RecordDefinition {
+    // The name of the record, e.g. `Point`, or `Shape.Circle` for enum variants
+    name: KString,
+    fields: Vec<RecordField>,
+    meta: PtrMut<MetaMap>,
+}
+
+/// A field in a record declaration, along with its optional type hint
+pub(crate) struct RecordField {
+    pub name: KString,
+    pub type_hint: Option<KString>,
+    /// The values bound to the names used in the type hint, in order of first appearance
+    pub type_hint_bindings: Vec<KValue>,
+}
+
+impl RecordConstructor {
+    /// Makes a constructor for a record
+    pub fn new(type_name: KString, fields: Vec<RecordField>) -> Self {
+        Self::with_name(type_name.clone(), type_name, fields)
+    }
+
+    /// Makes a constructor for an enum variant
+    pub fn new_variant(enum_name: KString, variant_name: &str, fields: Vec<RecordField>) -> Self {
+        let name = format!("{enum_name}.{variant_name}").into();
+        Self::with_name(name, enum_name, fields)
+    }
+
+    /// Makes the value of an enum variant that doesn't have any fields
+    pub fn make_unit_variant(enum_name: KString, variant_name: &str) -> KValue {
+        Self::new_variant(enum_name, variant_name, Vec::new()).make_record(ValueMap::default())
+    }
+
+    fn with_name(name: KString, type_name: KString, fields: Vec<RecordField>) -> Self {
+        let mut meta = MetaMap::default();
+        meta.insert(MetaKey::Type, type_name.into());
+        meta.add_fn(UnaryOp::Display.into(), {
+            let name = name.clone();
+            move |ctx| display_record(&name, ctx)
+        });
+        meta.add_fn(BinaryOp::Equal.into(), |ctx| {
+            records_are_equal(ctx).map(KValue::from)
+        });
+        meta.add_fn(BinaryOp::NotEqual.into(), |ctx| {
+            records_are_equal(ctx).map(|equal| KValue::from(!equal))
+        });
+
+        Self(Ptr::from(RecordDefinition {
+            name,
+            fields,
+            meta: meta.into(),
+        }))
+    }
+
+    /// Returns a tuple containing the record's field values, if the record was made by this
+    /// constructor
+    pub fn field_values(&self, value: &KValue) -> Option<KTuple> {
+        match value {
+            KValue::Map(m) if self.is_constructor_of(m) => {
+                let data = m.data();
+                let values = self
+                    .0
+                    .fields
+                    .iter()
+                    .map(|field| data.get(&field.name).cloned().unwrap_or_default())
+                    .collect::<Vec<_>>();
+                Some(values.into())
+            }
+            _ => None,
+        }
+    }
+
+    fn is_constructor_of(&self, record: &KMap) -> bool {
+        record
+            .meta_map()
+            .is_some_and(|meta| PtrMut::ptr_eq(meta, &self.0.meta))
+    }
+
+    fn make_record(&self, data: ValueMap) -> KValue {
+        let mut record = KMap::with_data(data);
+        record.set_meta_map(Some(self.0.meta.clone()));
+        record.into()
+    }
+}
+
+impl KotoEntries for RecordConstructor {}
+
+impl KotoObject for RecordConstructor {
+    fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append(self.0.name.clone());
+        Ok(())
+    }
+
+    fn equal(&self, rhs: &KValue) -> Result<bool> {
+        let result = match rhs {
+            KValue::Object(o) => o
+                .cast::<Self>()
+                .is_ok_and(|other| Ptr::ptr_eq(&self.0, &other.0)),
+            _ => false,
+        };
+        Ok(result)
+    }
+
+    fn not_equal(&self, rhs: &KValue) -> Result<bool> {
+        self.equal(rhs).map(|result| !result)
+    }
+
+    fn is_callable(&self) -> bool {
+        true
+    }
+
+    fn call(&mut self, ctx: &mut CallContext) -> Result<KValue> {
+        let definition = &self.0;
+        let args = ctx.args();
+
+        if args.len() != definition.fields.len() {
+            return runtime_error!(
+                "'{}' expects {} argument(s), found {}",
+                definition.name,
+                definition.fields.len(),
+                args.len()
+            );
+        }
+
+        let mut data = ValueMap::with_capacity(args.len());
+        for (field, arg) in definition.fields.iter().zip(args) {
+            if let Some(type_hint) = &field.type_hint {
+                if !ctx
+                    .vm
+                    .value_matches_type(arg, type_hint, &field.type_hint_bindings)
+                {
+                    return runtime_error!(
+                        "Expected '{type_hint}' for '{}' in '{}', found '{}'",
+                        field.name,
+                        definition.name,
+                        arg.type_as_string()
+                    );
+                }
+            }
+
+            data.insert(field.name.clone().into(), arg.clone());
+        }
+
+        Ok(self.make_record(data))
+    }
+}
+
+impl From<RecordConstructor> for KValue {
+    fn from(constructor: RecordConstructor) -> Self {
+        KObject::from(constructor).into()
+    }
+}
+
+// The @display function for records, e.g. `Point(x: 1, y: 2)`
+fn display_record(name: &KString, ctx: &mut CallContext) -> Result<KValue> {
+    let KValue::Map(record) = ctx.instance().clone() else {
+        return unexpected_type("a record", ctx.instance());
+    };
+
+    let mut display_ctx = DisplayContext::with_vm(ctx.vm);
+    display_ctx.append(name.clone());
+
+    if let (false, Some(meta)) = (record.is_empty(), record.meta_map()) {
+        // Adding the record as a container causes string values to be displayed with quotes
+        display_ctx.push_container(PtrMut::address(meta));
+
+        display_ctx.append('(');
+        for (i, (key, value)) in record.data().iter().enumerate() {
+            if i > 0 {
+                display_ctx.append(", ");
+            }
+            if let KValue::Str(key) = key.value() {
+                display_ctx.append(key.clone());
+            }
+            display_ctx.append(": ");
+            value.display(&mut display_ctx)?;
+        }
+        display_ctx.append(')');
+
+        display_ctx.pop_container();
+    }
+
+    Ok(display_ctx.result().into())
+}
+
+// Compares two records, which are equal if they were made by the same constructor,
+// and if their fields are equal.
+fn records_are_equal(ctx: &mut CallContext) -> Result<bool> {
+    let (a, b) = match (ctx.instance(), ctx.args()) {
+        (KValue::Map(a), [KValue::Map(b)]) => (a.clone(), b.clone()),
+        (KValue::Map(_), [_]) => return Ok(false),
+        (instance, args) => return unexpected_args_after_instance("|Map|", instance, args),
+    };
+
+    let same_constructor = match (a.meta_map(), b.meta_map()) {
+        (Some(meta_a), Some(meta_b)) => PtrMut::ptr_eq(meta_a, meta_b),
+        _ => false,
+    };
+    if !same_constructor || a.len() != b.len() {
+        return Ok(false);
+    }
+
+    let entries = a
+        .data()
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<_>>();
+
+    for (key, value_a) in entries {
+        let Some(value_b) = b.get(&key) else {
+            return Ok(false);
+        };
+        match ctx.vm.run_binary_op(BinaryOp::Equal, value_a, value_b)? {
+            KValue::Bool(true) => {}
+            KValue::Bool(false) => return Ok(false),
+            unexpected => return unexpected_type("Bool from equality comparison", &unexpected),
+        }
+    }
+
+    Ok(true)
+}
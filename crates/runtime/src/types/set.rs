@@ -0,0 +1,179 @@
+use crate::{prelude::*, Borrow, BorrowMut, PtrMut, Result};
+use indexmap::{Equivalent, IndexSet};
+use std::{
+    hash::{BuildHasherDefault, Hash},
+    ops::{Deref, DerefMut},
+};
+
+type ValueSetType = IndexSet<ValueKey, BuildHasherDefault<KotoHasher>>;
+
+/// The ordered set of [ValueKeys](crate::ValueKey) used by the Koto runtime
+///
+/// See also: [KSet]
+#[derive(Clone, Default)]
+pub struct ValueSet(ValueSetType);
+
+impl ValueSet {
+    /// Creates a new set with the given capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(ValueSetType::with_capacity_and_hasher(
+            capacity,
+            Default::default(),
+        ))
+    }
+}
+
+impl Deref for ValueSet {
+    type Target = ValueSetType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ValueSet {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromIterator<ValueKey> for ValueSet {
+    fn from_iter<T: IntoIterator<Item = ValueKey>>(iter: T) -> ValueSet {
+        Self(ValueSetType::from_iter(iter))
+    }
+}
+
+/// The set type used by the Koto runtime
+///
+/// Like [KMap], the set's entries are kept in insertion order.
+#[derive(Clone, Default)]
+pub struct KSet(PtrMut<SetData>);
+
+// The set's entries, along with a flag that's set when the set has been frozen
+#[derive(Default)]
+struct SetData {
+    values: ValueSet,
+    frozen: bool,
+}
+
+impl KSet {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty set with the given capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_data(ValueSet::with_capacity(capacity))
+    }
+
+    /// Creates a set containing the provided data
+    pub fn with_data(values: ValueSet) -> Self {
+        Self(
+            SetData {
+                values,
+                frozen: false,
+            }
+            .into(),
+        )
+    }
+
+    /// Returns the number of entries in the set
+    pub fn len(&self) -> usize {
+        self.data().len()
+    }
+
+    /// Returns true if there are no entries in the set
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the set contains the given key
+    pub fn contains<K>(&self, key: &K) -> bool
+    where
+        K: Hash + Equivalent<ValueKey> + ?Sized,
+    {
+        self.data().contains(key)
+    }
+
+    /// Inserts a key into the set, returning true if the key wasn't already in the set
+    pub fn insert(&self, key: impl Into<ValueKey>) -> bool {
+        self.data_mut().insert(key.into())
+    }
+
+    /// Returns a reference to the set's entries
+    pub fn data(&self) -> Borrow<'_, ValueSet> {
+        Borrow::map(self.0.borrow(), |data| &data.values)
+    }
+
+    /// Returns a mutable reference to the set's entries
+    ///
+    /// Note that this doesn't check if the set has been frozen, see [KSet::checked_data_mut].
+    pub fn data_mut(&self) -> BorrowMut<'_, ValueSet> {
+        BorrowMut::map(self.0.borrow_mut(), |data| &mut data.values)
+    }
+
+    /// Returns a mutable reference to the set's entries, or an error if the set is frozen
+    pub fn checked_data_mut(&self) -> Result<BorrowMut<'_, ValueSet>> {
+        if self.is_frozen() {
+            runtime_error!("Unable to modify a frozen Set")
+        } else {
+            Ok(self.data_mut())
+        }
+    }
+
+    /// Returns true if the set has been frozen
+    pub fn is_frozen(&self) -> bool {
+        self.0.borrow().frozen
+    }
+
+    /// Freezes the set, preventing further modifications
+    ///
+    /// The set's entries are immutable, so only the set itself needs to be frozen.
+    pub fn freeze(&self) {
+        self.0.borrow_mut().frozen = true;
+    }
+
+    /// Returns true if the provided KSet occupies the same memory address
+    pub fn is_same_instance(&self, other: &Self) -> bool {
+        PtrMut::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns true if both sets contain the same entries, regardless of their order
+    pub fn is_equal(&self, other: &Self) -> bool {
+        if self.is_same_instance(other) {
+            return true;
+        }
+
+        let data = self.data();
+        let other_data = other.data();
+        data.len() == other_data.len() && data.is_subset(&other_data)
+    }
+
+    /// Renders the set to the provided display context
+    pub fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
+        ctx.append("Set(");
+
+        // Sets can't contain containers, but adding the set as a container causes string entries
+        // to be displayed with quotes.
+        ctx.push_container(PtrMut::address(&self.0));
+
+        for (i, key) in self.data().iter().enumerate() {
+            if i > 0 {
+                ctx.append(", ");
+            }
+            key.value().display(ctx)?;
+        }
+
+        ctx.pop_container();
+
+        ctx.append(')');
+        Ok(())
+    }
+}
+
+impl From<ValueSet> for KSet {
+    fn from(values: ValueSet) -> Self {
+        Self::with_data(values)
+    }
+}
@@ -31,6 +31,9 @@ pub enum KValue {
     /// The hash map type used in Koto
     Map(KMap),
 
+    /// The set type used in Koto
+    Set(KSet),
+
     /// The string type used in Koto
     Str(KString),
 
@@ -88,6 +91,7 @@ impl KValue {
                 let meta = m.meta_map().map(|meta| meta.borrow().clone());
                 KMap::with_contents(data, meta).into()
             }
+            KValue::Set(s) => KSet::with_data(s.data().clone()).into(),
             KValue::Iterator(i) => i.make_copy()?.into(),
             KValue::Object(o) => o.try_borrow()?.copy().into(),
             _ => self.clone(),
@@ -145,7 +149,7 @@ impl KValue {
     pub fn is_iterable(&self) -> bool {
         use KValue::*;
         match self {
-            Range(_) | List(_) | Tuple(_) | Map(_) | Str(_) | Iterator(_) => true,
+            Range(_) | List(_) | Tuple(_) | Map(_) | Set(_) | Str(_) | Iterator(_) => true,
             Object(o) => o.try_borrow().map_or(false, |o| {
                 !matches!(o.is_iterable(), IsIterable::NotIterable)
             }),
@@ -153,6 +157,32 @@ impl KValue {
         }
     }
 
+    /// Freezes the value, preventing further modifications
+    ///
+    /// Lists and maps are frozen along with their contents, sets are frozen,
+    /// and the contents of tuples are also frozen. Other values are unaffected.
+    pub fn freeze(&self) {
+        use KValue::*;
+        match self {
+            List(l) => l.freeze(),
+            Map(m) => m.freeze(),
+            Set(s) => s.freeze(),
+            Tuple(t) => t.iter().for_each(|value| value.freeze()),
+            _ => {}
+        }
+    }
+
+    /// Returns true if the value is a frozen list, map, or set
+    pub fn is_frozen(&self) -> bool {
+        use KValue::*;
+        match self {
+            List(l) => l.is_frozen(),
+            Map(m) => m.is_frozen(),
+            Set(s) => s.is_frozen(),
+            _ => false,
+        }
+    }
+
     /// Returns the value's type as a [KString]
     pub fn type_as_string(&self) -> KString {
         use KValue::*;
@@ -171,6 +201,7 @@ impl KValue {
                 },
             },
             Map(_) => TYPE_MAP.with(|x| x.clone()),
+            Set(_) => TYPE_SET.with(|x| x.clone()),
             Str(_) => TYPE_STRING.with(|x| x.clone()),
             Tuple(_) => TYPE_TUPLE.with(|x| x.clone()),
             Function(f) if f.generator => TYPE_GENERATOR.with(|x| x.clone()),
@@ -205,6 +236,7 @@ impl KValue {
             List(l) => return l.display(ctx),
             Tuple(t) => return t.display(ctx),
             Map(m) => return m.display(ctx),
+            Set(s) => return s.display(ctx),
             Object(o) => return o.try_borrow()?.display(ctx),
         };
         if result.is_ok() {
@@ -223,6 +255,7 @@ thread_local! {
     static TYPE_RANGE: KString = "Range".into();
     static TYPE_MAP: KString = "Map".into();
     static TYPE_OBJECT: KString = "Object".into();
+    static TYPE_SET: KString = "Set".into();
     static TYPE_STRING: KString = "String".into();
     static TYPE_TUPLE: KString = "Tuple".into();
     static TYPE_FUNCTION: KString = "Function".into();
@@ -297,6 +330,12 @@ impl From<KMap> for KValue {
     }
 }
 
+impl From<KSet> for KValue {
+    fn from(value: KSet) -> Self {
+        Self::Set(value)
+    }
+}
+
 impl From<KObject> for KValue {
     fn from(value: KObject) -> Self {
         Self::Object(value)
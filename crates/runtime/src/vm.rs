@@ -2,12 +2,16 @@ use crate::{
     core_lib::CoreLib,
     error::{Error, ErrorKind},
     prelude::*,
-    types::{meta_id_to_key, value::RegisterSlice},
-    DefaultStderr, DefaultStdin, DefaultStdout, KCaptureFunction, KFunction, Ptr, Result,
+    type_hint::TypeHint,
+    types::{meta_id_to_key, value::RegisterSlice, Interface, RecordConstructor, RecordField},
+    DefaultFileSystem, DefaultOs, DefaultStderr, DefaultStdin, DefaultStdout, KCaptureFunction,
+    KFunction, Ptr, Result,
 };
 use instant::Instant;
-use koto_bytecode::{Chunk, Instruction, InstructionReader, Loader};
-use koto_parser::{ConstantIndex, MetaKeyId, StringAlignment, StringFormatOptions};
+use koto_bytecode::{Chunk, CompilerSettings, Instruction, InstructionReader, Loader};
+use koto_parser::{
+    ConstantIndex, MetaKeyId, StringAlignment, StringFormatOptions, StringFormatRepresentation,
+};
 use rustc_hash::FxHasher;
 use std::{
     collections::HashMap,
@@ -37,6 +41,8 @@ struct VmContext {
     loader: KCell<Loader>,
     // The cached export maps of imported modules
     imported_modules: KCell<ModuleCache>,
+    // Type hints that have been parsed while checking values, see `value_matches_type`
+    type_hints: KCell<TypeHintCache>,
 }
 
 impl Default for VmContext {
@@ -55,6 +61,7 @@ impl VmContext {
             core_lib,
             loader: Loader::default().into(),
             imported_modules: ModuleCache::default().into(),
+            type_hints: TypeHintCache::default().into(),
         }
     }
 }
@@ -96,6 +103,49 @@ pub struct KotoVmSettings {
 
     /// The runtime's stderr
     pub stderr: Ptr<dyn KotoFile>,
+
+    /// The runtime's filesystem, used by the `io` module
+    pub file_system: Ptr<dyn KotoFileSystem>,
+
+    /// The runtime's interface to the operating system, used by the `os` module
+    pub os: Ptr<dyn KotoOs>,
+
+    /// An optional sink that receives the messages logged by the `log` module
+    ///
+    /// If no sink is provided then messages are written to the runtime's stderr.
+    pub log_sink: Option<Ptr<dyn KotoLogSink>>,
+
+    /// Whether or not the elements of containers should be checked against nested type hints
+    ///
+    /// e.g. when enabled, `let x: List<Number> = [1, 2, 3]` will check each of the list's
+    /// elements. When disabled, only the outer type of the value will be checked.
+    ///
+    /// Checking elements requires iterating through the container, so disabling the checks can
+    /// be useful in performance-sensitive scripts.
+    ///
+    /// Enabled by default.
+    pub enable_container_type_checks: bool,
+
+    /// Whether or not integer overflow in arithmetic operations should result in an error
+    ///
+    /// When disabled, integer arithmetic wraps around on overflow, e.g. `i64::MAX + 1` results in
+    /// `i64::MIN`. When enabled, a runtime error is thrown instead.
+    ///
+    /// Scripts can choose how overflow is handled for individual operations with the `checked_*`,
+    /// `saturating_*`, and `wrapping_*` functions in the `number` module.
+    ///
+    /// Disabled by default.
+    pub enable_integer_overflow_checks: bool,
+
+    /// The permissions granted to scripts for accessing the filesystem, processes, and environment
+    ///
+    /// All permissions are granted by default.
+    pub permissions: KotoPermissions,
+
+    /// The settings that are used when compiling imported modules
+    ///
+    /// When using the `Koto` API, these are derived from its compiler settings.
+    pub module_compiler_settings: CompilerSettings,
 }
 
 impl Default for KotoVmSettings {
@@ -107,6 +157,13 @@ impl Default for KotoVmSettings {
             stdin: make_ptr!(DefaultStdin::default()),
             stdout: make_ptr!(DefaultStdout::default()),
             stderr: make_ptr!(DefaultStderr::default()),
+            file_system: make_ptr!(DefaultFileSystem::default()),
+            os: make_ptr!(DefaultOs::default()),
+            log_sink: None,
+            enable_container_type_checks: true,
+            enable_integer_overflow_checks: false,
+            permissions: KotoPermissions::default(),
+            module_compiler_settings: CompilerSettings::default(),
         }
     }
 }
@@ -211,6 +268,26 @@ impl KotoVm {
         &mut self.exports
     }
 
+    /// The settings that were used to initialize the VM
+    pub fn settings(&self) -> &KotoVmSettings {
+        &self.context.settings
+    }
+
+    /// The filesystem used by the VM
+    pub fn file_system(&self) -> &Ptr<dyn KotoFileSystem> {
+        &self.context.settings.file_system
+    }
+
+    /// The operating system interface used by the VM
+    pub fn os(&self) -> &Ptr<dyn KotoOs> {
+        &self.context.settings.os
+    }
+
+    /// The log sink used by the VM
+    pub fn log_sink(&self) -> Option<&Ptr<dyn KotoLogSink>> {
+        self.context.settings.log_sink.as_ref()
+    }
+
     /// The stdin wrapper used by the VM
     pub fn stdin(&self) -> &Ptr<dyn KotoFile> {
         &self.context.settings.stdin
@@ -463,9 +540,28 @@ impl KotoVm {
             BinaryOp::Subtract => self.run_subtract(result_register, lhs_register, rhs_register)?,
             BinaryOp::Multiply => self.run_multiply(result_register, lhs_register, rhs_register)?,
             BinaryOp::Divide => self.run_divide(result_register, lhs_register, rhs_register)?,
+            BinaryOp::IntegerDivide => {
+                self.run_integer_divide(result_register, lhs_register, rhs_register)?
+            }
             BinaryOp::Remainder => {
                 self.run_remainder(result_register, lhs_register, rhs_register)?
             }
+            BinaryOp::Power => self.run_power(result_register, lhs_register, rhs_register)?,
+            BinaryOp::BitwiseAnd => {
+                self.run_bitwise_and(result_register, lhs_register, rhs_register)?
+            }
+            BinaryOp::BitwiseOr => {
+                self.run_bitwise_or(result_register, lhs_register, rhs_register)?
+            }
+            BinaryOp::BitwiseXor => {
+                self.run_bitwise_xor(result_register, lhs_register, rhs_register)?
+            }
+            BinaryOp::ShiftLeft => {
+                self.run_shift_left(result_register, lhs_register, rhs_register)?
+            }
+            BinaryOp::ShiftRight => {
+                self.run_shift_right(result_register, lhs_register, rhs_register)?
+            }
             BinaryOp::AddAssign => {
                 self.run_add_assign(lhs_register, rhs_register)?;
                 self.set_register(result_register, self.clone_register(lhs_register));
@@ -538,6 +634,7 @@ impl KotoVm {
             Tuple(t) => Ok(KIterator::with_tuple(t)),
             Str(s) => Ok(KIterator::with_string(s)),
             Map(m) => Ok(KIterator::with_map(m)),
+            Set(s) => Ok(KIterator::with_set(s)),
             Object(o) => {
                 use IsIterable::*;
 
@@ -673,21 +770,25 @@ impl KotoVm {
                     self.execution_state = ExecutionState::Suspended;
                     return Ok(value);
                 }
-                Err(error) => match self.pop_call_stack_on_error(error.clone(), true) {
-                    Ok((recover_register, ip)) => {
-                        let catch_value = match error.error {
-                            ErrorKind::KotoError { thrown_value, .. } => thrown_value,
-                            _ => KValue::Str(error.to_string().into()),
-                        };
-
-                        self.set_register(recover_register, catch_value);
-                        self.set_ip(ip);
-                    }
-                    Err(error) => {
-                        self.execution_state = ExecutionState::Inactive;
-                        return Err(error);
+                Err(error) => {
+                    // Exiting can't be caught by scripts
+                    let allow_catch = !matches!(error.error, ErrorKind::Exit(_));
+                    match self.pop_call_stack_on_error(error.clone(), allow_catch) {
+                        Ok((recover_register, ip)) => {
+                            let catch_value = match error.error {
+                                ErrorKind::KotoError { thrown_value, .. } => thrown_value,
+                                _ => KValue::Str(error.to_string().into()),
+                            };
+
+                            self.set_register(recover_register, catch_value);
+                            self.set_ip(ip);
+                        }
+                        Err(error) => {
+                            self.execution_state = ExecutionState::Inactive;
+                            return Err(error);
+                        }
                     }
-                },
+                }
             }
 
             self.instruction_ip = self.ip();
@@ -790,7 +891,14 @@ impl KotoVm {
             Subtract { register, lhs, rhs } => self.run_subtract(register, lhs, rhs)?,
             Multiply { register, lhs, rhs } => self.run_multiply(register, lhs, rhs)?,
             Divide { register, lhs, rhs } => self.run_divide(register, lhs, rhs)?,
+            IntegerDivide { register, lhs, rhs } => self.run_integer_divide(register, lhs, rhs)?,
             Remainder { register, lhs, rhs } => self.run_remainder(register, lhs, rhs)?,
+            Power { register, lhs, rhs } => self.run_power(register, lhs, rhs)?,
+            BitwiseAnd { register, lhs, rhs } => self.run_bitwise_and(register, lhs, rhs)?,
+            BitwiseOr { register, lhs, rhs } => self.run_bitwise_or(register, lhs, rhs)?,
+            BitwiseXor { register, lhs, rhs } => self.run_bitwise_xor(register, lhs, rhs)?,
+            ShiftLeft { register, lhs, rhs } => self.run_shift_left(register, lhs, rhs)?,
+            ShiftRight { register, lhs, rhs } => self.run_shift_right(register, lhs, rhs)?,
             AddAssign { lhs, rhs } => self.run_add_assign(lhs, rhs)?,
             SubtractAssign { lhs, rhs } => self.run_subtract_assign(lhs, rhs)?,
             MultiplyAssign { lhs, rhs } => self.run_multiply_assign(lhs, rhs)?,
@@ -808,6 +916,16 @@ impl KotoVm {
             JumpBack { offset } => self.jump_ip_back(offset as u32),
             JumpIfTrue { register, offset } => self.run_jump_if_true(register, offset as u32)?,
             JumpIfFalse { register, offset } => self.run_jump_if_false(register, offset as u32)?,
+            JumpIfNull { register, offset } => {
+                if matches!(self.get_register(register), KValue::Null) {
+                    self.jump_ip(offset as u32);
+                }
+            }
+            JumpIfNotNull { register, offset } => {
+                if !matches!(self.get_register(register), KValue::Null) {
+                    self.jump_ip(offset as u32);
+                }
+            }
             Call {
                 result,
                 function,
@@ -944,12 +1062,81 @@ impl KotoVm {
             Debug { register, constant } => self.run_debug(register, constant)?,
             CheckSizeEqual { register, size } => self.run_check_size_equal(register, size)?,
             CheckSizeMin { register, size } => self.run_check_size_min(register, size)?,
-            AssertType { value, type_string } => self.run_assert_type(value, type_string)?,
+            AssertType {
+                value,
+                type_string,
+                bindings,
+                binding_count,
+            } => self.run_assert_type(value, type_string, bindings, binding_count)?,
             CheckType {
                 value,
                 jump_offset,
                 type_string,
-            } => self.run_check_type(value, jump_offset as u32, type_string)?,
+                bindings,
+                binding_count,
+            } => self.run_check_type(
+                value,
+                jump_offset as u32,
+                type_string,
+                bindings,
+                binding_count,
+            )?,
+            AssertMap { value } => self.run_assert_map(value)?,
+            CheckMap { value, jump_offset } => self.run_check_map(value, jump_offset as u32),
+            AccessMapEntry { register, map, key } => {
+                self.run_access_map_entry(register, map, key)?
+            }
+            CheckMapEntry {
+                register,
+                map,
+                key,
+                jump_offset,
+            } => self.run_check_map_entry(register, map, key, jump_offset as u32)?,
+            MapRest {
+                register,
+                map,
+                keys_start,
+                key_count,
+            } => self.run_map_rest(register, map, keys_start, key_count)?,
+            RangeContains {
+                register,
+                range,
+                value,
+            } => self.run_range_contains(register, range, value)?,
+            Freeze { value } => self.get_register(value).freeze(),
+            MakeRecord {
+                register,
+                fields_start,
+                field_count,
+                name,
+            } => self.run_make_record(register, fields_start, field_count, name, None)?,
+            MakeEnumVariant {
+                register,
+                fields_start,
+                field_count,
+                enum_name,
+                variant_name,
+            } => self.run_make_record(
+                register,
+                fields_start,
+                field_count,
+                enum_name,
+                Some(variant_name),
+            )?,
+            CheckRecord {
+                register,
+                value,
+                constructor,
+                jump_offset,
+            } => self.run_check_record(register, value, constructor, jump_offset as u32)?,
+            CheckRecordConstructor { value, jump_offset } => {
+                self.run_check_record_constructor(value, jump_offset as u32)
+            }
+            MakeInterface {
+                register,
+                keys,
+                name,
+            } => self.run_make_interface(register, keys, name)?,
         }
 
         Ok(control_flow)
@@ -1063,6 +1250,7 @@ impl KotoVm {
             Tuple(tuple) => KIterator::with_tuple(tuple).into(),
             Str(s) => KIterator::with_string(s).into(),
             Map(map) => KIterator::with_map(map).into(),
+            Set(set) => KIterator::with_set(set).into(),
             Object(o) => {
                 use IsIterable::*;
                 let o_inner = o.try_borrow()?;
@@ -1404,6 +1592,12 @@ impl KotoVm {
         use UnaryOp::Negate;
 
         let result_value = match self.clone_register(value) {
+            Number(n) if self.context.settings.enable_integer_overflow_checks => {
+                match n.checked_neg() {
+                    Some(result) => Number(result),
+                    None => return runtime_error!("Integer overflow in '-{n}'"),
+                }
+            }
             Number(n) => Number(-n),
             Map(m) if m.contains_meta_key(&Negate.into()) => {
                 let op = m.get_meta_value(&Negate.into()).unwrap();
@@ -1451,6 +1645,28 @@ impl KotoVm {
         }
     }
 
+    // Performs an arithmetic operation on two numbers
+    //
+    // The checked version of the operation is used when integer overflow checks are enabled,
+    // with an error returned if the operation overflowed.
+    fn number_op(
+        &self,
+        a: KNumber,
+        b: KNumber,
+        op: BinaryOp,
+        unchecked_op: fn(KNumber, KNumber) -> KNumber,
+        checked_op: fn(KNumber, KNumber) -> Option<KNumber>,
+    ) -> Result<KNumber> {
+        if !self.context.settings.enable_integer_overflow_checks {
+            return Ok(unchecked_op(a, b));
+        }
+
+        match checked_op(a, b) {
+            Some(result) => Ok(result),
+            None => runtime_error!("Integer overflow in '{a} {op} {b}'"),
+        }
+    }
+
     fn run_add(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
         use BinaryOp::Add;
         use KValue::*;
@@ -1458,7 +1674,9 @@ impl KotoVm {
         let lhs_value = self.get_register(lhs);
         let rhs_value = self.get_register(rhs);
         let result_value = match (lhs_value, rhs_value) {
-            (Number(a), Number(b)) => Number(a + b),
+            (Number(a), Number(b)) => {
+                Number(self.number_op(*a, *b, Add, |a, b| a + b, KNumber::checked_add)?)
+            }
             (Str(a), Str(b)) => {
                 let result = a.to_string() + b.as_ref();
                 Str(result.into())
@@ -1492,6 +1710,7 @@ impl KotoVm {
                 Map(KMap::with_contents(data, meta))
             }
             (Object(o), _) => o.try_borrow()?.add(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.add_rhs(lhs_value)?,
             _ => return binary_op_error(lhs_value, rhs_value, Add),
         };
 
@@ -1506,13 +1725,16 @@ impl KotoVm {
         let lhs_value = self.get_register(lhs);
         let rhs_value = self.get_register(rhs);
         let result_value = match (lhs_value, rhs_value) {
-            (Number(a), Number(b)) => Number(a - b),
+            (Number(a), Number(b)) => {
+                Number(self.number_op(*a, *b, Subtract, |a, b| a - b, KNumber::checked_sub)?)
+            }
             (Map(m), _) if m.contains_meta_key(&Subtract.into()) => {
                 let op = m.get_meta_value(&Subtract.into()).unwrap();
                 let rhs_value = rhs_value.clone();
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.subtract(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.subtract_rhs(lhs_value)?,
             _ => return binary_op_error(lhs_value, rhs_value, Subtract),
         };
 
@@ -1528,13 +1750,16 @@ impl KotoVm {
         let rhs_value = self.get_register(rhs);
 
         let result_value = match (lhs_value, rhs_value) {
-            (Number(a), Number(b)) => Number(a * b),
+            (Number(a), Number(b)) => {
+                Number(self.number_op(*a, *b, Multiply, |a, b| a * b, KNumber::checked_mul)?)
+            }
             (Map(m), _) if m.contains_meta_key(&Multiply.into()) => {
                 let op = m.get_meta_value(&Multiply.into()).unwrap();
                 let rhs_value = rhs_value.clone();
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.multiply(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.multiply_rhs(lhs_value)?,
             _ => return binary_op_error(lhs_value, rhs_value, Multiply),
         };
 
@@ -1556,6 +1781,7 @@ impl KotoVm {
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.divide(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.divide_rhs(lhs_value)?,
             _ => return binary_op_error(lhs_value, rhs_value, Divide),
         };
 
@@ -1563,6 +1789,34 @@ impl KotoVm {
         Ok(())
     }
 
+    fn run_integer_divide(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
+        use BinaryOp::IntegerDivide;
+        use KValue::*;
+
+        let lhs_value = self.get_register(lhs);
+        let rhs_value = self.get_register(rhs);
+        let result_value = match (lhs_value, rhs_value) {
+            (Number(a), Number(b)) => Number(self.number_op(
+                *a,
+                *b,
+                IntegerDivide,
+                KNumber::div_floor,
+                KNumber::checked_div_floor,
+            )?),
+            (Map(m), _) if m.contains_meta_key(&IntegerDivide.into()) => {
+                let op = m.get_meta_value(&IntegerDivide.into()).unwrap();
+                let rhs_value = rhs_value.clone();
+                return self.call_overridden_binary_op(result, lhs, rhs_value, op);
+            }
+            (Object(o), _) => o.try_borrow()?.integer_divide(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.integer_divide_rhs(lhs_value)?,
+            _ => return binary_op_error(lhs_value, rhs_value, IntegerDivide),
+        };
+
+        self.set_register(result, result_value);
+        Ok(())
+    }
+
     fn run_remainder(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
         use BinaryOp::Remainder;
         use KValue::*;
@@ -1582,6 +1836,7 @@ impl KotoVm {
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.remainder(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.remainder_rhs(lhs_value)?,
             _ => return binary_op_error(lhs_value, rhs_value, Remainder),
         };
         self.set_register(result, result_value);
@@ -1589,6 +1844,149 @@ impl KotoVm {
         Ok(())
     }
 
+    fn run_power(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
+        use BinaryOp::Power;
+        use KValue::*;
+
+        let lhs_value = self.get_register(lhs);
+        let rhs_value = self.get_register(rhs);
+        let result_value = match (lhs_value, rhs_value) {
+            (Number(a), Number(b)) => {
+                Number(self.number_op(*a, *b, Power, KNumber::pow, KNumber::checked_pow)?)
+            }
+            (Map(m), _) if m.contains_meta_key(&Power.into()) => {
+                let op = m.get_meta_value(&Power.into()).unwrap();
+                let rhs_value = rhs_value.clone();
+                return self.call_overridden_binary_op(result, lhs, rhs_value, op);
+            }
+            (Object(o), _) => o.try_borrow()?.power(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.power_rhs(lhs_value)?,
+            _ => return binary_op_error(lhs_value, rhs_value, Power),
+        };
+
+        self.set_register(result, result_value);
+        Ok(())
+    }
+
+    fn run_bitwise_and(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
+        use BinaryOp::BitwiseAnd;
+        use KValue::*;
+
+        let lhs_value = self.get_register(lhs);
+        let rhs_value = self.get_register(rhs);
+        let result_value = match (lhs_value, rhs_value) {
+            (Number(a), Number(b)) => Number((i64::from(a) & i64::from(b)).into()),
+            (Map(m), _) if m.contains_meta_key(&BitwiseAnd.into()) => {
+                let op = m.get_meta_value(&BitwiseAnd.into()).unwrap();
+                let rhs_value = rhs_value.clone();
+                return self.call_overridden_binary_op(result, lhs, rhs_value, op);
+            }
+            (Object(o), _) => o.try_borrow()?.bitwise_and(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.bitwise_and_rhs(lhs_value)?,
+            _ => return binary_op_error(lhs_value, rhs_value, BitwiseAnd),
+        };
+
+        self.set_register(result, result_value);
+        Ok(())
+    }
+
+    fn run_bitwise_or(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
+        use BinaryOp::BitwiseOr;
+        use KValue::*;
+
+        let lhs_value = self.get_register(lhs);
+        let rhs_value = self.get_register(rhs);
+        let result_value = match (lhs_value, rhs_value) {
+            (Number(a), Number(b)) => Number((i64::from(a) | i64::from(b)).into()),
+            (Map(m), _) if m.contains_meta_key(&BitwiseOr.into()) => {
+                let op = m.get_meta_value(&BitwiseOr.into()).unwrap();
+                let rhs_value = rhs_value.clone();
+                return self.call_overridden_binary_op(result, lhs, rhs_value, op);
+            }
+            (Object(o), _) => o.try_borrow()?.bitwise_or(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.bitwise_or_rhs(lhs_value)?,
+            _ => return binary_op_error(lhs_value, rhs_value, BitwiseOr),
+        };
+
+        self.set_register(result, result_value);
+        Ok(())
+    }
+
+    fn run_bitwise_xor(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
+        use BinaryOp::BitwiseXor;
+        use KValue::*;
+
+        let lhs_value = self.get_register(lhs);
+        let rhs_value = self.get_register(rhs);
+        let result_value = match (lhs_value, rhs_value) {
+            (Number(a), Number(b)) => Number((i64::from(a) ^ i64::from(b)).into()),
+            (Map(m), _) if m.contains_meta_key(&BitwiseXor.into()) => {
+                let op = m.get_meta_value(&BitwiseXor.into()).unwrap();
+                let rhs_value = rhs_value.clone();
+                return self.call_overridden_binary_op(result, lhs, rhs_value, op);
+            }
+            (Object(o), _) => o.try_borrow()?.bitwise_xor(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.bitwise_xor_rhs(lhs_value)?,
+            _ => return binary_op_error(lhs_value, rhs_value, BitwiseXor),
+        };
+
+        self.set_register(result, result_value);
+        Ok(())
+    }
+
+    fn run_shift_left(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
+        use BinaryOp::ShiftLeft;
+        use KValue::*;
+
+        let lhs_value = self.get_register(lhs);
+        let rhs_value = self.get_register(rhs);
+        let result_value = match (lhs_value, rhs_value) {
+            (Number(a), Number(b)) => {
+                let shift = shift_amount(b)?;
+                Number(i64::from(a).checked_shl(shift).unwrap_or(0).into())
+            }
+            (Map(m), _) if m.contains_meta_key(&ShiftLeft.into()) => {
+                let op = m.get_meta_value(&ShiftLeft.into()).unwrap();
+                let rhs_value = rhs_value.clone();
+                return self.call_overridden_binary_op(result, lhs, rhs_value, op);
+            }
+            (Object(o), _) => o.try_borrow()?.shift_left(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.shift_left_rhs(lhs_value)?,
+            _ => return binary_op_error(lhs_value, rhs_value, ShiftLeft),
+        };
+
+        self.set_register(result, result_value);
+        Ok(())
+    }
+
+    fn run_shift_right(&mut self, result: u8, lhs: u8, rhs: u8) -> Result<()> {
+        use BinaryOp::ShiftRight;
+        use KValue::*;
+
+        let lhs_value = self.get_register(lhs);
+        let rhs_value = self.get_register(rhs);
+        let result_value = match (lhs_value, rhs_value) {
+            (Number(a), Number(b)) => {
+                let a = i64::from(a);
+                let shift = shift_amount(b)?;
+                // Shifting by more than the number of bits fills the result with the sign bit
+                let result = a.checked_shr(shift).unwrap_or(if a < 0 { -1 } else { 0 });
+                Number(result.into())
+            }
+            (Map(m), _) if m.contains_meta_key(&ShiftRight.into()) => {
+                let op = m.get_meta_value(&ShiftRight.into()).unwrap();
+                let rhs_value = rhs_value.clone();
+                return self.call_overridden_binary_op(result, lhs, rhs_value, op);
+            }
+            (Object(o), _) => o.try_borrow()?.shift_right(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.shift_right_rhs(lhs_value)?,
+            _ => return binary_op_error(lhs_value, rhs_value, ShiftRight),
+        };
+
+        self.set_register(result, result_value);
+        Ok(())
+    }
+
     fn run_add_assign(&mut self, lhs: u8, rhs: u8) -> Result<()> {
         use BinaryOp::AddAssign;
         use KValue::*;
@@ -1597,7 +1995,9 @@ impl KotoVm {
         let rhs_value = self.get_register(rhs);
         match (lhs_value, rhs_value) {
             (Number(a), Number(b)) => {
-                self.set_register(lhs, Number(a + b));
+                let result =
+                    self.number_op(*a, *b, AddAssign, |a, b| a + b, KNumber::checked_add)?;
+                self.set_register(lhs, Number(result));
                 Ok(())
             }
             (Map(m), _) if m.contains_meta_key(&AddAssign.into()) => {
@@ -1612,6 +2012,11 @@ impl KotoVm {
                 o.try_borrow_mut()?.add_assign(&o2)
             }
             (Object(o), _) => o.try_borrow_mut()?.add_assign(rhs_value),
+            (_, Object(o)) => {
+                let result = o.try_borrow()?.add_rhs(lhs_value)?;
+                self.set_register(lhs, result);
+                Ok(())
+            }
             _ => binary_op_error(lhs_value, rhs_value, AddAssign),
         }
     }
@@ -1624,7 +2029,9 @@ impl KotoVm {
         let rhs_value = self.get_register(rhs);
         match (lhs_value, rhs_value) {
             (Number(a), Number(b)) => {
-                self.set_register(lhs, Number(a - b));
+                let result =
+                    self.number_op(*a, *b, SubtractAssign, |a, b| a - b, KNumber::checked_sub)?;
+                self.set_register(lhs, Number(result));
                 Ok(())
             }
             (Map(m), _) if m.contains_meta_key(&SubtractAssign.into()) => {
@@ -1639,6 +2046,11 @@ impl KotoVm {
                 o.try_borrow_mut()?.subtract_assign(&o2)
             }
             (Object(o), _) => o.try_borrow_mut()?.subtract_assign(rhs_value),
+            (_, Object(o)) => {
+                let result = o.try_borrow()?.subtract_rhs(lhs_value)?;
+                self.set_register(lhs, result);
+                Ok(())
+            }
             _ => binary_op_error(lhs_value, rhs_value, SubtractAssign),
         }
     }
@@ -1651,7 +2063,9 @@ impl KotoVm {
         let rhs_value = self.get_register(rhs);
         match (lhs_value, rhs_value) {
             (Number(a), Number(b)) => {
-                self.set_register(lhs, Number(a * b));
+                let result =
+                    self.number_op(*a, *b, MultiplyAssign, |a, b| a * b, KNumber::checked_mul)?;
+                self.set_register(lhs, Number(result));
                 Ok(())
             }
             (Map(m), _) if m.contains_meta_key(&MultiplyAssign.into()) => {
@@ -1666,6 +2080,11 @@ impl KotoVm {
                 o.try_borrow_mut()?.multiply_assign(&o2)
             }
             (Object(o), _) => o.try_borrow_mut()?.multiply_assign(rhs_value),
+            (_, Object(o)) => {
+                let result = o.try_borrow()?.multiply_rhs(lhs_value)?;
+                self.set_register(lhs, result);
+                Ok(())
+            }
             _ => binary_op_error(lhs_value, rhs_value, MultiplyAssign),
         }
     }
@@ -1693,6 +2112,11 @@ impl KotoVm {
                 o.try_borrow_mut()?.divide_assign(&o2)
             }
             (Object(o), _) => o.try_borrow_mut()?.divide_assign(rhs_value),
+            (_, Object(o)) => {
+                let result = o.try_borrow()?.divide_rhs(lhs_value)?;
+                self.set_register(lhs, result);
+                Ok(())
+            }
             _ => binary_op_error(lhs_value, rhs_value, DivideAssign),
         }
     }
@@ -1720,6 +2144,11 @@ impl KotoVm {
                 o.try_borrow_mut()?.remainder_assign(&o2)
             }
             (Object(o), _) => o.try_borrow_mut()?.remainder_assign(rhs_value),
+            (_, Object(o)) => {
+                let result = o.try_borrow()?.remainder_rhs(lhs_value)?;
+                self.set_register(lhs, result);
+                Ok(())
+            }
             _ => binary_op_error(lhs_value, rhs_value, RemainderAssign),
         }
     }
@@ -1739,6 +2168,7 @@ impl KotoVm {
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.less(rhs_value)?.into(),
+            (_, Object(o)) => o.try_borrow()?.greater(lhs_value)?.into(),
             _ => return binary_op_error(lhs_value, rhs_value, Less),
         };
         self.set_register(result, result_value);
@@ -1761,6 +2191,7 @@ impl KotoVm {
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.less_or_equal(rhs_value)?.into(),
+            (_, Object(o)) => o.try_borrow()?.greater_or_equal(lhs_value)?.into(),
             _ => return binary_op_error(lhs_value, rhs_value, LessOrEqual),
         };
         self.set_register(result, result_value);
@@ -1783,6 +2214,7 @@ impl KotoVm {
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.greater(rhs_value)?.into(),
+            (_, Object(o)) => o.try_borrow()?.less(lhs_value)?.into(),
             _ => return binary_op_error(lhs_value, rhs_value, Greater),
         };
         self.set_register(result, result_value);
@@ -1805,6 +2237,7 @@ impl KotoVm {
                 return self.call_overridden_binary_op(result, lhs, rhs_value, op);
             }
             (Object(o), _) => o.try_borrow()?.greater_or_equal(rhs_value)?.into(),
+            (_, Object(o)) => o.try_borrow()?.less_or_equal(lhs_value)?.into(),
             _ => return binary_op_error(lhs_value, rhs_value, GreaterOrEqual),
         };
         self.set_register(result, result_value);
@@ -1837,6 +2270,7 @@ impl KotoVm {
                 let b = b.clone();
                 self.compare_value_ranges(&a, &b)?
             }
+            (Set(a), Set(b)) => a.is_equal(b),
             (Map(m), _) if m.contains_meta_key(&Equal.into()) => {
                 let op = m.get_meta_value(&Equal.into()).unwrap();
                 let rhs_value = rhs_value.clone();
@@ -1852,6 +2286,7 @@ impl KotoVm {
                 }
             }
             (Object(o), _) => o.try_borrow()?.equal(rhs_value)?,
+            (_, Object(o)) => o.try_borrow()?.equal_rhs(lhs_value)?,
             (CaptureFunction(a), CaptureFunction(b)) => {
                 if a.info == b.info {
                     let captures_a = a.captures.clone();
@@ -1897,6 +2332,7 @@ impl KotoVm {
                 let b = b.clone();
                 !self.compare_value_ranges(&a, &b)?
             }
+            (Set(a), Set(b)) => !a.is_equal(b),
             (Map(m), _) if m.contains_meta_key(&NotEqual.into()) => {
                 let op = m.get_meta_value(&NotEqual.into()).unwrap();
                 let rhs_value = rhs_value.clone();
@@ -1912,6 +2348,7 @@ impl KotoVm {
                 }
             }
             (Object(o), _) => o.try_borrow()?.not_equal(rhs_value)?,
+            (_, Object(o)) => !o.try_borrow()?.equal_rhs(lhs_value)?,
             (CaptureFunction(a), CaptureFunction(b)) => {
                 if a.info == b.info {
                     let captures_a = a.captures.clone();
@@ -2071,6 +2508,7 @@ impl KotoVm {
                 return self.call_overridden_unary_op(result_register, value_register, op);
             }
             Map(m) => Some(m.len()),
+            Set(s) => Some(s.len()),
             Object(o) => o.try_borrow()?.size(),
             TemporaryTuple(RegisterSlice { count, .. }) => Some(*count as usize),
             _ => None,
@@ -2114,12 +2552,11 @@ impl KotoVm {
         // Attempt to compile the imported module from disk,
         // using the current source path as the relative starting location
         let source_path = self.reader.chunk.source_path.clone();
-        let compile_result = match self
-            .context
-            .loader
-            .borrow_mut()
-            .compile_module(&import_name, source_path.as_deref())
-        {
+        let compile_result = match self.context.loader.borrow_mut().compile_module(
+            &import_name,
+            source_path.as_deref(),
+            self.context.settings.module_compiler_settings,
+        ) {
             Ok(result) => result,
             Err(error) => return runtime_error!("Failed to import '{import_name}': {error}"),
         };
@@ -2235,7 +2672,7 @@ impl KotoVm {
 
         match indexable {
             List(list) => {
-                let mut list_data = list.data_mut();
+                let mut list_data = list.checked_data_mut()?;
                 let list_len = list_data.len();
                 match index_value {
                     Number(index) => {
@@ -2365,7 +2802,7 @@ impl KotoVm {
 
         match self.get_register(map_register) {
             KValue::Map(map) => {
-                map.data_mut().insert(key, value);
+                map.checked_data_mut()?.insert(key, value);
                 Ok(())
             }
             KValue::Object(o) => {
@@ -2483,6 +2920,7 @@ impl KotoVm {
             Range(_) => core_op!(range, true),
             Str(_) => core_op!(string, true),
             Tuple(_) => core_op!(tuple, true),
+            Set(_) => core_op!(set, true),
             Iterator(_) => core_op!(iterator, false),
             Map(map) => {
                 let mut access_map = map.clone();
@@ -2846,8 +3284,14 @@ impl KotoVm {
         }
     }
 
-    fn run_assert_type(&self, value_register: u8, type_index: ConstantIndex) -> Result<()> {
-        if self.compare_value_type(value_register, type_index) {
+    fn run_assert_type(
+        &self,
+        value_register: u8,
+        type_index: ConstantIndex,
+        bindings: u8,
+        binding_count: u8,
+    ) -> Result<()> {
+        if self.compare_value_type(value_register, type_index, bindings, binding_count) {
             Ok(())
         } else {
             unexpected_type(
@@ -2862,29 +3306,361 @@ impl KotoVm {
         value_register: u8,
         jump_offset: u32,
         type_index: ConstantIndex,
+        bindings: u8,
+        binding_count: u8,
     ) -> Result<()> {
-        if !self.compare_value_type(value_register, type_index) {
+        if !self.compare_value_type(value_register, type_index, bindings, binding_count) {
             self.jump_ip(jump_offset);
         }
         Ok(())
     }
 
-    fn compare_value_type(&self, value_register: u8, type_index: ConstantIndex) -> bool {
+    fn run_assert_map(&self, value_register: u8) -> Result<()> {
+        match self.get_register(value_register) {
+            KValue::Map(_) => Ok(()),
+            unexpected => unexpected_type("Map", unexpected),
+        }
+    }
+
+    fn run_check_map(&mut self, value_register: u8, jump_offset: u32) {
+        if !matches!(self.get_register(value_register), KValue::Map(_)) {
+            self.jump_ip(jump_offset);
+        }
+    }
+
+    fn run_access_map_entry(
+        &mut self,
+        result_register: u8,
+        map_register: u8,
+        key: ConstantIndex,
+    ) -> Result<()> {
+        match self.get_map_pattern_entry(map_register, key)? {
+            Some(value) => {
+                self.set_register(result_register, value);
+                Ok(())
+            }
+            None => runtime_error!(
+                "The map is missing an entry for '{}'",
+                self.get_constant_str(key)
+            ),
+        }
+    }
+
+    fn run_check_map_entry(
+        &mut self,
+        result_register: u8,
+        map_register: u8,
+        key: ConstantIndex,
+        jump_offset: u32,
+    ) -> Result<()> {
+        match self.get_map_pattern_entry(map_register, key)? {
+            Some(value) => self.set_register(result_register, value),
+            None => self.jump_ip(jump_offset),
+        }
+        Ok(())
+    }
+
+    // Gets a map entry while unpacking a map pattern
+    fn get_map_pattern_entry(
+        &self,
+        map_register: u8,
+        key: ConstantIndex,
+    ) -> Result<Option<KValue>> {
+        match self.get_register(map_register) {
+            KValue::Map(map) => Ok(map.get(&ValueKey::from(self.koto_string_from_constant(key)))),
+            unexpected => unexpected_type("Map", unexpected),
+        }
+    }
+
+    fn run_map_rest(
+        &mut self,
+        result_register: u8,
+        map_register: u8,
+        keys_start: u8,
+        key_count: u8,
+    ) -> Result<()> {
+        let KValue::Map(map) = self.get_register(map_register) else {
+            return unexpected_type("Map", self.get_register(map_register));
+        };
+
+        let keys = self
+            .register_slice(keys_start, key_count)
+            .iter()
+            .cloned()
+            .map(ValueKey::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let rest = map
+            .data()
+            .iter()
+            .filter(|(key, _)| !keys.contains(key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<ValueMap>();
+
+        self.set_register(result_register, KMap::with_data(rest).into());
+        Ok(())
+    }
+
+    fn run_make_record(
+        &mut self,
+        result_register: u8,
+        fields_start: u8,
+        field_count: u8,
+        name: ConstantIndex,
+        variant_name: Option<ConstantIndex>,
+    ) -> Result<()> {
+        let fields = self
+            .register_slice(fields_start, field_count * 3)
+            .chunks_exact(3)
+            .map(|field| match field {
+                [KValue::Str(name), KValue::Str(type_hint), bindings] => Ok(RecordField {
+                    name: name.clone(),
+                    type_hint: Some(type_hint.clone()),
+                    type_hint_bindings: match bindings {
+                        KValue::Tuple(bindings) => bindings.to_vec(),
+                        _ => Vec::new(),
+                    },
+                }),
+                [KValue::Str(name), KValue::Null, _] => Ok(RecordField {
+                    name: name.clone(),
+                    type_hint: None,
+                    type_hint_bindings: Vec::new(),
+                }),
+                [unexpected, _, _] => unexpected_type("String as record field name", unexpected),
+                _ => unreachable!(),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let name = self.koto_string_from_constant(name);
+        let result = match variant_name {
+            Some(variant_name) => {
+                let variant_name = self.get_constant_str(variant_name);
+                if fields.is_empty() {
+                    RecordConstructor::make_unit_variant(name, variant_name)
+                } else {
+                    RecordConstructor::new_variant(name, variant_name, fields).into()
+                }
+            }
+            None => RecordConstructor::new(name, fields).into(),
+        };
+
+        self.set_register(result_register, result);
+        Ok(())
+    }
+
+    fn run_check_record(
+        &mut self,
+        result_register: u8,
+        value_register: u8,
+        constructor_register: u8,
+        jump_offset: u32,
+    ) -> Result<()> {
+        let field_values = match self.get_register(constructor_register) {
+            KValue::Object(o) if o.is_a::<RecordConstructor>() => o
+                .cast::<RecordConstructor>()?
+                .field_values(self.get_register(value_register)),
+            unexpected => return unexpected_type("a record constructor", unexpected),
+        };
+
+        match field_values {
+            Some(values) => self.set_register(result_register, values.into()),
+            None => self.jump_ip(jump_offset),
+        }
+
+        Ok(())
+    }
+
+    fn run_check_record_constructor(&mut self, value_register: u8, jump_offset: u32) {
+        if !matches!(
+            self.get_register(value_register),
+            KValue::Object(o) if o.is_a::<RecordConstructor>()
+        ) {
+            self.jump_ip(jump_offset);
+        }
+    }
+
+    fn run_make_interface(
+        &mut self,
+        result_register: u8,
+        keys_register: u8,
+        name: ConstantIndex,
+    ) -> Result<()> {
+        let KValue::Map(keys_map) = self.get_register(keys_register) else {
+            return unexpected_type("Map", self.get_register(keys_register));
+        };
+
+        let keys = keys_map.data().keys().cloned().collect();
+        let meta_keys = keys_map
+            .meta_map()
+            .map(|meta| meta.borrow().keys().cloned().collect())
+            .unwrap_or_default();
+
+        let name = self.koto_string_from_constant(name);
+        let interface = Interface::new(name, keys, meta_keys);
+        self.set_register(result_register, interface.into());
+        Ok(())
+    }
+
+    fn run_range_contains(
+        &mut self,
+        result_register: u8,
+        range_register: u8,
+        value_register: u8,
+    ) -> Result<()> {
+        let KValue::Range(range) = self.get_register(range_register) else {
+            return unexpected_type("Range", self.get_register(range_register));
+        };
+
+        let result = match self.get_register(value_register) {
+            KValue::Number(n) => {
+                let n = f64::from(n);
+                let after_start = match range.start() {
+                    Some(start) => n >= start as f64,
+                    None => true,
+                };
+                let before_end = match range.end() {
+                    Some((end, true)) => n <= end as f64,
+                    Some((end, false)) => n < end as f64,
+                    None => true,
+                };
+                after_start && before_end
+            }
+            _ => false,
+        };
+
+        self.set_register(result_register, result.into());
+        Ok(())
+    }
+
+    fn compare_value_type(
+        &self,
+        value_register: u8,
+        type_index: ConstantIndex,
+        bindings: u8,
+        binding_count: u8,
+    ) -> bool {
         let value = self.get_register(value_register);
-        match self.get_constant_str(type_index) {
+        let hint = self.get_constant_str(type_index);
+        let bindings = self.register_slice(bindings, binding_count);
+        self.value_matches_type(value, hint, bindings)
+    }
+
+    // Checks a value against a type hint, e.g. `Number`, or `List<String>?`
+    //
+    // The bindings contain the values assigned to the names in the hint, in the order in which
+    // the names first appear. Bindings can be empty when none of the names refer to local values.
+    pub(crate) fn value_matches_type(
+        &self,
+        value: &KValue,
+        hint: &str,
+        bindings: &[KValue],
+    ) -> bool {
+        if TypeHint::is_simple(hint) {
+            self.value_matches_type_name(value, hint, bindings.first())
+        } else {
+            self.parsed_type_hint(hint)
+                .is_some_and(|hint| self.value_matches_type_hint(value, &hint, bindings))
+        }
+    }
+
+    // Returns the parsed form of a type hint, parsing it if it hasn't been encountered before
+    //
+    // None is returned if the hint is invalid.
+    fn parsed_type_hint(&self, hint: &str) -> Option<Ptr<TypeHint>> {
+        if let Some(cached) = self.context.type_hints.borrow().get(hint) {
+            return cached.clone();
+        }
+
+        let parsed = TypeHint::parse(hint).map(Ptr::from);
+        self.context
+            .type_hints
+            .borrow_mut()
+            .insert(hint.to_string(), parsed.clone());
+        parsed
+    }
+
+    fn value_matches_type_hint(
+        &self,
+        value: &KValue,
+        hint: &TypeHint,
+        bindings: &[KValue],
+    ) -> bool {
+        match hint {
+            TypeHint::Union(types) => types
+                .iter()
+                .any(|member| self.value_matches_type_hint(value, member, bindings)),
+            TypeHint::Type {
+                name,
+                binding,
+                nested,
+                optional,
+            } => {
+                if *optional && matches!(value, KValue::Null) {
+                    return true;
+                }
+
+                if !self.value_matches_type_name(value, name, bindings.get(*binding)) {
+                    return false;
+                }
+
+                if nested.is_empty() || !self.context.settings.enable_container_type_checks {
+                    return true;
+                }
+
+                // Check the container's elements against the nested types.
+                // Nested types for other values are accepted without checking.
+                match (name.as_str(), value, nested.as_slice()) {
+                    ("List", KValue::List(l), [element_type]) => l.data().iter().all(|element| {
+                        self.value_matches_type_hint(element, element_type, bindings)
+                    }),
+                    ("Set", KValue::Set(s), [element_type]) => s.data().iter().all(|element| {
+                        self.value_matches_type_hint(element.value(), element_type, bindings)
+                    }),
+                    ("Tuple", KValue::Tuple(t), [element_type]) => t.iter().all(|element| {
+                        self.value_matches_type_hint(element, element_type, bindings)
+                    }),
+                    ("Tuple", KValue::Tuple(t), element_types) => {
+                        t.len() == element_types.len()
+                            && t.iter()
+                                .zip(element_types.iter())
+                                .all(|(element, element_type)| {
+                                    self.value_matches_type_hint(element, element_type, bindings)
+                                })
+                    }
+                    ("Map", KValue::Map(m), [value_type]) => m.data().values().all(|entry_value| {
+                        self.value_matches_type_hint(entry_value, value_type, bindings)
+                    }),
+                    ("Map", KValue::Map(m), [key_type, value_type]) => {
+                        m.data().iter().all(|(key, entry_value)| {
+                            self.value_matches_type_hint(key.value(), key_type, bindings)
+                                && self.value_matches_type_hint(entry_value, value_type, bindings)
+                        })
+                    }
+                    _ => true,
+                }
+            }
+        }
+    }
+
+    fn value_matches_type_name(
+        &self,
+        value: &KValue,
+        expected_type: &str,
+        binding: Option<&KValue>,
+    ) -> bool {
+        match expected_type {
             "Any" => true,
             "Callable" => value.is_callable(),
             "Indexable" => value.is_indexable(),
             "Iterable" => value.is_iterable(),
-            expected_type => {
+            _ => {
                 if value.type_as_string() == expected_type {
                     true
                 } else {
                     // The type didn't match, so look for a base value to check
-                    let mut value = value.clone();
+                    let mut current = value.clone();
 
                     loop {
-                        match value {
+                        match current {
                             KValue::Map(m) if m.contains_meta_key(&MetaKey::Base) => {
                                 let base = m.get_meta_value(&MetaKey::Base).unwrap();
                                 if base.type_as_string() == expected_type {
@@ -2892,19 +3668,101 @@ impl KotoVm {
                                 } else {
                                     // The base didn't match the expected type,
                                     // but continue looping to check the base's base.
-                                    value = base;
+                                    current = base;
                                 }
                             }
                             _ => break,
                         }
                     }
 
-                    false
+                    // Finally, check if the expected type refers to an interface, looking
+                    // first at the value bound to the type's name, and then at non-local values.
+                    let resolved = match binding {
+                        Some(KValue::Null) | None => self
+                            .exports
+                            .get(expected_type)
+                            .or_else(|| self.context.prelude.get(expected_type)),
+                        Some(bound) => Some(bound.clone()),
+                    };
+
+                    match resolved {
+                        Some(KValue::Object(o)) => o
+                            .cast::<Interface>()
+                            .is_ok_and(|interface| self.value_conforms_to(value, &interface)),
+                        _ => false,
+                    }
                 }
             }
         }
     }
 
+    // Checks that a value provides all of the keys and meta keys required by an interface
+    fn value_conforms_to(&self, value: &KValue, interface: &Interface) -> bool {
+        interface
+            .keys()
+            .iter()
+            .all(|key| self.value_has_key(value, key))
+            && interface
+                .meta_keys()
+                .iter()
+                .all(|meta_key| value_has_meta_key(value, meta_key))
+    }
+
+    // Checks if a key can be accessed on a value, following the same lookup rules as `.` access
+    fn value_has_key(&self, value: &KValue, key: &ValueKey) -> bool {
+        use KValue::*;
+
+        let core_lib = &self.context.core_lib;
+        let core_module_has_key = |module: &KMap, iterator_fallback: bool| {
+            module.get(key).is_some() || (iterator_fallback && core_lib.iterator.get(key).is_some())
+        };
+
+        match value {
+            List(_) => core_module_has_key(&core_lib.list, true),
+            Number(_) => core_module_has_key(&core_lib.number, false),
+            Range(_) => core_module_has_key(&core_lib.range, true),
+            Str(_) => core_module_has_key(&core_lib.string, true),
+            Tuple(_) => core_module_has_key(&core_lib.tuple, true),
+            Set(_) => core_module_has_key(&core_lib.set, true),
+            Iterator(_) => core_module_has_key(&core_lib.iterator, false),
+            Map(map) => {
+                if map.meta_map().is_none() {
+                    return map.get(key).is_some() || core_module_has_key(&core_lib.map, false);
+                }
+
+                let named_key = match key.value() {
+                    Str(name) => Some(MetaKey::Named(name.clone())),
+                    _ => None,
+                };
+                let mut map = map.clone();
+                loop {
+                    if map.get(key).is_some()
+                        || named_key
+                            .as_ref()
+                            .is_some_and(|named| map.contains_meta_key(named))
+                    {
+                        return true;
+                    }
+                    match map.get_meta_value(&MetaKey::Base) {
+                        Some(Map(base)) => map = base,
+                        _ => break,
+                    }
+                }
+
+                (map.contains_meta_key(&UnaryOp::Iterator.into())
+                    || map.contains_meta_key(&UnaryOp::Next.into()))
+                    && core_lib.iterator.get(key).is_some()
+            }
+            Object(o) => o.try_borrow().is_ok_and(|o| {
+                o.entries()
+                    .is_some_and(|entries| entries.get(key).is_some())
+                    || (!matches!(o.is_iterable(), IsIterable::NotIterable)
+                        && core_lib.iterator.get(key).is_some())
+            }),
+            _ => false,
+        }
+    }
+
     fn get_value_size(&mut self, value_register: u8) -> Result<usize> {
         match self.run_unary_op(UnaryOp::Size, self.clone_register(value_register))? {
             KValue::Number(n) => Ok(n.into()),
@@ -2949,14 +3807,52 @@ impl KotoVm {
         let value = self.clone_register(value_register);
         let value_is_number = matches!(&value, KValue::Number(_));
 
+        if let Some(options) = format_options {
+            // Values that implement @format are responsible for interpreting the format string
+            match &value {
+                KValue::Map(m) => {
+                    if let Some(format_fn) = m.get_meta_value(&MetaKey::Format) {
+                        let format_string = self.format_options_string(options);
+                        let rendered = match self.call_instance_function(
+                            value.clone(),
+                            format_fn,
+                            format_string,
+                        )? {
+                            KValue::Str(rendered) => rendered,
+                            unexpected => {
+                                return unexpected_type("String from @format", &unexpected)
+                            }
+                        };
+                        return self.push_to_string_builder(&rendered);
+                    }
+                }
+                KValue::Object(o) => {
+                    let format_string = self.format_options_string(options);
+                    if let Some(rendered) = o.try_borrow()?.format(&format_string)? {
+                        return self.push_to_string_builder(&rendered);
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some(format_string) = options.format_string {
+                let format_string = self.get_constant_str(format_string);
+                return runtime_error!(
+                    "Unsupported format string '{format_string}' for a value of type '{}'",
+                    value.type_as_string()
+                );
+            }
+        }
+
         // Render the value as a string, applying the precision option if specified
+        //
+        // The sign and representation prefix of formatted numbers are kept separate from the
+        // rendered digits so that zero-padding can be inserted between them.
         let precision = format_options.and_then(|options| options.precision);
-        let rendered = match value {
-            KValue::Number(n) => match precision {
-                Some(precision) if n.is_f64() || n.is_i64_in_f64_range() => {
-                    format!("{:.*}", precision as usize, f64::from(n))
-                }
-                _ => n.to_string(),
+        let (prefix, rendered) = match value {
+            KValue::Number(n) => match format_options {
+                Some(options) => format_number(n, options)?,
+                None => (String::new(), n.to_string()),
             },
             other => match self.run_unary_op(UnaryOp::Display, other)? {
                 KValue::Str(rendered) => match precision {
@@ -2967,9 +3863,9 @@ impl KotoVm {
                         for grapheme in rendered.graphemes(true).take(precision as usize) {
                             truncated.push_str(grapheme);
                         }
-                        truncated
+                        (String::new(), truncated)
                     }
-                    None => rendered.to_string(),
+                    None => (String::new(), rendered.to_string()),
                 },
                 other => return unexpected_type("String", &other),
             },
@@ -2978,7 +3874,8 @@ impl KotoVm {
         // Apply other formatting options to the rendered string
         let result = match format_options {
             Some(options) => {
-                let len = rendered.graphemes(true).count();
+                // The prefix only contains ASCII characters
+                let len = prefix.len() + rendered.graphemes(true).count();
                 let min_width = options.min_width.unwrap_or(0) as usize;
                 if len < min_width {
                     let fill = match options.fill_character {
@@ -2986,12 +3883,20 @@ impl KotoVm {
                         None => KString::from(" "),
                     };
                     let fill_chars = min_width - len;
+                    let prefix_len = prefix.len();
+                    let rendered = prefix + &rendered;
 
                     match options.alignment {
                         StringAlignment::Default => {
                             if value_is_number {
-                                // Right-alignment by default for numbers
-                                fill.repeat(fill_chars) + &rendered
+                                if fill.as_str() == "0" {
+                                    // Zero-padding goes between the number's prefix and digits
+                                    let (prefix, digits) = rendered.split_at(prefix_len);
+                                    format!("{prefix}{}{digits}", fill.repeat(fill_chars))
+                                } else {
+                                    // Right-alignment by default for numbers
+                                    fill.repeat(fill_chars) + &rendered
+                                }
                             } else {
                                 // Left alignment by default for non-numbers
                                 rendered + &fill.repeat(fill_chars)
@@ -3010,15 +3915,76 @@ impl KotoVm {
                         StringAlignment::Right => fill.repeat(fill_chars) + &rendered,
                     }
                 } else {
-                    rendered
+                    prefix + &rendered
                 }
             }
             None => rendered,
         };
 
-        // Add the result to the string builder
+        self.push_to_string_builder(&result)
+    }
+
+    // Returns the format string that's passed to values that implement `@format`
+    //
+    // Custom format strings are passed as they appear in the source, while standard formatting
+    // options are rendered back into their source form.
+    fn format_options_string(&self, options: &StringFormatOptions) -> KString {
+        if let Some(format_string) = options.format_string {
+            return self.koto_string_from_constant(format_string);
+        }
+
+        let fill = options
+            .fill_character
+            .map(|fill| self.get_constant_str(fill));
+
+        let mut result = String::new();
+        let alignment = match options.alignment {
+            StringAlignment::Default => None,
+            StringAlignment::Left => Some('<'),
+            StringAlignment::Center => Some('^'),
+            StringAlignment::Right => Some('>'),
+        };
+        if let Some(alignment) = alignment {
+            result.extend(fill);
+            result.push(alignment);
+        }
+        if options.show_sign {
+            result.push('+');
+        }
+        if options.alternate {
+            result.push('#');
+        }
+        if alignment.is_none() {
+            // Without an alignment, the fill character is used for zero-padding
+            result.extend(fill);
+        }
+        if let Some(min_width) = options.min_width {
+            result.push_str(&min_width.to_string());
+        }
+        if options.thousands_separator {
+            result.push(',');
+        }
+        if let Some(precision) = options.precision {
+            result.push_str(&format!(".{precision}"));
+        }
+        let representation = match options.representation {
+            StringFormatRepresentation::Default => None,
+            StringFormatRepresentation::Binary => Some('b'),
+            StringFormatRepresentation::Octal => Some('o'),
+            StringFormatRepresentation::HexLower => Some('x'),
+            StringFormatRepresentation::HexUpper => Some('X'),
+            StringFormatRepresentation::ExpLower => Some('e'),
+            StringFormatRepresentation::ExpUpper => Some('E'),
+        };
+        result.extend(representation);
+
+        result.into()
+    }
+
+    // Adds a string to the string builder that's currently under construction
+    fn push_to_string_builder(&mut self, s: &str) -> Result<()> {
         if let Some(builder) = self.string_builders.last_mut() {
-            builder.push_str(&result);
+            builder.push_str(s);
             Ok(())
         } else {
             runtime_error!(ErrorKind::MissingStringBuilder)
@@ -3250,6 +4216,117 @@ fn binary_op_error(lhs: &KValue, rhs: &KValue, op: BinaryOp) -> Result<()> {
     })
 }
 
+// Renders a number using the provided format options
+//
+// The number's sign and representation prefix (e.g. `0x`) are returned separately to its digits.
+fn format_number(n: KNumber, options: &StringFormatOptions) -> Result<(String, String)> {
+    use StringFormatRepresentation::*;
+
+    let mut prefix = String::new();
+
+    let digits = match options.representation {
+        Binary | Octal | HexLower | HexUpper => {
+            // Only integers can be formatted with these representations, with negative numbers
+            // shown in two's complement
+            let n = match n {
+                KNumber::I64(n) => n,
+                KNumber::F64(f) if f.fract() == 0.0 => i64::from(n),
+                _ => {
+                    return runtime_error!(
+                        "Expected an integer for a binary, octal, or hex format, found '{n}'"
+                    )
+                }
+            };
+            if options.show_sign && n >= 0 {
+                prefix.push('+');
+            }
+            if options.alternate {
+                prefix.push_str(match options.representation {
+                    Binary => "0b",
+                    Octal => "0o",
+                    _ => "0x",
+                });
+            }
+            let digits = match options.representation {
+                Binary => format!("{n:b}"),
+                Octal => format!("{n:o}"),
+                HexLower => format!("{n:x}"),
+                _ => format!("{n:X}"),
+            };
+            return Ok((prefix, digits));
+        }
+        ExpLower | ExpUpper => {
+            let rendered = match (n, options.precision) {
+                (KNumber::F64(n), Some(precision)) => format!("{n:.*e}", precision as usize),
+                (KNumber::F64(n), None) => format!("{n:e}"),
+                (KNumber::I64(n), Some(precision)) => format!("{n:.*e}", precision as usize),
+                (KNumber::I64(n), None) => format!("{n:e}"),
+            };
+            if options.representation == ExpUpper {
+                rendered.replace('e', "E")
+            } else {
+                rendered
+            }
+        }
+        Default => match options.precision {
+            Some(precision) if n.is_f64() || n.is_i64_in_f64_range() => {
+                format!("{:.*}", precision as usize, f64::from(n))
+            }
+            _ => n.to_string(),
+        },
+    };
+
+    let digits = match digits.strip_prefix('-') {
+        Some(unsigned) => {
+            prefix.push('-');
+            unsigned.to_string()
+        }
+        None => {
+            if options.show_sign && !n.is_nan() {
+                prefix.push('+');
+            }
+            digits
+        }
+    };
+
+    if options.thousands_separator && options.representation == Default {
+        Ok((prefix, add_thousands_separators(&digits)))
+    } else {
+        Ok((prefix, digits))
+    }
+}
+
+// Inserts `,` separators between groups of three digits in the integer part of a number
+fn add_thousands_separators(digits: &str) -> String {
+    let (integer, fraction) = match digits.find('.') {
+        Some(position) => digits.split_at(position),
+        None => (digits, ""),
+    };
+
+    if !integer.bytes().all(|b| b.is_ascii_digit()) {
+        // NaN or infinity
+        return digits.to_string();
+    }
+
+    let mut result = String::with_capacity(digits.len() + integer.len() / 3);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(digit);
+    }
+    result.push_str(fraction);
+    result
+}
+
+// Converts the RHS of a bit shift operation into a shift amount
+fn shift_amount(n: &KNumber) -> Result<u32> {
+    match i64::from(n) {
+        n if n < 0 => runtime_error!("Expected a non-negative shift amount, found '{n}'"),
+        n => Ok(u32::try_from(n).unwrap_or(u32::MAX)),
+    }
+}
+
 fn signed_index_to_unsigned(index: i8, size: usize) -> usize {
     if index < 0 {
         size - (index as isize).unsigned_abs().min(size)
@@ -3320,6 +4397,11 @@ impl<'a, const N: usize> From<&'a [KValue; N]> for CallArgs<'a> {
 // The Map is optional to prevent recursive imports (see Vm::run_import).
 type ModuleCache = HashMap<PathBuf, Option<KMap>, BuildHasherDefault<FxHasher>>;
 
+// A cache of parsed type hints, keyed by the hint's string representation
+//
+// Invalid hints are cached as None.
+type TypeHintCache = HashMap<String, Option<Ptr<TypeHint>>, BuildHasherDefault<FxHasher>>;
+
 // A frame in the VM's call stack
 #[derive(Clone, Debug)]
 struct Frame {
@@ -3449,3 +4531,31 @@ pub enum ReturnOrYield {
     Return(KValue),
     Yield(KValue),
 }
+
+// Checks if a value provides a meta key
+//
+// Meta keys that correspond to built-in behaviour are provided by the core value types,
+// e.g. lists provide `@iterator`, `@size`, and `@[]`.
+fn value_has_meta_key(value: &KValue, meta_key: &MetaKey) -> bool {
+    if let KValue::Map(map) = value {
+        let mut map = map.clone();
+        loop {
+            if map.contains_meta_key(meta_key) {
+                return true;
+            }
+            match map.get_meta_value(&MetaKey::Base) {
+                Some(KValue::Map(base)) => map = base,
+                _ => break,
+            }
+        }
+    }
+
+    match meta_key {
+        MetaKey::UnaryOp(UnaryOp::Iterator) => value.is_iterable(),
+        MetaKey::UnaryOp(UnaryOp::Next) => matches!(value, KValue::Iterator(_)),
+        MetaKey::UnaryOp(UnaryOp::Size) => value.is_indexable() || matches!(value, KValue::Set(_)),
+        MetaKey::BinaryOp(BinaryOp::Index) => value.is_indexable(),
+        MetaKey::Call => value.is_callable(),
+        _ => false,
+    }
+}
@@ -0,0 +1,319 @@
+mod file_system {
+    use koto_bytecode::{CompilerSettings, Loader};
+    use koto_runtime::{prelude::*, MemoryFileSystem, PathAccess, Ptr, Result};
+    use koto_test_utils::check_script_output_with_vm;
+
+    fn make_vm(file_system: &MemoryFileSystem) -> KotoVm {
+        KotoVm::with_settings(KotoVmSettings {
+            file_system: make_ptr!(file_system.clone()),
+            ..Default::default()
+        })
+    }
+
+    fn run_script(script: &str, file_system: &MemoryFileSystem) -> Result<KValue> {
+        let mut vm = make_vm(file_system);
+
+        let mut loader = Loader::default();
+        let chunk = match loader.compile_script(script, None, CompilerSettings::default()) {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                panic!("Error while compiling script: {error}");
+            }
+        };
+
+        vm.run(chunk)
+    }
+
+    fn check_script_output(
+        script: &str,
+        file_system: &MemoryFileSystem,
+        expected_output: impl Into<KValue>,
+    ) {
+        if let Err(error) =
+            check_script_output_with_vm(make_vm(file_system), script, expected_output)
+        {
+            panic!("{error}");
+        }
+    }
+
+    fn check_script_error(script: &str, file_system: &MemoryFileSystem, expected_prefix: &str) {
+        match run_script(script, file_system) {
+            Ok(result) => panic!("Expected an error, found '{result:?}'"),
+            Err(error) => {
+                let message = error.to_string();
+                assert!(
+                    message.starts_with(expected_prefix),
+                    "Unexpected error message: {message}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn read_to_string() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/hello.txt", "Hello!");
+
+        check_script_output("io.read_to_string '/data/hello.txt'", &fs, "Hello!");
+    }
+
+    #[test]
+    fn read_lines() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/lines.txt", "one\ntwo\nthree\n");
+
+        check_script_output(
+            "
+f = io.open '/data/lines.txt'
+lines = []
+while line = f.read_line()
+  lines.push line
+lines
+",
+            &fs,
+            KValue::List(KList::from_slice(&[
+                "one".into(),
+                "two".into(),
+                "three".into(),
+            ])),
+        );
+    }
+
+    #[test]
+    fn write_file() {
+        let fs = MemoryFileSystem::new();
+
+        check_script_output(
+            "
+f = io.create '/data/out.txt'
+f.write_line 'abc'
+f.write 123
+",
+            &fs,
+            KValue::Null,
+        );
+
+        assert_eq!(fs.file_contents("/data/out.txt").unwrap(), b"abc\n123");
+    }
+
+    #[test]
+    fn exists() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/a.txt", "");
+
+        check_script_output(
+            "
+io.exists('/data/a.txt')
+  and io.exists('/data')
+  and not io.exists('/data/b.txt')
+",
+            &fs,
+            true,
+        );
+    }
+
+    #[test]
+    fn read_dir() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/b.txt", "");
+        fs.insert_file("/data/a.txt", "");
+        fs.insert_file("/data/nested/c.txt", "");
+
+        check_script_output(
+            "
+io.read_dir('/data')
+  .each |path| io.path(path).file_name()
+  .to_tuple()
+",
+            &fs,
+            KValue::Tuple(vec!["a.txt".into(), "b.txt".into(), "nested".into()].into()),
+        );
+    }
+
+    #[test]
+    fn walk_dir() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/b.txt", "");
+        fs.insert_file("/data/a/c.txt", "");
+
+        check_script_output(
+            "io.walk_dir('/data').to_tuple()",
+            &fs,
+            KValue::Tuple(
+                vec![
+                    "/data/a".into(),
+                    "/data/a/c.txt".into(),
+                    "/data/b.txt".into(),
+                ]
+                .into(),
+            ),
+        );
+    }
+
+    #[test]
+    fn glob() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/a.txt", "");
+        fs.insert_file("/data/b.md", "");
+        fs.insert_file("/data/nested/c.txt", "");
+
+        check_script_output(
+            "io.glob('/data/**/*.txt').to_tuple()",
+            &fs,
+            KValue::Tuple(vec!["/data/a.txt".into(), "/data/nested/c.txt".into()].into()),
+        );
+    }
+
+    #[test]
+    fn make_and_remove_dir() {
+        let fs = MemoryFileSystem::new();
+
+        check_script_output(
+            "
+io.make_dir '/data/empty'
+result = [io.metadata('/data/empty').is_dir(), io.read_dir('/data/empty').count()]
+io.remove_dir '/data/empty'
+result.push io.exists '/data/empty'
+result
+",
+            &fs,
+            KValue::List(KList::from_slice(&[true.into(), 0.into(), false.into()])),
+        );
+    }
+
+    #[test]
+    fn remove_dir_recursive() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/nested/a.txt", "");
+        fs.insert_file("/other.txt", "");
+
+        check_script_output("io.remove_dir '/data', true", &fs, KValue::Null);
+
+        assert!(fs.file_contents("/data/nested/a.txt").is_none());
+        assert!(fs.file_contents("/other.txt").is_some());
+    }
+
+    #[test]
+    fn remove_non_empty_dir() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/a.txt", "");
+
+        check_script_error("io.remove_dir '/data'", &fs, "io.remove_dir: ");
+    }
+
+    #[test]
+    fn metadata() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/a.txt", "abc");
+
+        check_script_output(
+            "
+metadata = io.metadata '/data/a.txt'
+metadata.is_file() and not metadata.is_dir() and metadata.size() == 3
+",
+            &fs,
+            true,
+        );
+    }
+
+    #[test]
+    fn copy_and_rename() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/a.txt", "abc");
+
+        check_script_output(
+            "
+io.copy '/data/a.txt', '/data/b.txt'
+io.rename '/data', '/moved'
+",
+            &fs,
+            KValue::Null,
+        );
+
+        assert!(fs.file_contents("/data/a.txt").is_none());
+        assert_eq!(fs.file_contents("/moved/a.txt").unwrap(), b"abc");
+        assert_eq!(fs.file_contents("/moved/b.txt").unwrap(), b"abc");
+    }
+
+    #[test]
+    fn seek_past_end_of_file() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/a.txt", "abc");
+
+        check_script_output(
+            "
+f = io.open '/data/a.txt'
+f.seek 3
+f.read_to_string()
+",
+            &fs,
+            "",
+        );
+        assert!(run_script(
+            "
+f = io.create '/data/b.txt'
+f.seek 1000000000000000000
+f.write 'x'
+",
+            &fs
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn remove_file() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/data/a.txt", "a");
+
+        check_script_output("io.remove_file '/data/a.txt'", &fs, KValue::Null);
+
+        assert!(fs.file_contents("/data/a.txt").is_none());
+    }
+
+    #[test]
+    fn open_missing_file() {
+        let fs = MemoryFileSystem::new();
+
+        assert!(run_script("io.open '/data/missing.txt'", &fs).is_err());
+    }
+
+    #[test]
+    fn errors_include_the_function_name() {
+        let fs = MemoryFileSystem::new();
+
+        check_script_error(
+            "io.read_to_string '/missing.txt'",
+            &fs,
+            "io.read_to_string: Unable to read file '/missing.txt'",
+        );
+        check_script_error("io.read_dir '/missing'", &fs, "io.read_dir: ");
+        check_script_error("io.copy '/missing.txt', '/b.txt'", &fs, "io.copy: ");
+    }
+
+    #[test]
+    fn permissions_are_resolved_with_the_file_system() {
+        let fs = MemoryFileSystem::new();
+        fs.insert_file("/allowed/a.txt", "a");
+        fs.insert_file("/denied/b.txt", "b");
+
+        let make_vm = || {
+            KotoVm::with_settings(KotoVmSettings {
+                file_system: make_ptr!(fs.clone()),
+                permissions: KotoPermissions {
+                    read: PathAccess::Within(vec!["/allowed".into()]),
+                    ..KotoPermissions::none()
+                },
+                ..Default::default()
+            })
+        };
+
+        // The paths don't exist on the host, so they're only resolvable via the file system
+        check_script_output_with_vm(make_vm(), "io.read_to_string '/allowed/a.txt'", "a").unwrap();
+        assert!(check_script_output_with_vm(
+            make_vm(),
+            "io.read_to_string '/allowed/../denied/b.txt'",
+            "b"
+        )
+        .is_err());
+    }
+}
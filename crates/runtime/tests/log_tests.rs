@@ -0,0 +1,153 @@
+mod log {
+    use koto_bytecode::{CompilerSettings, Loader};
+    use koto_runtime::{prelude::*, LogLevel, LogRecord, Ptr, PtrMut, Result};
+    use koto_test_utils::OutputCapture;
+
+    fn run_script(script: &str, mut vm: KotoVm) {
+        let mut loader = Loader::default();
+        let chunk = match loader.compile_script(script, None, CompilerSettings::default()) {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                panic!("Error while compiling script: {error}");
+            }
+        };
+
+        if let Err(error) = vm.run(chunk) {
+            panic!("Unexpected error: {error}");
+        }
+    }
+
+    // A log sink that records the messages it receives
+    #[derive(Clone, Default)]
+    struct TestSink {
+        records: PtrMut<Vec<LogRecord>>,
+        min_level: Option<LogLevel>,
+    }
+
+    impl KotoLogSink for TestSink {
+        fn log(&self, record: &LogRecord) -> Result<()> {
+            self.records.borrow_mut().push(record.clone());
+            Ok(())
+        }
+
+        fn is_enabled(&self, level: LogLevel) -> bool {
+            match self.min_level {
+                Some(min_level) => level >= min_level,
+                None => true,
+            }
+        }
+    }
+
+    fn vm_with_sink(sink: &TestSink) -> KotoVm {
+        KotoVm::with_settings(KotoVmSettings {
+            log_sink: Some(make_ptr!(sink.clone())),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn levels_and_messages() {
+        let sink = TestSink::default();
+
+        let script = "
+log.debug 'a'
+log.info 'b'
+log.warn 'c'
+log.error 1 + 1
+";
+        run_script(script, vm_with_sink(&sink));
+
+        let records = sink.records.borrow();
+        let result = records
+            .iter()
+            .map(|record| (record.level, record.message.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            [
+                (LogLevel::Debug, "a"),
+                (LogLevel::Info, "b"),
+                (LogLevel::Warn, "c"),
+                (LogLevel::Error, "2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let sink = TestSink::default();
+
+        let script = "
+log.info 'Request complete', {path: '/index.html', status: 200, tags: ['x', 'y']}
+";
+        run_script(script, vm_with_sink(&sink));
+
+        let records = sink.records.borrow();
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].fields,
+            [
+                ("path".to_string(), "/index.html".to_string()),
+                ("status".to_string(), "200".to_string()),
+                ("tags".to_string(), "['x', 'y']".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn disabled_levels_are_skipped() {
+        let sink = TestSink {
+            min_level: Some(LogLevel::Warn),
+            ..Default::default()
+        };
+
+        let script = "
+log.debug 'a'
+log.info 'b'
+log.warn 'c'
+log.error 'd'
+";
+        run_script(script, vm_with_sink(&sink));
+
+        let records = sink.records.borrow();
+        let result = records
+            .iter()
+            .map(|record| record.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(result, ["c", "d"]);
+    }
+
+    #[test]
+    fn default_output_is_written_to_stderr() {
+        let (vm, output) = OutputCapture::make_vm_with_output_capture();
+
+        let script = "
+log.info 'Server started', {port: 8080, host: 'local host'}
+log.error 'Oops'
+";
+        run_script(script, vm);
+
+        assert_eq!(
+            output.captured_output().as_str(),
+            "\
+[info] Server started port=8080 host=\"local host\"
+[error] Oops
+"
+        );
+    }
+
+    #[test]
+    fn default_output_escapes_newlines() {
+        let (vm, output) = OutputCapture::make_vm_with_output_capture();
+
+        let script = "
+log.warn 'Line 1\\nLine 2', {detail: 'a\\nb'}
+";
+        run_script(script, vm);
+
+        assert_eq!(
+            output.captured_output().as_str(),
+            "[warn] \"Line 1\\nLine 2\" detail=\"a\\nb\"\n"
+        );
+    }
+}
@@ -176,6 +176,24 @@ mod objects {
             arithmetic_op!(self, rhs, %)
         }
 
+        fn add_rhs(&self, lhs: &KValue) -> Result<KValue> {
+            match lhs {
+                KValue::Number(n) => Ok(Self::make_value(i64::from(n) + self.x)),
+                unexpected => unexpected_type("Number", unexpected),
+            }
+        }
+
+        fn subtract_rhs(&self, lhs: &KValue) -> Result<KValue> {
+            match lhs {
+                KValue::Number(n) => Ok(Self::make_value(i64::from(n) - self.x)),
+                unexpected => unexpected_type("Number", unexpected),
+            }
+        }
+
+        fn equal_rhs(&self, lhs: &KValue) -> Result<bool> {
+            comparison_op!(self, lhs, ==)
+        }
+
         fn add_assign(&mut self, rhs: &KValue) -> Result<()> {
             assignment_op!(self, rhs, +=)
         }
@@ -514,6 +532,24 @@ x.as_number()
             test_object_script(script, 5);
         }
 
+        #[test]
+        fn add_rhs() {
+            let script = "
+x = 33 + (make_object 22)
+x.as_number()
+";
+            test_object_script(script, 55);
+        }
+
+        #[test]
+        fn subtract_rhs() {
+            let script = "
+x = 100 - (make_object 1)
+x.as_number()
+";
+            test_object_script(script, 99);
+        }
+
         #[test]
         fn add_assign() {
             let script = "
@@ -649,6 +685,18 @@ x.as_number()
             test_object_script(script, false);
         }
 
+        #[test]
+        fn equal_number_lhs() {
+            let script = "2 == (make_object 2)";
+            test_object_script(script, true);
+        }
+
+        #[test]
+        fn greater_number_lhs() {
+            let script = "3 > (make_object 2)";
+            test_object_script(script, true);
+        }
+
         #[test]
         fn equal_null_rhs() {
             let script = "null == (make_object 2)";
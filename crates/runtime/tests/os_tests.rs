@@ -0,0 +1,164 @@
+mod os {
+    use koto_bytecode::{CompilerSettings, Loader};
+    use koto_runtime::{prelude::*, Error, ErrorKind, Ptr, Result};
+    use std::time::Duration;
+
+    fn run_script(script: &str, settings: KotoVmSettings) -> Result<KValue> {
+        let mut vm = KotoVm::with_settings(settings);
+
+        let mut loader = Loader::default();
+        let chunk = match loader.compile_script(script, None, CompilerSettings::default()) {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                panic!("Error while compiling script: {error}");
+            }
+        };
+
+        vm.run(chunk)
+    }
+
+    struct TestOs {}
+
+    impl KotoOs for TestOs {
+        fn env_var(&self, key: &str) -> Result<Option<String>> {
+            Ok((key == "TEST").then(|| "hello".into()))
+        }
+
+        fn exit(&self, code: i32) -> Result<()> {
+            runtime_error!("exit {code}")
+        }
+
+        fn process_id(&self) -> Result<u32> {
+            Ok(42)
+        }
+
+        fn sleep(&self, _duration: Duration) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn test_os_settings() -> KotoVmSettings {
+        KotoVmSettings {
+            os: make_ptr!(TestOs {}),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn exit_unwinds_through_catch() {
+        let script = "
+try
+  os.exit 3
+catch _
+  99
+";
+
+        match run_script(script, KotoVmSettings::default()) {
+            Err(Error {
+                error: ErrorKind::Exit(3),
+                ..
+            }) => {}
+            Ok(result) => panic!("Expected an exit error, found '{result:?}'"),
+            Err(e) => panic!("Unexpected error: {e}"),
+        }
+    }
+
+    #[test]
+    fn exit_code_out_of_range() {
+        let script = "
+try
+  os.exit 256
+catch _
+  'out of range'
+";
+
+        match run_script(script, KotoVmSettings::default()) {
+            Ok(KValue::Str(result)) => assert_eq!(result.as_str(), "out of range"),
+            Ok(result) => panic!("Unexpected result: {result:?}"),
+            Err(e) => panic!("Unexpected error: {e}"),
+        }
+    }
+
+    #[test]
+    fn exit_code_must_be_an_integer() {
+        let script = "
+try
+  os.exit 2.5
+catch error
+  error
+";
+
+        match run_script(script, KotoVmSettings::default()) {
+            Ok(KValue::Str(result)) => {
+                assert_eq!(result.as_str(), "Expected an integer exit code, found 2.5")
+            }
+            Ok(result) => panic!("Unexpected result: {result:?}"),
+            Err(e) => panic!("Unexpected error: {e}"),
+        }
+    }
+
+    #[test]
+    fn exit_skips_finally_blocks() {
+        let script = "
+try
+  os.exit 2.0
+catch _
+  null
+finally
+  export cleaned_up = true
+";
+
+        let mut vm = KotoVm::default();
+        let exports = vm.exports().clone();
+        let chunk = Loader::default()
+            .compile_script(script, None, CompilerSettings::default())
+            .unwrap();
+
+        match vm.run(chunk) {
+            Err(Error {
+                error: ErrorKind::Exit(2),
+                ..
+            }) => {}
+            Ok(result) => panic!("Expected an exit error, found '{result:?}'"),
+            Err(e) => panic!("Unexpected error: {e}"),
+        }
+        assert!(exports.get("cleaned_up").is_none());
+    }
+
+    #[test]
+    fn overridden_env_and_pid() {
+        let script = "
+os.sleep 1000
+x = os.env 'TEST'
+y = os.env 'OTHER'
+'{x} {y} {os.pid()}'
+";
+
+        match run_script(script, test_os_settings()) {
+            Ok(KValue::Str(result)) => assert_eq!(result.as_str(), "hello null 42"),
+            Ok(result) => panic!("Unexpected result: {result:?}"),
+            Err(e) => panic!("Unexpected error: {e}"),
+        }
+    }
+
+    #[test]
+    fn overridden_exit_can_be_caught() {
+        let script = "
+try
+  os.exit 1
+catch error
+  'caught {error}'
+";
+
+        match run_script(script, test_os_settings()) {
+            Ok(KValue::Str(result)) => assert_eq!(result.as_str(), "caught exit 1"),
+            Ok(result) => panic!("Unexpected result: {result:?}"),
+            Err(e) => panic!("Unexpected error: {e}"),
+        }
+    }
+
+    #[test]
+    fn unsupported_operations_produce_errors() {
+        assert!(run_script("os.hostname()", test_os_settings()).is_err());
+    }
+}
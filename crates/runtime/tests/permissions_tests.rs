@@ -0,0 +1,114 @@
+mod permissions {
+    use koto_bytecode::{CompilerSettings, Loader};
+    use koto_runtime::{prelude::*, Error, ErrorKind, PathAccess, Result};
+    use std::{fs, path::PathBuf};
+
+    fn run_script(script: &str, permissions: KotoPermissions) -> Result<KValue> {
+        let mut vm = KotoVm::with_settings(KotoVmSettings {
+            permissions,
+            ..Default::default()
+        });
+
+        let mut loader = Loader::default();
+        let chunk = match loader.compile_script(script, None, CompilerSettings::default()) {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                panic!("Error while compiling script: {error}");
+            }
+        };
+
+        vm.run(chunk)
+    }
+
+    fn check_script_succeeds(script: &str, permissions: KotoPermissions) {
+        if let Err(error) = run_script(script, permissions) {
+            panic!("Unexpected error: {error}");
+        }
+    }
+
+    fn check_permission_denied(script: &str, permissions: KotoPermissions) {
+        match run_script(script, permissions) {
+            Err(Error {
+                error: ErrorKind::PermissionDenied(_),
+                ..
+            }) => {}
+            Ok(_) => panic!("Expected a permission denied error"),
+            Err(error) => panic!("Unexpected error: {error}"),
+        }
+    }
+
+    // Creates a test directory containing an `allowed` subdirectory
+    fn make_test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("koto-permissions-{name}"));
+        fs::create_dir_all(dir.join("allowed")).unwrap();
+        fs::write(dir.join("allowed").join("a.txt"), "a").unwrap();
+        fs::write(dir.join("b.txt"), "b").unwrap();
+        dir
+    }
+
+    #[test]
+    fn read_within_allowed_dir() {
+        let dir = make_test_dir("read");
+        let permissions = KotoPermissions {
+            read: PathAccess::Within(vec![dir.join("allowed")]),
+            ..KotoPermissions::none()
+        };
+        let dir = dir.to_string_lossy();
+
+        let script = format!(
+            "
+dir = r'{dir}'
+assert_eq (io.read_to_string io.extend_path dir, 'allowed', 'a.txt'), 'a'
+assert_eq io.read_dir(io.extend_path dir, 'allowed').count(), 1
+"
+        );
+        check_script_succeeds(&script, permissions.clone());
+
+        let script = format!("io.read_to_string io.extend_path r'{dir}', 'b.txt'");
+        check_permission_denied(&script, permissions.clone());
+
+        // Parent components can't be used to escape from the allowed directory
+        let script = format!("io.read_to_string io.extend_path r'{dir}', 'allowed', '..', 'b.txt'");
+        check_permission_denied(&script, permissions.clone());
+
+        let script = format!("io.read_dir(r'{dir}').count()");
+        check_permission_denied(&script, permissions);
+    }
+
+    #[test]
+    fn write_within_allowed_dir() {
+        let dir = make_test_dir("write");
+        let permissions = KotoPermissions {
+            write: PathAccess::Within(vec![dir.join("allowed")]),
+            ..KotoPermissions::none()
+        };
+        let dir = dir.to_string_lossy();
+
+        let script = format!(
+            "
+path = io.extend_path r'{dir}', 'allowed', 'new', 'c.txt'
+io.make_dir io.extend_path r'{dir}', 'allowed', 'new'
+io.create(path).write 'c'
+io.remove_file path
+"
+        );
+        check_script_succeeds(&script, permissions.clone());
+
+        let script = format!("io.create io.extend_path r'{dir}', 'c.txt'");
+        check_permission_denied(&script, permissions.clone());
+
+        let script = format!("io.remove_file io.extend_path r'{dir}', 'b.txt'");
+        check_permission_denied(&script, permissions);
+    }
+
+    #[test]
+    fn env_access_denied() {
+        check_permission_denied("os.env 'HOME'", KotoPermissions::none());
+        check_permission_denied("os.set_env 'FOO', 'bar'", KotoPermissions::none());
+    }
+
+    #[test]
+    fn process_spawning_denied() {
+        check_permission_denied("os.run 'echo'", KotoPermissions::none());
+    }
+}
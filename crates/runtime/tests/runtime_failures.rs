@@ -1,19 +1,23 @@
 mod runtime {
     use koto_bytecode::{CompilerSettings, Loader};
     use koto_lexer::{Position, Span};
-    use koto_runtime::{ErrorFrame, KotoVm};
+    use koto_runtime::{ErrorFrame, KotoPermissions, KotoVm, KotoVmSettings};
     use koto_test_utils::script_instructions;
 
     fn check_script_fails(script: &str) {
-        check_that_script_fails(script, None);
+        check_that_script_fails(script, None, KotoVmSettings::default());
     }
 
     fn check_script_fails_with_span(script: &str, span: Span) {
-        check_that_script_fails(script, Some(span))
+        check_that_script_fails(script, Some(span), KotoVmSettings::default())
     }
 
-    fn check_that_script_fails(script: &str, span: Option<Span>) {
-        let mut vm = KotoVm::default();
+    fn check_script_fails_with_settings(script: &str, settings: KotoVmSettings) {
+        check_that_script_fails(script, None, settings);
+    }
+
+    fn check_that_script_fails(script: &str, span: Option<Span>, settings: KotoVmSettings) {
+        let mut vm = KotoVm::with_settings(settings);
 
         let mut loader = Loader::default();
         let chunk = match loader.compile_script(script, None, CompilerSettings::default()) {
@@ -363,6 +367,31 @@ for i: Number, _x: Bool in 'abc'.enumerate()
                 );
             }
 
+            #[test]
+            fn optional_type_hint() {
+                check_script_fails("let x: String? = 42");
+            }
+
+            #[test]
+            fn union_type_hint() {
+                check_script_fails("let x: Number | String = true");
+            }
+
+            #[test]
+            fn nested_list_type_hint() {
+                check_script_fails("let x: List<Number> = [1, 2, 'three']");
+            }
+
+            #[test]
+            fn nested_map_type_hint() {
+                check_script_fails("let x: Map<String, Number> = {foo: 1, bar: null}");
+            }
+
+            #[test]
+            fn tuple_with_mismatched_size() {
+                check_script_fails("let x: Tuple<Number, Number> = (1, 2, 3)");
+            }
+
             #[test]
             fn generator_with_type_hint() {
                 let script = "\
@@ -557,6 +586,253 @@ x.insert (1, [2, 3]), 'hello'
             }
         }
 
+        mod frozen_values {
+            use super::*;
+
+            #[test]
+            fn push_to_const_list() {
+                let script = "
+const x = [1, 2, 3]
+x.push 4
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn assign_to_index_in_const_list() {
+                let script = "
+const x = [1, 2, 3]
+x[0] = 99
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn insert_into_const_map() {
+                let script = "
+const x = {foo: 42}
+x.bar = 99
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn modify_nested_list_in_const_map() {
+                let script = "
+const x = {foo: [1, 2, 3]}
+x.foo.remove 0
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn modify_list_in_const_tuple() {
+                let script = "
+const x = (1, [2, 3])
+x[1].clear()
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn insert_into_const_set() {
+                let script = "
+const x = set.new [1, 2, 3]
+x.insert 4
+";
+                check_script_fails(script);
+            }
+        }
+
+        mod big_numbers {
+            use super::*;
+
+            #[test]
+            fn big_int_division_by_zero() {
+                let script = "
+x = number.big 42
+x // 0
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn big_int_negative_exponent() {
+                let script = "
+(number.big 2) ** -1
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn big_int_exponent_too_large() {
+                let script = "
+(number.big 10) ** 4000000000
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn big_int_negative_shift() {
+                let script = "
+(number.big 1) << -1
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn big_int_shift_too_large() {
+                let script = "
+(number.big 1) << 4000000000
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn big_int_bitwise_op_with_decimal() {
+                let script = "
+(number.big 1) & 1.5
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn invalid_decimal_string() {
+                let script = "
+number.decimal '1.2.3'
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn decimal_overflow() {
+                let script = "
+x = number.decimal '79228162514264337593543950335'
+x + 1
+";
+                check_script_fails(script);
+            }
+        }
+
+        mod integer_overflow {
+            use super::*;
+
+            fn check_script_overflows(script: &str) {
+                check_script_fails_with_settings(
+                    script,
+                    KotoVmSettings {
+                        enable_integer_overflow_checks: true,
+                        ..Default::default()
+                    },
+                );
+            }
+
+            #[test]
+            fn add() {
+                let script = "
+x = 9223372036854775807
+x + 1
+";
+                check_script_overflows(script);
+            }
+
+            #[test]
+            fn subtract_assign() {
+                let script = "
+x = -9223372036854775807
+x -= 2
+";
+                check_script_overflows(script);
+            }
+
+            #[test]
+            fn multiply() {
+                let script = "
+x = 4611686018427387904
+x * 2
+";
+                check_script_overflows(script);
+            }
+
+            #[test]
+            fn power() {
+                let script = "
+x = 2
+x ** 63
+";
+                check_script_overflows(script);
+            }
+
+            #[test]
+            fn negate() {
+                let script = "
+x = -9223372036854775807 - 1
+-x
+";
+                check_script_overflows(script);
+            }
+
+            #[test]
+            fn integer_divide() {
+                let script = "
+x = -9223372036854775807 - 1
+x // -1
+";
+                check_script_overflows(script);
+            }
+        }
+
+        mod bytes {
+            use super::*;
+
+            #[test]
+            fn push_out_of_range_byte() {
+                let script = "
+x = bytes.new()
+x.push 256
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn invalid_hex_string() {
+                let script = "
+bytes.from_hex 'xyz0'
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn index_out_of_bounds() {
+                let script = "
+x = bytes.new [1, 2, 3]
+x[3]
+";
+                check_script_fails(script);
+            }
+        }
+
+        mod sets {
+            use super::*;
+
+            #[test]
+            fn insert_unhashable_value() {
+                let script = "
+x = set.new()
+x.insert [1, 2, 3]
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn make_set_with_unhashable_value() {
+                let script = "
+(1, 2, {}).to_set()
+";
+                check_script_fails(script);
+            }
+        }
+
         mod meta_maps {
             use super::*;
 
@@ -591,6 +867,59 @@ x.reversed().next()
             }
         }
 
+        mod records {
+            use super::*;
+
+            #[test]
+            fn missing_record_argument() {
+                let script = "
+record Point(x, y)
+Point 1
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn mismatched_record_field_type() {
+                let script = "
+record Point(x: Number, y: Number)
+Point 1, 'two'
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn match_with_non_constructor_in_record_pattern() {
+                let script = "
+Point = 42
+match 1
+  Point(x) then x
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn value_not_conforming_to_interface() {
+                let script = "
+interface Shape
+  area
+let x: Shape = {name: 'square'}
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn value_missing_meta_key_from_interface() {
+                let script = "
+interface Displayable
+  @display
+f = |x: Displayable| '{x}'
+f {foo: 42}
+";
+                check_script_fails(script);
+            }
+        }
+
         mod strings {
             use super::*;
 
@@ -610,6 +939,63 @@ x = r###########################################################################
 ";
                 check_script_fails(script);
             }
+
+            #[test]
+            fn custom_format_string_without_format_meta_key() {
+                let script = "
+x = 42
+'{x:%Y-%m}'
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn hex_format_with_non_integer() {
+                check_script_fails("'{1.5:x}'");
+            }
+
+            #[test]
+            fn format_meta_key_returning_non_string() {
+                let script = "
+x =
+  @format: |_| 42
+'{x:>5}'
+";
+                check_script_fails(script);
+            }
+        }
+
+        mod os {
+            use super::*;
+
+            fn check_script_fails_without_process_spawning(script: &str) {
+                check_script_fails_with_settings(
+                    script,
+                    KotoVmSettings {
+                        permissions: KotoPermissions {
+                            allow_process_spawning: false,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                );
+            }
+
+            #[test]
+            fn command_with_process_spawning_disabled() {
+                let script = "
+os.command 'echo'
+";
+                check_script_fails_without_process_spawning(script);
+            }
+
+            #[test]
+            fn run_with_process_spawning_disabled() {
+                let script = "
+os.run 'echo', 'hello'
+";
+                check_script_fails_without_process_spawning(script);
+            }
         }
     }
 }
@@ -67,6 +67,36 @@ a = 99
         fn remainder_with_a_divisor_of_zero() {
             check_script_output("(1 % 0).is_nan()", true);
         }
+
+        #[test]
+        fn integer_overflow_wraps_by_default() {
+            let script = "
+x = 9223372036854775807
+x + 1
+";
+            check_script_output(script, i64::MIN);
+        }
+
+        #[test]
+        fn integer_overflow_checks_enabled_without_overflow() {
+            let script = "
+x = 9223372036854775806
+x += 1
+x - 1, x * 1.5, 2 ** 62
+";
+            let vm = KotoVm::with_settings(KotoVmSettings {
+                enable_integer_overflow_checks: true,
+                ..Default::default()
+            });
+            let expected = tuple(&[
+                (i64::MAX - 1).into(),
+                (i64::MAX as f64 * 1.5).into(),
+                (1_i64 << 62).into(),
+            ]);
+            if let Err(e) = check_script_output_with_vm(vm, script, expected) {
+                panic!("{e}");
+            }
+        }
     }
 
     mod logic {
@@ -177,6 +207,37 @@ a, b, c
 ";
             check_script_output(script, number_tuple(&[500, 50, 30]));
         }
+
+        #[test]
+        fn const_declaration() {
+            let script = "
+const x = 42
+const y: String = 'foo'
+x, y
+";
+            check_script_output(script, tuple(&[42.into(), "foo".into()]));
+        }
+
+        #[test]
+        fn const_list_can_be_read() {
+            let script = "
+const x = [1, [2, 3]]
+x[1][0] + x[0]
+";
+            check_script_output(script, 3);
+        }
+
+        #[test]
+        fn const_shadowed_in_nested_function() {
+            let script = "
+const x = 1
+f = ||
+  x = 2
+  x
+f() + x
+";
+            check_script_output(script, 3);
+        }
     }
 
     #[allow(clippy::reversed_empty_ranges)]
@@ -581,6 +642,49 @@ true
 ";
             check_script_output(script, true);
         }
+
+        #[test]
+        fn optional_type_accepts_null() {
+            let script = "
+let x: String?, y: String? = null, 'foo'
+y
+";
+            check_script_output(script, "foo");
+        }
+
+        #[test]
+        fn union_type() {
+            let script = "
+let x: Number | String, y: Number | String = 42, 'foo'
+x, y
+";
+            check_script_output(script, tuple(&[42.into(), "foo".into()]));
+        }
+
+        #[test]
+        fn nested_types() {
+            let script = "
+let x: List<Number | String> = [1, 'two', 3]
+let y: Map<String, Tuple<Number, Bool>> = {foo: (1, true)}
+x[1], y.foo[1]
+";
+            check_script_output(script, tuple(&["two".into(), true.into()]));
+        }
+
+        #[test]
+        fn nested_types_with_container_checks_disabled() {
+            let script = "
+let x: List<Number> = [1, 'two', 3]
+x[1]
+";
+            let vm = KotoVm::with_settings(KotoVmSettings {
+                enable_container_type_checks: false,
+                ..Default::default()
+            });
+            if let Err(e) = check_script_output_with_vm(vm, script, "two") {
+                panic!("{e}");
+            }
+        }
     }
 
     mod if_expressions {
@@ -991,6 +1095,173 @@ x
         }
     }
 
+    mod records_and_enums {
+        use super::*;
+
+        #[test]
+        fn record_fields() {
+            let script = "
+record Point(x, y)
+p = Point 1, 2
+p.x + p.y
+";
+            check_script_output(script, 3);
+        }
+
+        #[test]
+        fn record_display() {
+            let script = "
+record Point(x: Number, y: String)
+'{Point 1, 'a'}'
+";
+            check_script_output(script, "Point(x: 1, y: 'a')");
+        }
+
+        #[test]
+        fn record_equality() {
+            let script = "
+record Point(x, y)
+record Other(x, y)
+a = Point 1, 2
+a == (Point 1, 2), a != (Point 1, 3), a == (Other 1, 2)
+";
+            check_script_output(script, tuple(&[true.into(), true.into(), false.into()]));
+        }
+
+        #[test]
+        fn enum_variants() {
+            let script = "
+enum Shape
+  Circle(r)
+  Rect(w, h)
+  Empty
+area = |shape|
+  match shape
+    Shape.Circle(r) then r * r * 3
+    Shape.Rect(w, h) then w * h
+    Shape.Empty then 0
+area(Shape.Circle 2), area(Shape.Rect 2, 3), area(Shape.Empty)
+";
+            check_script_output(script, number_tuple(&[12, 6, 0]));
+        }
+
+        #[test]
+        fn enum_variant_type() {
+            let script = "
+enum Shape
+  Circle(r)
+koto.type Shape.Circle 1
+";
+            check_script_output(script, "Shape");
+        }
+
+        #[test]
+        fn record_pattern_with_nested_pattern() {
+            let script = "
+record Point(x, y)
+match Point 0, (1, 2)
+  Point(1, _) then -1
+  Point(0, (a, b)) then a + b
+";
+            check_script_output(script, 3);
+        }
+
+        #[test]
+        fn call_expressions_in_match_arms() {
+            let script = "
+Square = |n| n * n
+n = 4
+match 16
+  Square(3) then 'nine'
+  Square(n) then 'sixteen'
+";
+            check_script_output(script, "sixteen");
+        }
+
+        #[test]
+        fn call_expressions_with_alternatives_in_match_arms() {
+            let script = "
+Square = |n| n * n
+record Point(x, y)
+x = match Point 1, 2
+  Square(3) or Point(1, _) then 'first'
+  _ then 'second'
+y = match 9
+  Point(1, _) or Square(3) then 'first'
+  _ then 'second'
+x, y
+";
+            check_script_output(script, tuple(&["first".into(), "first".into()]));
+        }
+    }
+
+    mod interfaces {
+        use super::*;
+
+        #[test]
+        fn interface_with_keys() {
+            let script = "
+interface Shape
+  area
+  name
+f = |shape: Shape| shape.area()
+f {area: (|| 42), name: 'square'}
+";
+            check_script_output(script, 42);
+        }
+
+        #[test]
+        fn interface_with_meta_keys() {
+            let script = "
+interface Container
+  @size
+  @[]
+f = |x: Container| size x
+f([1, 2, 3]) + f('ab')
+";
+            check_script_output(script, 5);
+        }
+
+        #[test]
+        fn interface_satisfied_by_base_map() {
+            let script = "
+interface Named
+  name
+  @display
+base =
+  name: 'base'
+  @display: || 'base'
+x =
+  @base: base
+let y: Named = x
+y.name
+";
+            check_script_output(script, "base");
+        }
+
+        #[test]
+        fn interface_in_match_pattern() {
+            let script = "
+interface Sized
+  @size
+match 42, [1, 2]
+  x: Sized, _ then 'first'
+  _, x: Sized then 'second'
+";
+            check_script_output(script, "second");
+        }
+
+        #[test]
+        fn interface_display() {
+            let script = "
+interface Sized
+  @size
+'{Sized}: {koto.type Sized}'
+";
+            check_script_output(script, "Sized: Interface");
+        }
+    }
+
     mod switch_expressions {
         use super::*;
 
@@ -2205,6 +2476,77 @@ m[0]
         }
     }
 
+    mod sets {
+        use super::*;
+
+        #[test]
+        fn empty() {
+            check_script_output("set.new()", KValue::Set(KSet::new()));
+        }
+
+        #[test]
+        fn from_list() {
+            let expected = KSet::new();
+            expected.insert(1);
+            expected.insert("a");
+
+            check_script_output("set.new [1, 'a', 1, 'a']", KValue::Set(expected));
+        }
+
+        #[test]
+        fn equality_ignores_order() {
+            let script = "
+a = set.new [1, 2, 3]
+b = set.new [3, 2, 1]
+a == b, a != b
+";
+            check_script_output(script, tuple(&[true.into(), false.into()]));
+        }
+
+        #[test]
+        fn size() {
+            let script = "
+size set.new 'hello'
+";
+            check_script_output(script, 4);
+        }
+
+        #[test]
+        fn iteration() {
+            let script = "
+result = 0
+for x in set.new [1, 2, 2, 3]
+  result += x
+result
+";
+            check_script_output(script, 6);
+        }
+
+        #[test]
+        fn insert_and_remove() {
+            let script = "
+x = set.new()
+a = x.insert 42
+b = x.insert 42
+c = x.remove 42
+a, b, c, x.is_empty()
+";
+            check_script_output(
+                script,
+                tuple(&[true.into(), false.into(), true.into(), true.into()]),
+            );
+        }
+
+        #[test]
+        fn set_type_hint() {
+            let script = "
+let x: Set<Number> = set.new [1, 2, 3]
+size x
+";
+            check_script_output(script, 3);
+        }
+    }
+
     mod chains {
         use super::*;
 
@@ -2888,9 +3230,30 @@ x = ('foo', 'bar')
         #[test_case("'{'hello':.2}'", "he"; "precision with string")]
         #[test_case("'{'hello':10}'", "hello     "; "min width with string")]
         #[test_case("'{'hello':~>4.2}'", "~~he"; "right-aligned truncated string")]
+        #[test_case("'{255:x}'", "ff"; "lowercase hex")]
+        #[test_case("'{255:#X}'", "0xFF"; "uppercase hex with prefix")]
+        #[test_case("'{5:#06b}'", "0b0101"; "zero-padded binary with prefix")]
+        #[test_case("'{8:o}'", "10"; "octal")]
+        #[test_case("'{-1.0:x}'", "ffffffffffffffff"; "hex with integer float")]
+        #[test_case("'{42:+}'", "+42"; "explicit sign")]
+        #[test_case("'{-4.2:06.1}'", "-004.2"; "zero-padded negative float")]
+        #[test_case("'{1234567:,}'", "1,234,567"; "thousands separators")]
+        #[test_case("'{-9876.543:>12,.2}'", "   -9,876.54"; "thousands separators with precision")]
+        #[test_case("'{1234.5:e}'", "1.2345e3"; "exponent")]
+        #[test_case("'{0.00012:.1E}'", "1.2E-4"; "uppercase exponent with precision")]
         fn formatted_expression(input: &str, expected: &str) {
             check_script_output(input, expected);
         }
+
+        #[test]
+        fn formatted_expression_with_format_meta_key() {
+            let script = "
+x =
+  @format: |spec| 'x: {spec}'
+'{x:.2} {x:%Y} {x:*>+8,.1} {x:#06X}'
+";
+            check_script_output(script, "x: .2 x: %Y x: *>+8,.1 x: #06X");
+        }
     }
 
     mod raw_strings {
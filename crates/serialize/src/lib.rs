@@ -1,6 +1,9 @@
 //! Serde serialization support for Koto value types
 
-use koto_runtime::KValue;
+use koto_runtime::{
+    core_lib::number::{BigInt, Decimal},
+    KValue,
+};
 use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
 
 /// A newtype that allows us to implement support for Serde serialization
@@ -42,7 +45,27 @@ impl<'a> Serialize for SerializableValue<'a> {
                 }
                 seq.end()
             }
+            KValue::Set(set) => {
+                let mut seq = s.serialize_seq(Some(set.len()))?;
+                for element in set.data().iter() {
+                    seq.serialize_element(&SerializableValue(element.value()))?;
+                }
+                seq.end()
+            }
             KValue::Str(string) => s.serialize_str(string),
+            // BigInts outside of the i64 range and Decimals are serialized as strings to avoid
+            // losing precision.
+            KValue::Object(o) if o.is_a::<BigInt>() => {
+                let n = o.cast::<BigInt>().map_err(serde::ser::Error::custom)?;
+                match n.to_i64() {
+                    Some(n) => s.serialize_i64(n),
+                    None => s.serialize_str(&n.to_string()),
+                }
+            }
+            KValue::Object(o) if o.is_a::<Decimal>() => {
+                let n = o.cast::<Decimal>().map_err(serde::ser::Error::custom)?;
+                s.serialize_str(&n.to_string())
+            }
             // TODO, is it ok to do nothing for non-fundamental types, e.g. External Values?
             _ => s.serialize_unit(),
         }
@@ -0,0 +1,302 @@
+//! A Koto language module for working with CSV data
+
+use koto_runtime::{
+    core_lib::io::File, prelude::*, Error, KIteratorOutput as Output, Ptr, PtrMut, Result,
+};
+use std::io::{self, Read};
+
+pub fn make_module() -> KMap {
+    use KValue::{Map, Object, Str};
+
+    let result = KMap::with_type("csv");
+
+    result.add_fn("from_string", |ctx| {
+        let (input, options) = match ctx.args() {
+            [Str(input)] => (input, Options::default()),
+            [Str(input), Map(options)] => (input, Options::from_map(options)?),
+            unexpected => return unexpected_args("|String|, or |String, Map|", unexpected),
+        };
+
+        let mut reader = options.reader(input.as_bytes());
+        let headers = read_headers(&mut reader, &options)?;
+
+        let mut rows = ValueVec::new();
+        let mut record = csv::StringRecord::new();
+        loop {
+            match reader.read_record(&mut record) {
+                Ok(true) => rows.push(record_to_value(&record, headers.as_deref())),
+                Ok(false) => break,
+                Err(error) => return Err(read_error(error)),
+            }
+        }
+
+        Ok(KList::with_data(rows).into())
+    });
+
+    result.add_fn("read_rows", |ctx| {
+        let (file, options) = match ctx.args() {
+            [Object(o)] if o.is_a::<File>() => {
+                (o.cast::<File>()?.inner().clone(), Options::default())
+            }
+            [Object(o), Map(options)] if o.is_a::<File>() => (
+                o.cast::<File>()?.inner().clone(),
+                Options::from_map(options)?,
+            ),
+            unexpected => return unexpected_args("|File|, or |File, Map|", unexpected),
+        };
+
+        Ok(KIterator::new(RowIterator::new(file, &options)?).into())
+    });
+
+    result.add_fn("to_string", |ctx| {
+        let (rows, options) = match ctx.args() {
+            [rows] if rows.is_iterable() => (rows.clone(), Options::default()),
+            [rows, Map(options)] if rows.is_iterable() => {
+                (rows.clone(), Options::from_map(options)?)
+            }
+            unexpected => return unexpected_args("|Iterable|, or |Iterable, Map|", unexpected),
+        };
+
+        write_rows(ctx.vm, rows, &options)
+    });
+
+    result
+}
+
+// The options that can be provided when reading or writing CSV data
+struct Options {
+    delimiter: u8,
+    quote: u8,
+    headers: bool,
+}
+
+impl Options {
+    fn from_map(map: &KMap) -> Result<Self> {
+        let mut result = Self::default();
+
+        for (key, value) in map.data().iter() {
+            match (key.to_string().as_str(), value) {
+                ("delimiter", KValue::Str(s)) => result.delimiter = single_byte("delimiter", s)?,
+                ("quote", KValue::Str(s)) => result.quote = single_byte("quote", s)?,
+                ("headers", KValue::Bool(b)) => result.headers = *b,
+                ("delimiter" | "quote", unexpected) => {
+                    return unexpected_type("String", unexpected)
+                }
+                ("headers", unexpected) => return unexpected_type("Bool", unexpected),
+                (unknown, _) => return runtime_error!("Unknown option '{unknown}'"),
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn reader<R: Read>(&self, input: R) -> csv::Reader<R> {
+        csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .has_headers(self.headers)
+            .from_reader(input)
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            headers: true,
+        }
+    }
+}
+
+fn single_byte(option: &str, s: &str) -> Result<u8> {
+    match s.as_bytes() {
+        [byte] if byte.is_ascii() => Ok(*byte),
+        _ => runtime_error!("The '{option}' option must be a single ASCII character"),
+    }
+}
+
+// Reads the header row if headers are enabled
+fn read_headers<R: Read>(
+    reader: &mut csv::Reader<R>,
+    options: &Options,
+) -> Result<Option<Vec<KString>>> {
+    if !options.headers {
+        return Ok(None);
+    }
+
+    match reader.headers() {
+        Ok(headers) => Ok(Some(headers.iter().map(KString::from).collect())),
+        Err(error) => Err(read_error(error)),
+    }
+}
+
+// Rows are converted into maps when headers are available, otherwise into tuples of strings
+fn record_to_value(record: &csv::StringRecord, headers: Option<&[KString]>) -> KValue {
+    match headers {
+        Some(headers) => {
+            let result = KMap::with_capacity(headers.len());
+            for (header, field) in headers.iter().zip(record.iter()) {
+                result.insert(header.clone(), field);
+            }
+            result.into()
+        }
+        None => KValue::Tuple(record.iter().map(KValue::from).collect::<Vec<_>>().into()),
+    }
+}
+
+// csv::Error already describes where the error occurred, e.g. "CSV error: record 1 (line: 2...)"
+fn read_error(error: csv::Error) -> Error {
+    error.to_string().into()
+}
+
+fn write_error(error: impl std::fmt::Display) -> Error {
+    format!("Error while writing CSV data: {error}").into()
+}
+
+fn write_rows(vm: &mut KotoVm, rows: KValue, options: &Options) -> Result<KValue> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .quote(options.quote)
+        .from_writer(Vec::new());
+
+    // The keys of the first map row, used to define the column order for subsequent map rows
+    let mut map_keys: Option<Vec<ValueKey>> = None;
+    let mut record = Vec::new();
+
+    for output in vm.make_iterator(rows)? {
+        let row = match output {
+            Output::Value(row) => row,
+            Output::ValuePair(a, b) => KValue::Tuple(vec![a, b].into()),
+            Output::Error(error) => return Err(error),
+        };
+
+        let values = match &row {
+            KValue::Map(map) => {
+                let keys = match &map_keys {
+                    Some(keys) => keys,
+                    None => {
+                        let keys = map.data().keys().cloned().collect::<Vec<_>>();
+                        if options.headers {
+                            let header = keys.iter().map(|key| key.to_string());
+                            writer.write_record(header).map_err(write_error)?;
+                        }
+                        map_keys.insert(keys)
+                    }
+                };
+
+                let data = map.data();
+                keys.iter()
+                    .map(|key| data.get(key).cloned().unwrap_or_default())
+                    .collect::<Vec<_>>()
+            }
+            KValue::List(list) => list.data().to_vec(),
+            KValue::Tuple(tuple) => tuple.to_vec(),
+            unexpected => return unexpected_type("Map, List, or Tuple as a row", unexpected),
+        };
+
+        record.clear();
+        for value in values.iter() {
+            let field = match value {
+                KValue::Str(s) => s.to_string(),
+                KValue::Null => String::new(),
+                other => vm.value_to_string(other)?,
+            };
+            record.push(field);
+        }
+
+        writer.write_record(&record).map_err(write_error)?;
+    }
+
+    let bytes = writer.into_inner().map_err(write_error)?;
+    match String::from_utf8(bytes) {
+        Ok(result) => Ok(result.into()),
+        Err(error) => Err(write_error(error)),
+    }
+}
+
+// An iterator that lazily reads rows from a file
+//
+// Copies of the iterator share the underlying file and reader, so rows that are read by one copy
+// won't be produced by the others.
+#[derive(Clone)]
+struct RowIterator {
+    reader: PtrMut<csv::Reader<FileReader>>,
+    headers: Option<Ptr<Vec<KString>>>,
+}
+
+impl RowIterator {
+    fn new(file: Ptr<dyn KotoFile>, options: &Options) -> Result<Self> {
+        let mut reader = options.reader(FileReader::new(file));
+        let headers = read_headers(&mut reader, options)?;
+
+        Ok(Self {
+            reader: reader.into(),
+            headers: headers.map(Ptr::from),
+        })
+    }
+}
+
+impl KotoIterator for RowIterator {
+    fn make_copy(&self) -> Result<KIterator> {
+        Ok(KIterator::new(self.clone()))
+    }
+}
+
+impl Iterator for RowIterator {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = csv::StringRecord::new();
+        match self.reader.borrow_mut().read_record(&mut record) {
+            Ok(true) => {
+                let headers = self.headers.as_ref().map(|headers| headers.as_slice());
+                Some(Output::Value(record_to_value(&record, headers)))
+            }
+            Ok(false) => None,
+            Err(error) => Some(Output::Error(read_error(error))),
+        }
+    }
+}
+
+// An adapter that provides [Read] for a [KotoFile]
+//
+// The file is read a line at a time so that rows can be read as soon as they're available,
+// e.g. when reading from stdin.
+struct FileReader {
+    file: Ptr<dyn KotoFile>,
+    line: Vec<u8>,
+    position: usize,
+}
+
+impl FileReader {
+    fn new(file: Ptr<dyn KotoFile>) -> Self {
+        Self {
+            file,
+            line: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+impl Read for FileReader {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        while self.position >= self.line.len() {
+            match self.file.read_line() {
+                Ok(Some(line)) => {
+                    self.line = line.into_bytes();
+                    self.position = 0;
+                }
+                Ok(None) => return Ok(0),
+                Err(error) => return Err(io::Error::other(error.to_string())),
+            }
+        }
+
+        let available = &self.line[self.position..];
+        let count = available.len().min(buffer.len());
+        buffer[..count].copy_from_slice(&available[..count]);
+        self.position += count;
+
+        Ok(count)
+    }
+}
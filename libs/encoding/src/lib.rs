@@ -0,0 +1,81 @@
+//! A Koto language module for hashing and encoding data
+
+use koto_runtime::{
+    core_lib::bytes::{decode_base64, decode_hex, encode_base64, encode_hex, Bytes},
+    prelude::*,
+    Result,
+};
+use md5::Md5;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use sha2::{Digest, Sha256};
+
+// The characters that are percent-encoded by `url_encode`
+//
+// All characters are encoded other than the unreserved characters defined in RFC 3986.
+const URL_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+pub fn make_module() -> KMap {
+    use KValue::Str;
+
+    let result = KMap::with_type("encoding");
+
+    result.add_fn("crc32", |ctx| {
+        with_data(ctx.args(), |data| Ok(crc32fast::hash(data).into()))
+    });
+
+    result.add_fn("from_base64", |ctx| match ctx.args() {
+        [Str(s)] => decode_base64(s).map(|result| Bytes::from(result).into()),
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("from_hex", |ctx| match ctx.args() {
+        [Str(s)] => decode_hex(s).map(|result| Bytes::from(result).into()),
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("md5", |ctx| {
+        with_data(ctx.args(), |data| Ok(encode_hex(&Md5::digest(data)).into()))
+    });
+
+    result.add_fn("sha256", |ctx| {
+        with_data(ctx.args(), |data| {
+            Ok(encode_hex(&Sha256::digest(data)).into())
+        })
+    });
+
+    result.add_fn("to_base64", |ctx| {
+        with_data(ctx.args(), |data| Ok(encode_base64(data).into()))
+    });
+
+    result.add_fn("to_hex", |ctx| {
+        with_data(ctx.args(), |data| Ok(encode_hex(data).into()))
+    });
+
+    result.add_fn("url_decode", |ctx| match ctx.args() {
+        [Str(s)] => match percent_decode_str(s).decode_utf8() {
+            Ok(result) => Ok(result.as_ref().into()),
+            Err(_) => runtime_error!("The decoded string contains invalid UTF-8"),
+        },
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result.add_fn("url_encode", |ctx| match ctx.args() {
+        [Str(s)] => Ok(utf8_percent_encode(s, URL_ENCODE_SET).to_string().into()),
+        unexpected => unexpected_args("|String|", unexpected),
+    });
+
+    result
+}
+
+// Calls the function with the data contained in a String or Bytes argument
+fn with_data(args: &[KValue], f: impl FnOnce(&[u8]) -> Result<KValue>) -> Result<KValue> {
+    match args {
+        [KValue::Str(s)] => f(s.as_bytes()),
+        [KValue::Object(o)] if o.is_a::<Bytes>() => f(&o.cast::<Bytes>()?),
+        unexpected => unexpected_args("|String|, or |Bytes|", unexpected),
+    }
+}
@@ -13,6 +13,8 @@ fn run_script(script: &str, path: &Path, should_fail_at_runtime: bool) {
 
     let prelude = koto.prelude();
     prelude.insert("color", koto_color::make_module());
+    prelude.insert("csv", koto_csv::make_module());
+    prelude.insert("encoding", koto_encoding::make_module());
     prelude.insert("geometry", koto_geometry::make_module());
     prelude.insert("json", koto_json::make_module());
     prelude.insert("random", koto_random::make_module());
@@ -67,6 +69,8 @@ mod lib_tests {
     use super::*;
 
     lib_test!(color);
+    lib_test!(csv);
+    lib_test!(encoding);
     lib_test!(geometry);
     lib_test!(json);
     lib_test!(random);